@@ -0,0 +1,371 @@
+//! Reconciles the keyboard to a [`KeyboardConfig`], only writing what differs.
+//!
+//! First a [`Plan`] is made by comparing the configuration against what the device reports, this
+//! plan can be shown to the user before it is applied.
+
+use std::collections::BTreeMap;
+
+use crate::commands::{MacroId, ProfileId};
+use crate::configuration::{get_default_keymap, EffectConfig, KeyboardConfig, MacroConfig};
+use crate::diff::{compare_mapping, diff_macros, MacroChange, MappingChange};
+use crate::macro_names::{allocate_macro_ids, MacroNames};
+use crate::{CommandStatus, Error, Huntsman};
+
+/// The changes needed to make the device match a [`KeyboardConfig`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Plan {
+    /// The ids allocated to the macro names.
+    pub macro_ids: BTreeMap<String, MacroId>,
+    pub create_profiles: Vec<ProfileId>,
+    /// Profile to switch to, if the current profile is to be removed.
+    pub switch_profile: Option<ProfileId>,
+    pub delete_profiles: Vec<ProfileId>,
+    pub delete_macros: Vec<MacroId>,
+    pub macros: Vec<MacroChange>,
+    pub mappings: Vec<MappingChange>,
+    pub effects: Vec<(ProfileId, EffectConfig)>,
+    pub brightness: Vec<(ProfileId, f32)>,
+    pub game_mode: Option<bool>,
+}
+
+impl Plan {
+    /// Compare the configuration against the device and determine what has to change. Macro
+    /// names that are known in names keep their id, new names are given an id.
+    pub fn new(
+        h: &mut Huntsman,
+        config: &KeyboardConfig,
+        names: &MacroNames,
+    ) -> Result<Plan, Error> {
+        let model = h.model();
+        let first_profile = *model.profiles.start();
+        for (profile_id, profile) in config.profiles.iter() {
+            if !model.profiles.contains(profile_id) {
+                return Err(Error::InvalidArgument(format!(
+                    "Profile {} is not supported by the {}.",
+                    profile_id, model.name
+                )));
+            }
+            if let Some(effect) = &profile.effect {
+                if !model.supports(effect.kind()) {
+                    return Err(Error::InvalidArgument(format!(
+                        "Effect {:?} is not supported by the {}.",
+                        effect.kind(),
+                        model.name
+                    )));
+                }
+            }
+            for m in profile.mappings.iter() {
+                if !model.has_key(m.key.id) {
+                    return Err(Error::InvalidArgument(format!(
+                        "Key {} is not present on the {}.",
+                        m.key.id, model.name
+                    )));
+                }
+            }
+        }
+
+        let mut plan: Plan = Default::default();
+
+        // Profiles, the first profile always exists and is never removed.
+        let on_device = h.profile_list()?;
+        for profile_id in config.profiles.keys() {
+            if !on_device.contains(profile_id) {
+                plan.create_profiles.push(*profile_id);
+            }
+        }
+        for profile_id in on_device.iter() {
+            if *profile_id != first_profile && !config.profiles.contains_key(profile_id) {
+                plan.delete_profiles.push(*profile_id);
+            }
+        }
+        let current = h.profile_get_current()?;
+        if plan.delete_profiles.contains(&current) {
+            plan.switch_profile = Some(first_profile);
+        }
+
+        // Macros, all macros that are not in the configuration are removed, so only the ids of
+        // the names that are known already are taken.
+        for name in config.macros.keys() {
+            if let Some(macro_id) = names.get(name) {
+                plan.macro_ids.insert(name.clone(), macro_id);
+            }
+        }
+        let taken: Vec<MacroId> = plan.macro_ids.values().copied().collect();
+        let new_names: Vec<&String> = config
+            .macros
+            .keys()
+            .filter(|name| !plan.macro_ids.contains_key(*name))
+            .collect();
+        plan.macro_ids
+            .extend(allocate_macro_ids(new_names.into_iter(), &taken));
+        for macro_id in h.macro_list()? {
+            if !plan.macro_ids.values().any(|v| *v == macro_id) {
+                plan.delete_macros.push(macro_id);
+            }
+        }
+        let macros: Vec<MacroConfig> = config
+            .macros
+            .iter()
+            .map(|(name, events)| MacroConfig {
+                name: Some(name.clone()),
+                macro_id: plan.macro_ids[name],
+                events: events.clone(),
+            })
+            .collect();
+        plan.macros = diff_macros(h, &macros)?;
+
+        // Mappings, keys that are not listed should hold their default.
+        let macro_ids = plan.macro_ids.clone();
+        let lookup = move |name: &str| macro_ids.get(name).copied();
+        for (profile_id, profile) in config.profiles.iter() {
+            let exists = on_device.contains(profile_id);
+            for hypershift in [false, true].iter() {
+                for id in model.keys.iter() {
+                    let key = crate::commands::mappings::Key {
+                        id: *id,
+                        hypershift: *hypershift,
+                    };
+                    let configured = match profile.mappings.iter().rev().find(|m| m.key == key) {
+                        Some(m) => m.mapping.resolve(&lookup)?,
+                        None => get_default_keymap(&key),
+                    };
+                    // A profile that is created starts out with the defaults.
+                    let device = if exists {
+                        h.get_mapping(*profile_id, key)?.mapping
+                    } else {
+                        get_default_keymap(&key)
+                    };
+                    if let Some(change) = compare_mapping(*profile_id, key, device, configured) {
+                        plan.mappings.push(change);
+                    }
+                }
+            }
+            // A profile that is created has no effect or brightness to compare against yet.
+            if let Some(effect) = &profile.effect {
+                if !exists
+                    || h.get_effect(*profile_id)?.payload != effect.to_command(*profile_id).payload
+                {
+                    plan.effects.push((*profile_id, effect.clone()));
+                }
+            }
+            if let Some(brightness) = profile.brightness {
+                // Compare the byte that is sent, the device only holds that.
+                if !exists
+                    || (h.get_brightness(*profile_id)? * 255.0).round() as u8
+                        != (brightness * 255.0) as u8
+                {
+                    plan.brightness.push((*profile_id, brightness));
+                }
+            }
+        }
+        if let Some(game_mode) = config.game_mode {
+            if h.get_game_mode()? != game_mode {
+                plan.game_mode = Some(game_mode);
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Perform the changes, stops at the first command that doesn't succeed and returns its
+    /// status.
+    pub fn apply(&self, h: &mut Huntsman) -> Result<CommandStatus, Error> {
+        for profile_id in self.create_profiles.iter() {
+            let status = h.profile_create(*profile_id)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        if let Some(profile_id) = self.switch_profile {
+            let status = h.profile_set_current(profile_id)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        for profile_id in self.delete_profiles.iter() {
+            let status = h.profile_delete(*profile_id)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+
+        for macro_id in self.delete_macros.iter() {
+            let status = h.macro_delete(*macro_id)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        for change in self.macros.iter() {
+            let events = match &change.change {
+                crate::diff::Change::Added { new } | crate::diff::Change::Changed { new, .. } => {
+                    new
+                }
+                crate::diff::Change::Removed { .. } => continue,
+            };
+            let name = self
+                .macro_ids
+                .iter()
+                .find(|(_, v)| **v == change.macro_id)
+                .map(|(k, _)| k.as_str())
+                .unwrap_or("");
+            let status = h.macro_create_named(change.macro_id, name, events)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        for change in self.mappings.iter() {
+            let status = h.set_mapping(change.profile, change.key, change.mapping())?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        for (profile_id, effect) in self.effects.iter() {
+            let status = h.command_status(&effect.to_command(*profile_id))?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        for (profile_id, brightness) in self.brightness.iter() {
+            let status = h.set_brightness(*profile_id, *brightness)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        if let Some(game_mode) = self.game_mode {
+            let status = h.set_game_mode(game_mode)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        Ok(CommandStatus::Ok)
+    }
+}
+
+impl Plan {
+    /// Record the allocated ids in names and forget the names of the macros that are deleted.
+    pub fn update_names(&self, names: &mut MacroNames) {
+        for macro_id in self.delete_macros.iter() {
+            names.remove(*macro_id);
+        }
+        for (name, macro_id) in self.macro_ids.iter() {
+            names.insert(name, *macro_id);
+        }
+    }
+}
+
+impl std::fmt::Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for profile_id in self.create_profiles.iter() {
+            writeln!(f, "+ profile {}", profile_id)?;
+        }
+        for profile_id in self.delete_profiles.iter() {
+            writeln!(f, "- profile {}", profile_id)?;
+        }
+        for macro_id in self.delete_macros.iter() {
+            writeln!(f, "- macro 0x{:0>4x}", macro_id)?;
+        }
+        for (name, macro_id) in self.macro_ids.iter() {
+            if let Some(change) = self.macros.iter().find(|c| c.macro_id == *macro_id) {
+                writeln!(f, "{} ({})", change, name)?;
+            }
+        }
+        for change in self.mappings.iter() {
+            writeln!(f, "{}", change)?;
+        }
+        for (profile_id, effect) in self.effects.iter() {
+            writeln!(f, "= profile {} effect: {:?}", profile_id, effect)?;
+        }
+        for (profile_id, brightness) in self.brightness.iter() {
+            writeln!(f, "= profile {} brightness: {}", profile_id, brightness)?;
+        }
+        if let Some(game_mode) = self.game_mode {
+            writeln!(f, "= game mode: {}", game_mode)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::mappings::KeyMapping;
+
+    #[test]
+    fn test_apply_keyboard() {
+        let config: KeyboardConfig = serde_yaml::from_str(
+            r#"
+game_mode: true
+macros:
+  paste_sig:
+    - keyboard_make: a
+    - keyboard_break: a
+profiles:
+  1:
+    brightness: 0.5
+    effect: spectrum
+    mappings:
+      - id: caps_lock
+        mapping: { macro: { name: paste_sig } }
+      - id: right_control
+        mapping: { mouse: right }
+  3:
+    effect: { wave: { reverse: true } }
+"#,
+        )
+        .expect("valid config");
+
+        let mut h = Huntsman::virtual_new().expect("success");
+        assert_eq!(h.profile_create(2).expect("success"), CommandStatus::Ok);
+        assert_eq!(
+            h.profile_set_current(2).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(
+            h.macro_create_actions(0x42, &vec![]).expect("success"),
+            CommandStatus::Ok
+        );
+
+        // The name that is known keeps its id, the id of the removed macro is forgotten.
+        let mut names: MacroNames = Default::default();
+        names.insert("paste_sig", 0x1234);
+        names.insert("old", 0x42);
+        let plan = Plan::new(&mut h, &config, &names).expect("success");
+        assert_eq!(plan.macro_ids["paste_sig"], 0x1234);
+        assert_eq!(plan.create_profiles, vec![3]);
+        assert_eq!(plan.delete_profiles, vec![2]);
+        assert_eq!(plan.switch_profile, Some(1));
+        assert_eq!(plan.delete_macros, vec![0x42]);
+        assert_eq!(plan.mappings.len(), 2);
+        assert_eq!(plan.effects.len(), 2);
+        assert_eq!(plan.brightness, vec![(1, 0.5)]);
+        assert_eq!(plan.game_mode, Some(true));
+        assert_eq!(plan.apply(&mut h).expect("success"), CommandStatus::Ok);
+        plan.update_names(&mut names);
+        assert_eq!(names.get("old"), None);
+        assert_eq!(names.get("paste_sig"), Some(0x1234));
+
+        let macro_id = plan.macro_ids["paste_sig"];
+        assert_eq!(h.profile_list().expect("success"), vec![1, 3]);
+        assert_eq!(h.macro_list().expect("success"), vec![macro_id]);
+        let caps = crate::commands::mappings::Key {
+            id: crate::configuration::key_name_to_at101("caps_lock").expect("key"),
+            hypershift: false,
+        };
+        assert_eq!(
+            h.get_mapping(1, caps).expect("success").mapping,
+            KeyMapping::Macro { macro_id, count: 1 }
+        );
+        assert!(h.get_game_mode().expect("success"));
+        assert_eq!(h.get_effect(3).expect("success").payload.effect, 0x04);
+
+        // Applying again doesn't need any changes.
+        let plan = Plan::new(&mut h, &config, &names).expect("success");
+        assert!(plan.create_profiles.is_empty());
+        assert!(plan.delete_macros.is_empty());
+        assert!(plan.macros.is_empty());
+        assert!(plan.mappings.is_empty());
+        assert!(plan.effects.is_empty());
+        assert!(plan.brightness.is_empty());
+        assert_eq!(plan.game_mode, None);
+        assert_eq!(plan.to_string(), "");
+    }
+}
@@ -1,7 +1,10 @@
 //! This module holds the commands that can be sent to the device.
 
+use serde::{Deserialize, Serialize};
 use struct_helper::{Inspectable, ToBytes};
 
+use crate::Error;
+
 pub mod wire;
 use struct_helper::FromBytes;
 pub use wire::Cmd;
@@ -41,6 +44,66 @@ On profiles:
 
 */
 
+/// The outcome of a command as reported by the status byte in the response.
+#[must_use]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CommandStatus {
+    /// The command was executed, 0x02.
+    Ok,
+    /// The device is still processing the command, 0x01. 0x00 means it wasn't picked up yet.
+    Busy,
+    /// The device failed to execute the command, 0x03.
+    Failure,
+    /// The command referred to something that doesn't exist. The device reports this as a
+    /// failure (0x03), [`crate::Huntsman::macro_delete`] returns it when the macro is absent.
+    NotFound,
+    /// The device timed out executing the command, 0x04.
+    Timeout,
+    /// The command or its payload is not supported, 0x05. Also returned when sending garbage.
+    Unsupported,
+    /// Any other status byte.
+    Unknown(u8),
+}
+
+impl CommandStatus {
+    /// Returns true if the device executed the command.
+    pub fn is_ok(&self) -> bool {
+        *self == CommandStatus::Ok
+    }
+
+    /// Returns true if the device didn't finish processing the command yet.
+    pub fn is_busy(&self) -> bool {
+        *self == CommandStatus::Busy
+    }
+}
+
+impl From<u8> for CommandStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0x00 | 0x01 => CommandStatus::Busy,
+            0x02 => CommandStatus::Ok,
+            0x03 => CommandStatus::Failure,
+            0x04 => CommandStatus::Timeout,
+            0x05 => CommandStatus::Unsupported,
+            z => CommandStatus::Unknown(z),
+        }
+    }
+}
+
+impl std::fmt::Display for CommandStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CommandStatus::Ok => write!(f, "ok"),
+            CommandStatus::Busy => write!(f, "busy"),
+            CommandStatus::Failure => write!(f, "failure"),
+            CommandStatus::NotFound => write!(f, "not found"),
+            CommandStatus::Timeout => write!(f, "timeout"),
+            CommandStatus::Unsupported => write!(f, "unsupported"),
+            CommandStatus::Unknown(z) => write!(f, "unknown status 0x{:0>2x}", z),
+        }
+    }
+}
+
 /// Represents a command that can be sent over USB.
 pub trait Command: std::fmt::Debug {
     /// Should provider the raw bytes that are to be sent to the device over usb.
@@ -67,27 +130,54 @@ pub trait Command: std::fmt::Debug {
     /// Provides the payload definition that comes after the header.
     fn payload(&self) -> Vec<u8>;
 
-    /// Processes the response and returns an any holding it.
-    fn response(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
+    /// Number of leading payload bytes the device echoes from the request. A report is only
+    /// taken as the response to this command if these match, a report left by the previous
+    /// command on the same register doesn't. Zero if the response doesn't echo the request.
+    fn echo_len(&self) -> usize {
+        0
+    }
+
+    /// Returns true if the payload of the report echoes the request, see [`Command::echo_len`].
+    fn echoed_by(&self, response_payload: &[u8]) -> bool {
+        let payload = self.payload();
+        let len = std::cmp::min(self.echo_len(), payload.len());
+        response_payload.len() >= len && response_payload[..len] == payload[..len]
+    }
+
+    /// Verifies the checksum of the response and returns the status the device reported.
+    fn status(&self, data: &[u8]) -> Result<CommandStatus, Error> {
         let mut wire: wire::Command = wire::Command::from_le_bytes(data)?;
         let original_checksum = wire.checksum;
         wire.update_checksum();
         if original_checksum != wire.checksum {
-            return Err(format!(
-                "Checksum did not pass, got {:?} expected {:?}",
-                original_checksum, wire.checksum
-            ));
+            return Err(Error::ChecksumMismatch {
+                expected: wire.checksum,
+                received: original_checksum,
+            });
         }
-        if data[0] != 0x02 {
-            return Err(format!("Return code was not 0x02",));
+        Ok(CommandStatus::from(wire.status))
+    }
+
+    /// Processes the response and returns an any holding it.
+    fn response(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let status = self.status(data)?;
+        let wire: wire::Command = wire::Command::from_le_bytes(data)?;
+        if !status.is_ok() {
+            return Err(Error::Rejected {
+                cmd: wire.cmd,
+                status,
+            });
         }
         self.response_payload(&wire.payload[..])
     }
 
     /// If commands only care about the payload (most do), this method gets called from
     /// the default implementation of [`self.response()`] and passes just the payload slice.
-    fn response_payload(&self, _data: &[u8]) -> Result<Box<dyn Any>, String> {
-        unimplemented!("Reading response payload is not implemented for this command.");
+    fn response_payload(&self, _data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        Err(Error::Decode(format!(
+            "Reading response payload is not implemented for {:?}.",
+            self
+        )))
     }
 }
 
@@ -109,10 +199,10 @@ impl Command for GetSerialNumber {
     fn payload(&self) -> Vec<u8> {
         vec![0; 0x16]
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
         let end = data.iter().position(|&x| x == 0);
         if end.is_none() {
-            return Err("Couldn't find 0 termination.".to_string());
+            return Err(Error::Decode("Couldn't find 0 termination.".to_string()));
         }
         let end = end.unwrap();
         match std::str::from_utf8(&data[..end]) {
@@ -122,7 +212,7 @@ impl Command for GetSerialNumber {
                 }));
             }
             Err(v) => {
-                return Err(format!("{}", v));
+                return Err(Error::Decode(format!("{}", v)));
             }
         }
     }
@@ -140,6 +230,8 @@ let cmd = huntsman_comm::ArbitraryCommand {
 
 // 1, short, 2 medium, 3 long, 3 values matches slider in ui.
 #[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
 /// Duration, short, medium or long, like the slider in the ui.
 pub enum Duration {
     Short = 0x01,
@@ -373,6 +465,26 @@ impl Command for SetLedEffect {
     }
 }
 
+#[derive(Default, Copy, Clone, Debug)]
+/// Retrieve the LED effect of a profile, the response holds the same payload as [`SetLedEffect`].
+pub struct GetLedEffect(pub wire::SetLedEffect);
+impl GetLedEffect {
+    pub const CMD: Cmd = make_read_command(SetLedEffect::CMD);
+}
+impl Command for GetLedEffect {
+    fn register(&self) -> Cmd {
+        GetLedEffect::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        // Only the profile is filled in, the rest is populated by the device.
+        self.0.to_le_bytes().expect("Should succeed")
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = wire::SetLedEffect::from_le_bytes(data)?;
+        Ok(Box::new(GetLedEffect(res)))
+    }
+}
+
 #[derive(Default, Copy, Clone, Debug)]
 /// Sets the LED State, providing a direct RGB value for each individual led.
 /// Seems to be row.
@@ -452,6 +564,25 @@ impl Command for SetLedBrightness {
     }
 }
 
+#[derive(Default, Copy, Clone, Debug)]
+/// Retrieve the brightness of a profile.
+pub struct GetLedBrightness(pub wire::SetLedBrightness);
+impl GetLedBrightness {
+    pub const CMD: Cmd = make_read_command(SetLedBrightness::CMD);
+}
+impl Command for GetLedBrightness {
+    fn register(&self) -> Cmd {
+        GetLedBrightness::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.0.to_le_bytes().expect("Should succeed")
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = wire::SetLedBrightness::from_le_bytes(data)?;
+        Ok(Box::new(GetLedBrightness(res)))
+    }
+}
+
 #[derive(Default, Copy, Clone, Debug)]
 /// Toggles game mode on or off.
 pub struct SetGameMode {
@@ -477,6 +608,25 @@ impl Command for SetGameMode {
     }
 }
 
+#[derive(Default, Copy, Clone, Debug)]
+/// Retrieve whether game mode is enabled.
+pub struct GetGameMode(pub wire::SetGameMode);
+impl GetGameMode {
+    pub const CMD: Cmd = make_read_command(SetGameMode::CMD);
+}
+impl Command for GetGameMode {
+    fn register(&self) -> Cmd {
+        GetGameMode::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.0.to_le_bytes().expect("Should succeed")
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = wire::SetGameMode::from_le_bytes(data)?;
+        Ok(Box::new(GetGameMode(res)))
+    }
+}
+
 pub use profiles::ProfileId;
 #[derive(Default, Clone, Debug)]
 /// Set a key mapping.
@@ -495,8 +645,11 @@ impl Command for SetKeyMap {
         let serialized = self.0.to_be_bytes().expect("Success");
         serialized
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
-        let res = mappings::KeyMap::from_be_bytes(&data).expect("Should pass");
+    fn echo_len(&self) -> usize {
+        self.payload().len() // the whole mapping is echoed.
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = mappings::KeyMap::from_be_bytes(&data)?;
         let cmd = SetKeyMap(res);
         Ok(Box::new(cmd))
     }
@@ -519,8 +672,11 @@ impl Command for GetKeyMap {
         let serialized = self.0.to_be_bytes().expect("Success");
         serialized
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
-        let res = mappings::KeyMap::from_be_bytes(&data).expect("Should pass");
+    fn echo_len(&self) -> usize {
+        3 // profile, key id and hypershift.
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = mappings::KeyMap::from_be_bytes(&data)?;
         let cmd = GetKeyMap(res);
         Ok(Box::new(cmd))
     }
@@ -542,8 +698,8 @@ impl Command for GetActiveProfileCount {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
-        let macro_count = profiles::ProfileCount::from_be_bytes(&data).expect("Should pass");
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let macro_count = profiles::ProfileCount::from_be_bytes(&data)?;
         let cmd = GetActiveProfileCount(macro_count);
         Ok(Box::new(cmd))
     }
@@ -566,8 +722,8 @@ impl Command for GetActiveProfiles {
         self.0.to_be_bytes().expect("cannot fail")
     }
 
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
-        let macro_list = profiles::ProfileList::from_be_bytes(&data).expect("Should pass");
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let macro_list = profiles::ProfileList::from_be_bytes(&data)?;
         let cmd = GetActiveProfiles(macro_list);
         Ok(Box::new(cmd))
     }
@@ -609,9 +765,9 @@ impl Command for ProfileDelete {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
         // no real response, but we can't delete the profile that's active.
-        let z = profiles::ProfileDelete::from_be_bytes(&data).expect("Should pass");
+        let z = profiles::ProfileDelete::from_be_bytes(&data)?;
         let cmd = ProfileDelete(z);
         Ok(Box::new(cmd))
     }
@@ -633,8 +789,8 @@ impl Command for GetProfileCurrent {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
-        let res = profiles::ProfileCurrent::from_be_bytes(&data).expect("Should pass");
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = profiles::ProfileCurrent::from_be_bytes(&data)?;
         let cmd = GetProfileCurrent(res);
         Ok(Box::new(cmd))
     }
@@ -658,11 +814,32 @@ impl Command for SetProfileCurrent {
     }
 }
 
-// Set/Read profile metadata: 0x0508,.... read metadata 0x0588
+#[derive(Default, Copy, Clone, Debug)]
+/// Retrieve a chunk of the profile metadata, by profile id and page. The vendor software writes
+/// it with 0x0508, that layout isn't confirmed so there is no command for it.
+pub struct GetProfileMetadata(pub profiles::ProfileMetadata);
+impl GetProfileMetadata {
+    pub const CMD: Cmd = Cmd {
+        major: 0x05,
+        minor: 0x88,
+    };
+}
+impl Command for GetProfileMetadata {
+    fn register(&self) -> Cmd {
+        GetProfileMetadata::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.0.to_be_bytes().expect("cannot fail")
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = profiles::ProfileMetadata::from_be_bytes(data)?;
+        Ok(Box::new(GetProfileMetadata(res)))
+    }
+}
 
 #[derive(Default, Copy, Clone, Debug)]
 /// Get the memory storage statistics.
-pub struct GetStorageStatistics {}
+pub struct GetStorageStatistics(pub wire::GetStorageStatistics);
 impl GetStorageStatistics {
     pub const CMD: Cmd = Cmd {
         major: 0x06,
@@ -679,6 +856,10 @@ impl Command for GetStorageStatistics {
         };
         wire_cmd.to_le_bytes().expect("Should succeed")
     }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = wire::GetStorageStatistics::from_be_bytes(data)?;
+        Ok(Box::new(GetStorageStatistics(res)))
+    }
 }
 
 pub use macros::MacroId;
@@ -698,8 +879,8 @@ impl Command for GetActiveMacros {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
-        let macro_list = macros::MacroList::from_be_bytes(&data).expect("Should pass");
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let macro_list = macros::MacroList::from_be_bytes(&data)?;
         let cmd = GetActiveMacros(macro_list);
         Ok(Box::new(cmd))
     }
@@ -721,8 +902,8 @@ impl Command for GetActiveMacroCount {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
-    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, String> {
-        let macro_count = macros::MacroCount::from_be_bytes(&data).expect("Should pass");
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let macro_count = macros::MacroCount::from_be_bytes(&data)?;
         let cmd = GetActiveMacroCount(macro_count);
         Ok(Box::new(cmd))
     }
@@ -744,6 +925,9 @@ impl Command for MacroCreate {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
+    fn echo_len(&self) -> usize {
+        2 // macro id.
+    }
 }
 
 /// The command to create a macro.
@@ -762,6 +946,9 @@ impl Command for MacroDelete {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
+    fn echo_len(&self) -> usize {
+        2 // macro id.
+    }
 }
 
 /// Holds the macro payload.
@@ -780,17 +967,95 @@ impl Command for MacroActionsPayload {
     fn payload(&self) -> Vec<u8> {
         self.0.to_be_bytes().expect("cannot fail")
     }
+    fn echo_len(&self) -> usize {
+        6 // macro id and position.
+    }
+}
+
+/// Retrieve the number of event bytes allocated for a macro. This read variant of
+/// [`MacroCreate`] follows the read bit convention and has not been confirmed with a capture.
+#[derive(Debug, Default)]
+pub struct GetMacroSize(pub macros::MacroCreate);
+impl GetMacroSize {
+    pub const CMD: Cmd = make_read_command(MacroCreate::CMD);
+}
+impl Command for GetMacroSize {
+    fn register(&self) -> Cmd {
+        GetMacroSize::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.0.to_be_bytes().expect("cannot fail")
+    }
+    fn echo_len(&self) -> usize {
+        2 // macro id.
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = macros::MacroCreate::from_be_bytes(data)?;
+        Ok(Box::new(GetMacroSize(res)))
+    }
+}
+
+/// Retrieve a chunk of a macro's events at a position. This read variant of
+/// [`MacroActionsPayload`] follows the read bit convention and has not been confirmed with a
+/// capture.
+#[derive(Debug, Default)]
+pub struct GetMacroActionsPayload(pub macros::MacroActionsPayload);
+impl GetMacroActionsPayload {
+    pub const CMD: Cmd = make_read_command(MacroActionsPayload::CMD);
+}
+impl Command for GetMacroActionsPayload {
+    fn register(&self) -> Cmd {
+        GetMacroActionsPayload::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.0.to_be_bytes().expect("cannot fail")
+    }
+    fn echo_len(&self) -> usize {
+        6 // macro id and position.
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = macros::MacroActionsPayload::from_be_bytes(data)?;
+        Ok(Box::new(GetMacroActionsPayload(res)))
+    }
 }
 
 #[derive(Default, Copy, Clone, Debug)]
-/// Set the macro metadata
-pub struct MacroMetadata {}
+/// Set a chunk of the macro metadata, see [`macros::MacroInfo`].
+pub struct MacroMetadata(pub macros::MacroMetadata);
 impl MacroMetadata {
     pub const CMD: Cmd = Cmd {
         major: 0x06,
         minor: 0x0c,
     };
 }
+impl Command for MacroMetadata {
+    fn register(&self) -> Cmd {
+        MacroMetadata::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.0.to_be_bytes().expect("cannot fail")
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug)]
+/// Retrieve a chunk of the macro metadata, by the macro id and page offset. This is the read
+/// variant of [`MacroMetadata`], not confirmed against a capture.
+pub struct GetMacroMetadata(pub macros::MacroMetadata);
+impl GetMacroMetadata {
+    pub const CMD: Cmd = make_read_command(MacroMetadata::CMD);
+}
+impl Command for GetMacroMetadata {
+    fn register(&self) -> Cmd {
+        GetMacroMetadata::CMD
+    }
+    fn payload(&self) -> Vec<u8> {
+        self.0.to_be_bytes().expect("cannot fail")
+    }
+    fn response_payload(&self, data: &[u8]) -> Result<Box<dyn Any>, Error> {
+        let res = macros::MacroMetadata::from_be_bytes(data)?;
+        Ok(Box::new(GetMacroMetadata(res)))
+    }
+}
 
 #[derive(Default, Clone, Debug)]
 /// Sends an arbitrary payload to a register, use with caution, useful for testing.
@@ -824,7 +1089,8 @@ pub fn dev_run_cmd() -> Box<dyn Command> {
     })
 }
 
-fn make_read_command(cmd: Cmd) -> Cmd {
+/// Returns the register that reads back the value written by the provided register.
+const fn make_read_command(cmd: Cmd) -> Cmd {
     Cmd {
         major: cmd.major,
         minor: cmd.minor | 0x80,
@@ -840,17 +1106,31 @@ pub fn get_command_fields() -> Vec<(Cmd, Box<dyn Fn() -> Box<dyn struct_helper::
             make_read_command(SetKeyMap::CMD),
             Box::new(wire::SetKeyOverride::inspect),
         ),
+        (GetGameMode::CMD, Box::new(wire::SetGameMode::inspect)),
         (SetLedEffect::CMD, Box::new(wire::SetLedEffect::inspect)),
+        (GetLedEffect::CMD, Box::new(wire::SetLedEffect::inspect)),
         (SetLedState::CMD, Box::new(wire::SetLedState::inspect)),
         (
             SetLedBrightness::CMD,
             Box::new(wire::SetLedBrightness::inspect),
         ),
+        (
+            GetLedBrightness::CMD,
+            Box::new(wire::SetLedBrightness::inspect),
+        ),
         (
             GetStorageStatistics::CMD,
             Box::new(wire::GetStorageStatistics::inspect),
         ),
+        (
+            GetProfileMetadata::CMD,
+            Box::new(profiles::ProfileMetadata::inspect),
+        ),
         (MacroMetadata::CMD, Box::new(macros::MacroMetadata::inspect)),
+        (
+            GetMacroMetadata::CMD,
+            Box::new(macros::MacroMetadata::inspect),
+        ),
         (
             MacroActionsPayload::CMD,
             Box::new(macros::MacroActionsPayload::inspect),
@@ -904,6 +1184,37 @@ mod tests {
         assert_eq!(brightness.serialize(), expected_100_pct);
     }
 
+    #[test]
+    fn test_get_brightness() {
+        let request = parse_wireshark_truncated("00:1f:00:00:00:03:0f:84:01", 0x89);
+        let request_cmd = GetLedBrightness(wire::SetLedBrightness {
+            profile: 0x01,
+            ..Default::default()
+        });
+        assert_eq!(request_cmd.serialize(), request);
+
+        // From the sniffs at the top of this file.
+        let response = parse_wireshark_truncated("02:1f:00:00:00:03:0f:84:01:05:ff", 0x73);
+        let response = Command::response(&request_cmd, &response).expect("success");
+        let response = response.downcast_ref::<GetLedBrightness>().unwrap();
+        assert_eq!(response.0.profile, 0x01);
+        assert_eq!(response.0.value, 0xff);
+        assert_eq!(
+            GetLedEffect::CMD,
+            Cmd {
+                major: 0x0f,
+                minor: 0x82
+            }
+        );
+        assert_eq!(
+            GetGameMode::CMD,
+            Cmd {
+                major: 0x03,
+                minor: 0x80
+            }
+        );
+    }
+
     #[test]
     fn test_set_game_mode() {
         let enable = parse_wireshark_truncated("00:1f:00:00:00:03:03:00:00:08:01", 0x09);
@@ -936,6 +1247,13 @@ mod tests {
         // 0x0508 adds the profile metadata / guid stuffs
         // 0x0588 u8 u16:  u8 profile id, u16 page.
         //
+        assert_eq!(
+            GetProfileMetadata::CMD,
+            Cmd {
+                major: 0x05,
+                minor: 0x88
+            }
+        );
         let _potential_list_profiles = parse_wireshark_truncated("00:1f:00:00:00:41:05:81", 0xc5);
 
         // Storage metric retrieval before write goes through 0x068e
@@ -944,9 +1262,7 @@ mod tests {
     #[test]
     fn test_get_storage() {
         let request = parse_wireshark_truncated("00:1f:00:00:00:0e:06:8e", 0x86);
-        let request_cmd: GetStorageStatistics = GetStorageStatistics {
-            ..Default::default()
-        };
+        let request_cmd: GetStorageStatistics = Default::default();
         assert_eq!(request_cmd.serialize(), request);
         let respons = parse_wireshark_truncated(
             "02:1f:00:00:00:0e:06:8e:ff:ff:00:01:8f:f0:00:01:8a:78:00:01:8a:78",
@@ -956,18 +1272,37 @@ mod tests {
         //                                                                    |102384     |100984     |100984     |
         // 100984 + 100984 = 201968
         assert_eq!(0x0e, std::mem::size_of::<wire::GetStorageStatistics>());
-        // println!("{:?}", &respons[PAYLOAD_START..]);
-        // TODO: Whelp, the endianness here is wrong :(
-        let decoded = wire::GetStorageStatistics::from_be_bytes(&respons[PAYLOAD_START..])
-            .expect("Should pass");
+        // The values are big endian; with that the total matches the log and the logged free is
+        // free1 + free2, which is why the percentage exceeds 100.
+        let response = Command::response(&request_cmd, &respons).expect("success");
+        let decoded = response.downcast_ref::<GetStorageStatistics>().unwrap().0;
         let something = decoded.something;
         let total = decoded.total;
+        let free1 = decoded.free1;
+        let free2 = decoded.free2;
         assert_eq!(something, 0xFFFF);
         assert_eq!(total, 102384);
-        // Either the log is lying, or the data is incorrrect.
-        // let respons2 = parse_wireshark_truncated("02:1f:00:00:00:0e:06:8e:ff:ff:00:01:8f:f0:00:01:8a:78:00:07:b2:08:");
+        assert_eq!(free1, 100984);
+        assert_eq!(free2, 100984);
+        assert_eq!(free1 + free2, 201968);
+
+        // The second capture, free2 is larger than the total here. Its log line has a different
+        // total and a free value that isn't free1 + free2, so it belongs to another read. What
+        // free2 holds is an open question, free1 is used as the free bytes because it is the only
+        // value below the total in both captures.
+        let respons2 = helpers::parse_wireshark_value(
+            "02:1f:00:00:00:0e:06:8e:ff:ff:00:01:8f:f0:00:01:8a:78:00:07:b2:08",
+        );
         // CRSy3_OnboardMem2::GetData: storage: max[102368], free[9075328], percent[8865.40]
-        // Aight.... lets assume logging and the current parsing is broken.
+        let decoded =
+            wire::GetStorageStatistics::from_be_bytes(&respons2[PAYLOAD_START..]).expect("success");
+        let total = decoded.total;
+        let free1 = decoded.free1;
+        let free2 = decoded.free2;
+        assert_eq!(total, 102384);
+        assert_eq!(free1, 100984);
+        assert_eq!(free2, 504328);
+        assert!(free1 <= total && free2 > total);
 
         // 0x06, 0x03; delete macro!
         let _delete_macro = parse_wireshark_truncated("00:1f:00:00:00:02:06:03:3b:02:00", 0x3e);
@@ -996,5 +1331,54 @@ mod tests {
             Command::response(&request_cmd, &right_ctrl_right_click_resp).expect("success");
         let response = response.downcast_ref::<SetKeyMap>().unwrap();
         assert_eq!((*response).0, request_cmd.0);
+
+        // The response echoes the mapping, it isn't the response to setting another key.
+        assert!(request_cmd.echoed_by(&right_ctrl_right_click_resp[PAYLOAD_START..]));
+        let mut other_key = request_cmd.0;
+        other_key.key.id = 0x41;
+        assert!(!SetKeyMap(other_key).echoed_by(&right_ctrl_right_click_resp[PAYLOAD_START..]));
+    }
+
+    #[test]
+    fn test_response_errors() {
+        let request_cmd: GetActiveMacroCount = Default::default();
+        let mut corrupted = parse_wireshark_truncated("02:1f:00:00:00:02:06:80:00:01", 0x85);
+        corrupted[PAYLOAD_START + 1] = 0x02; // corrupt the payload, checksum stays the same.
+        match Command::response(&request_cmd, &corrupted) {
+            Err(Error::ChecksumMismatch { expected, received }) => {
+                assert_eq!(received, 0x85);
+                assert_eq!(expected, 0x86);
+            }
+            z => panic!("Expected checksum mismatch, got {:?}", z),
+        }
+
+        // macro delete on a macro that doesn't exist returns 0x03, a plain failure.
+        let missing = parse_wireshark_truncated("03:1f:00:00:00:02:06:03:3b:02:00", 0x3e);
+        let request_cmd: MacroDelete = Default::default();
+        match Command::response(&request_cmd, &missing) {
+            Err(Error::Rejected { cmd, status }) => {
+                assert_eq!(cmd, MacroDelete::CMD);
+                assert_eq!(status, CommandStatus::Failure);
+            }
+            z => panic!("Expected rejection, got {:?}", z),
+        }
+
+        // Other commands report a failure for 0x03 as well.
+        let failed = parse_wireshark_truncated("03:1f:00:00:00:01:05:03:02", 0x05);
+        let request_cmd: ProfileDelete = Default::default();
+        assert_eq!(
+            request_cmd.status(&failed).expect("checksum ok"),
+            CommandStatus::Failure
+        );
+
+        let unknown = parse_wireshark_truncated("42:1f:00:00:00:02:06:03:3b:02:00", 0x3e);
+        let request_cmd: MacroDelete = Default::default();
+        assert!(matches!(
+            Command::response(&request_cmd, &unknown),
+            Err(Error::Rejected {
+                status: CommandStatus::Unknown(0x42),
+                ..
+            })
+        ));
     }
 }
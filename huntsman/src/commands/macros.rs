@@ -328,23 +328,171 @@ pub fn macro_events_to_payloads(
     payloads
 }
 
-#[derive(Inspectable, FromBytes, ToBytes, Default, Copy, Clone, Debug)]
+/// Parses the event bytes of a macro, as stored on the device, back into actions.
+pub fn macro_bytes_to_events(bytes: &[u8]) -> Result<Vec<MacroAction>, String> {
+    let mut events: Vec<MacroAction> = Vec::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let mut action: MacroAction = Default::default();
+        offset += action.from_bytes(&bytes[offset..], Endianness::Big)?;
+        events.push(action);
+    }
+    Ok(events)
+}
+
+#[derive(Inspectable, FromBytes, ToBytes, Default, Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(C)]
 pub struct Uuid {
     pub uuid: [u8; 16],
 }
 
-#[derive(Inspectable, FromBytes, ToBytes, Default, Copy, Clone, Debug)]
+impl Uuid {
+    /// Weights of the uuid bytes in the relation between the uuid and the macro id, see the notes
+    /// in the tests.
+    const MACRO_ID_FACTORS: [u16; 16] =
+        [1, 3, 2, 4, 7, 11, 16, 22, 29, 37, 46, 56, 67, 79, 92, 106];
+
+    /// The uuid the vendor software would give the macro. The relation is only known for uuids
+    /// that hold a single non-zero byte, so that's what is returned; ids that can't be expressed
+    /// that way get the zero uuid.
+    pub fn for_macro_id(macro_id: MacroId) -> Uuid {
+        let mut uuid: Uuid = Default::default();
+        let remainder = match macro_id.checked_sub(120) {
+            Some(v) if v != 0 => v,
+            _ => return uuid,
+        };
+        for (i, factor) in Uuid::MACRO_ID_FACTORS.iter().enumerate() {
+            if remainder % factor == 0 && remainder / factor <= 0xff {
+                uuid.uuid[i] = (remainder / factor) as u8;
+                break;
+            }
+        }
+        uuid
+    }
+}
+
+/// Write a name as null terminated string into the buffer, truncating it if it doesn't fit.
+pub(crate) fn name_to_bytes(name: &str, buffer: &mut [u8]) {
+    let mut len = std::cmp::min(name.len(), buffer.len().saturating_sub(1));
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+    buffer[..len].copy_from_slice(&name.as_bytes()[..len]);
+}
+
+/// Read a null terminated name from the buffer.
+pub(crate) fn name_from_bytes(buffer: &[u8]) -> String {
+    let end = buffer.iter().position(|b| *b == 0).unwrap_or(buffer.len());
+    String::from_utf8_lossy(&buffer[..end]).to_string()
+}
+
+impl std::fmt::Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, b) in self.uuid.iter().enumerate() {
+            if i == 4 || i == 6 || i == 8 || i == 10 {
+                write!(f, "-")?;
+            }
+            write!(f, "{:0>2x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Length of the macro metadata, it is written in chunks with [`MacroMetadata`].
+pub const MACRO_METADATA_SIZE: usize = 0xfa;
+
+/// Bytes of metadata in a single [`MacroMetadata`] command.
+const MACRO_METADATA_CHUNK: usize = 0x4a;
+
+#[derive(Inspectable, FromBytes, ToBytes, Copy, Clone, Debug)]
 #[repr(C, packed)]
-/// Command for the macro metadata 0x060c (incomplete!)
+/// Command for the macro metadata 0x060c, holds a chunk of the metadata, see [`MacroInfo`] for
+/// its contents. This layout is derived from the captures, it is not confirmed.
 pub struct MacroMetadata {
     pub macro_id: u16,
+    /// Byte offset of this chunk in the metadata.
     pub page_offset: u16,
+    /// The total length of the metadata, always [`MACRO_METADATA_SIZE`].
     pub something_always_0x00fa: u16,
+    #[inspect(dissect_additional_type = "bytes", dissection_hide = "true")]
+    pub data: [u8; MACRO_METADATA_CHUNK],
+}
+impl Default for MacroMetadata {
+    fn default() -> MacroMetadata {
+        MacroMetadata {
+            macro_id: 0,
+            page_offset: 0,
+            something_always_0x00fa: MACRO_METADATA_SIZE as u16,
+            data: [0; MACRO_METADATA_CHUNK],
+        }
+    }
+}
+
+/// The metadata the vendor software stores with a macro, the keyboard itself doesn't use it.
+/// It starts with the uuid, followed by the number of actions as little endian u32 and the
+/// null terminated name. What follows the name looks like dirty memory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MacroInfo {
     pub uuid: Uuid,
-    pub action_bytes: u32, // is this... another endianness!?!?
-                           // pub name: [u8; 12],    // It can be longer....
-                           // Lots of more stuff here, which looks... mostly like dirty memory
+    /// Number of actions in the macro.
+    pub event_count: u32,
+    pub name: String,
+}
+
+impl MacroInfo {
+    /// Offset of the name in the metadata.
+    const NAME_OFFSET: usize = 20;
+
+    /// Create the metadata for a macro, the uuid is derived from the macro id where possible, see
+    /// [`Uuid::for_macro_id`].
+    pub fn new(name: &str, macro_id: MacroId, events: &[MacroAction]) -> MacroInfo {
+        MacroInfo {
+            uuid: Uuid::for_macro_id(macro_id),
+            event_count: events.len() as u32,
+            name: name.to_string(),
+        }
+    }
+
+    /// The metadata as stored on the device, names that don't fit are truncated.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; MACRO_METADATA_SIZE];
+        bytes[..16].copy_from_slice(&self.uuid.uuid);
+        bytes[16..MacroInfo::NAME_OFFSET].copy_from_slice(&self.event_count.to_le_bytes());
+        name_to_bytes(&self.name, &mut bytes[MacroInfo::NAME_OFFSET..]);
+        bytes
+    }
+
+    /// Parse the metadata as stored on the device.
+    pub fn from_bytes(bytes: &[u8]) -> Result<MacroInfo, String> {
+        if bytes.len() < MacroInfo::NAME_OFFSET {
+            return Err(format!(
+                "Macro metadata of {} bytes is too short.",
+                bytes.len()
+            ));
+        }
+        let mut info: MacroInfo = Default::default();
+        info.uuid.uuid.copy_from_slice(&bytes[..16]);
+        let mut count = [0u8; 4];
+        count.copy_from_slice(&bytes[16..MacroInfo::NAME_OFFSET]);
+        info.event_count = u32::from_le_bytes(count);
+        info.name = name_from_bytes(&bytes[MacroInfo::NAME_OFFSET..]);
+        Ok(info)
+    }
+}
+
+/// Split the metadata of a macro into the chunks to send.
+pub fn macro_info_to_payloads(macro_id: MacroId, info: &MacroInfo) -> Vec<MacroMetadata> {
+    let mut payloads: Vec<MacroMetadata> = Vec::new();
+    for (i, chunk) in info.to_bytes().chunks(MACRO_METADATA_CHUNK).enumerate() {
+        let mut cmd = MacroMetadata {
+            macro_id,
+            page_offset: (i * MACRO_METADATA_CHUNK) as u16,
+            ..Default::default()
+        };
+        cmd.data[..chunk.len()].copy_from_slice(chunk);
+        payloads.push(cmd);
+    }
+    payloads
 }
 
 #[derive(Inspectable, FromBytes, ToBytes, Clone, Copy, Debug, Default)]
@@ -531,6 +679,13 @@ mod tests {
         copied_assert_eq!(payloads[0].event_bytes_in_msg, 0x48 as u8);
         copied_assert_eq!(payloads[1].event_bytes_in_msg, (total_bytes - 0x48) as u8);
         copied_assert_eq!(total_bytes, 20 * 2 * 2); // each mouse action is 2 bytes.
+
+        // And the chunks concatenated result in the original actions.
+        let mut bytes: Vec<u8> = Vec::new();
+        for payload in payloads.iter() {
+            bytes.extend(&payload.events[..payload.event_bytes_in_msg as usize]);
+        }
+        assert_eq!(macro_bytes_to_events(&bytes).expect("success"), actions);
     }
 
     fn print_serialize<T: Serialize + std::fmt::Debug>(v: T) -> String {
@@ -556,6 +711,70 @@ mod tests {
         print_deserialize::<MacroAction>(r#"{"keyboard_make":"KEY_A"}"#);
     }
 
+    #[test]
+    fn test_uuid_for_macro_id() {
+        assert_eq!(Uuid::for_macro_id(121).uuid[0], 1);
+        assert_eq!(Uuid::for_macro_id(120), Default::default());
+        assert_eq!(Uuid::for_macro_id(0x0001), Default::default());
+        // Every uuid that is returned satisfies the relation from the notes above.
+        for macro_id in 0..=MacroId::MAX {
+            let uuid = Uuid::for_macro_id(macro_id);
+            if uuid == Default::default() {
+                continue;
+            }
+            assert_eq!(uuid.uuid.iter().filter(|b| **b != 0).count(), 1);
+            let sum: u32 = uuid
+                .uuid
+                .iter()
+                .zip(Uuid::MACRO_ID_FACTORS.iter())
+                .map(|(b, f)| *b as u32 * *f as u32)
+                .sum();
+            assert_eq!(sum + 120, macro_id as u32);
+        }
+    }
+
+    #[test]
+    fn test_macro_metadata() {
+        // Metadata of a macro named 'aaa' with six actions, as set by the vendor software.
+        let captured = parse_wireshark_value(
+            "eb:69:30:b1:54:e6:e2:49:8b:6c:20:41:37:cc:47:86:06:00:00:00:61:61:61:00:00:00:00:00:00:00:00:00:50:71:10:06:00",
+        );
+        let info = MacroInfo::from_bytes(&captured).expect("success");
+        assert_eq!(info.name, "aaa");
+        assert_eq!(info.event_count, 6);
+        assert_eq!(
+            format!("{}", info.uuid),
+            "eb6930b1-54e6-e249-8b6c-204137cc4786"
+        );
+        assert_eq!(&info.to_bytes()[..24], &captured[..24]);
+
+        let events = vec![
+            MacroAction::KeyboardMake { hid: 0x04 },
+            MacroAction::KeyboardBreak { hid: 0x04 },
+        ];
+        let info = MacroInfo::new("paste_sig", 0x1337, &events);
+        assert_eq!(info.uuid, Uuid::for_macro_id(0x1337));
+        assert_eq!(info.event_count, 2);
+
+        // Split in chunks and back.
+        let payloads = macro_info_to_payloads(0x1337, &info);
+        copied_assert_eq!(payloads.len(), 4);
+        copied_assert_eq!(payloads[1].page_offset, MACRO_METADATA_CHUNK as u16);
+        copied_assert_eq!(payloads[3].something_always_0x00fa, 0xfa);
+        let mut bytes: Vec<u8> = Vec::new();
+        for payload in payloads.iter() {
+            bytes.extend(&payload.data);
+        }
+        assert_eq!(MacroInfo::from_bytes(&bytes).expect("success"), info);
+        let wire = payloads[0].to_be_bytes().expect("success");
+        assert_eq!(&wire[..6], &[0x13, 0x37, 0x00, 0x00, 0x00, 0xfa]);
+
+        // Long names are truncated to fit.
+        let long = MacroInfo::new(&"é".repeat(200), 0x1337, &events);
+        let parsed = MacroInfo::from_bytes(&long.to_bytes()).expect("success");
+        assert_eq!(parsed.name, "é".repeat(114));
+    }
+
     #[test]
     pub fn test_macro_get_list() {
         let expected =
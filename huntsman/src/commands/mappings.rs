@@ -1,14 +1,13 @@
 use serde::{Deserialize, Serialize};
-use struct_helper::{offset_of, Endianness, FromBytes, ToBytes};
+use struct_helper::{Endianness, FromBytes, ToBytes};
 
 use crate::hut_util::{
-    at101_deserialize, at101_serialize, keyboard_page_deserialize, keyboard_page_serialize,
+    at101_deserialize, at101_serialize, consumer_page_deserialize, consumer_page_serialize,
+    keyboard_page_deserialize, keyboard_page_serialize, name_to_usage, usage_to_name,
 };
 
 /// Struct to denote a physical key on the keyboard.
-#[derive(
-    Debug, Clone, Copy, Default, FromBytes, ToBytes, PartialEq, Eq, Deserialize, Serialize,
-)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Key {
     /// The key's at101 code, or whatever the keyboard uses to denote it.
     #[serde(
@@ -21,8 +20,28 @@ pub struct Key {
     pub hypershift: bool,
 }
 
+// Written by hand, the derive places the fields by their offset in memory and the compiler is free
+// to put hypershift first.
+impl FromBytes for Key {
+    fn from_bytes(&mut self, src: &[u8], endianness: Endianness) -> Result<usize, String> {
+        let mut offset: usize = 0;
+        offset += self.id.from_bytes(&src[0..1], endianness)?;
+        offset += self.hypershift.from_bytes(&src[1..2], endianness)?;
+        Ok(offset)
+    }
+}
+
+impl ToBytes for Key {
+    fn to_bytes(&self, endianness: Endianness) -> Result<Vec<u8>, String> {
+        let mut buff: Vec<u8> = vec![];
+        buff.extend(self.id.to_bytes(endianness)?);
+        buff.extend(self.hypershift.to_bytes(endianness)?);
+        Ok(buff)
+    }
+}
+
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Deserialize, Serialize)]
-/// Enum to represent the possible modifiers.
+/// Enum to represent the possible modifiers, the values are the bits in the HID modifier byte.
 #[serde(rename_all = "snake_case")]
 pub enum Modifier {
     None = 0x00,
@@ -32,12 +51,38 @@ pub enum Modifier {
     LeftShift = 0x02,
     #[serde(alias = "alt", alias = "Alt")]
     LeftAlt = 0x04,
+    #[serde(
+        alias = "gui",
+        alias = "super",
+        alias = "win",
+        alias = "meta",
+        alias = "left_super",
+        alias = "left_win",
+        alias = "left_meta"
+    )]
+    LeftGui = 0x08,
     RightControl = 0x10,
     RightShift = 0x20,
     RightAlt = 0x40,
+    #[serde(alias = "right_super", alias = "right_win", alias = "right_meta")]
+    RightGui = 0x80,
+}
+// ^ Right modifier bitmask, 0x1=ctrl, 0x2=shift, 0x4 = alt, 0x8 = gui
+//  ^ Left modifier bitmask, 0x1=ctrl, 0x2=shift, 0x4 = alt, 0x8 = gui
+
+impl Modifier {
+    /// All modifiers, in the order of their bits.
+    pub const ALL: [Modifier; 8] = [
+        Modifier::LeftControl,
+        Modifier::LeftShift,
+        Modifier::LeftAlt,
+        Modifier::LeftGui,
+        Modifier::RightControl,
+        Modifier::RightShift,
+        Modifier::RightAlt,
+        Modifier::RightGui,
+    ];
 }
-// ^ Right modifier bitmask, 0x1=ctrl, 0x2=shift, 0x4 = alt
-//  ^ Left modifier bitmask, 0x1=ctrl, 0x2=shift, 0x4 = alt
 
 impl Default for Modifier {
     fn default() -> Self {
@@ -46,23 +91,15 @@ impl Default for Modifier {
 }
 
 type ModifiersVec = Vec<Modifier>;
-#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
-/// A container to hold multiple modifiers.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// The set of modifiers held down with a key, this is the HID modifier byte; each bit is one of
+/// the [`Modifier`]s. Serializes as the list of modifier names.
 #[serde(from = "ModifiersVec", into = "ModifiersVec")]
-pub struct Modifiers([Modifier; 8]); // this array is just shennenigans to make it copyable.
+pub struct Modifiers(u8);
 
 impl Modifiers {
     pub const fn none() -> Modifiers {
-        Modifiers([
-            Modifier::None,
-            Modifier::None,
-            Modifier::None,
-            Modifier::None,
-            Modifier::None,
-            Modifier::None,
-            Modifier::None,
-            Modifier::None,
-        ])
+        Modifiers(0)
     }
     pub const fn control() -> Modifiers {
         Self::with(Modifier::LeftControl)
@@ -76,45 +113,50 @@ impl Modifiers {
         Self::with(Modifier::LeftShift)
     }
 
+    pub const fn gui() -> Modifiers {
+        Self::with(Modifier::LeftGui)
+    }
+
     pub const fn with(m: Modifier) -> Modifiers {
-        let mut r: Modifiers = Modifiers::none();
-        r.0[0] = m;
-        r
+        Modifiers(m as u8)
+    }
+
+    /// The modifier byte.
+    pub const fn bits(&self) -> u8 {
+        self.0
     }
 
     /// Check if any modifier is set.
     pub fn is_any(&self) -> bool {
-        u8::from(*self) != 0
+        self.0 != 0
     }
 
-    /// Check if only the specified modifier is set (once).
+    /// Check if only the specified modifier is set.
     pub fn is_only(&self, m: Modifier) -> bool {
-        let mut found: bool = false;
-        for v in self.0.iter() {
-            if *v == Modifier::None {
-                continue;
-            }
+        m != Modifier::None && self.0 == m as u8
+    }
 
-            if *v == m {
-                found = true;
-            } else {
-                return false;
-            }
-        }
-        found
+    /// Check if the specified modifier is set.
+    pub fn contains(&self, m: Modifier) -> bool {
+        m != Modifier::None && (self.0 & m as u8) != 0
     }
 
     /// Add a modifier.
     pub fn push(&mut self, m: Modifier) {
-        for (i, v) in self.0.iter().enumerate() {
-            if *v == Modifier::None {
-                self.0[i] = m;
-                return;
-            }
-            if *v == m {
-                return;
-            }
-        }
+        self.0 |= m as u8;
+    }
+
+    /// Remove a modifier.
+    pub fn remove(&mut self, m: Modifier) {
+        self.0 &= !(m as u8);
+    }
+
+    /// The modifiers that are set, in the order of their bits.
+    pub fn iter(&self) -> impl Iterator<Item = Modifier> + '_ {
+        Modifier::ALL
+            .iter()
+            .copied()
+            .filter(move |m| self.contains(*m))
     }
 }
 
@@ -127,22 +169,14 @@ impl std::fmt::Debug for Modifiers {
 
 impl From<Modifiers> for ModifiersVec {
     fn from(v: Modifiers) -> ModifiersVec {
-        let mut r: ModifiersVec = Default::default();
-        for e in v.0.iter() {
-            if *e != Modifier::None {
-                r.push(*e)
-            }
-        }
-        r
+        v.iter().collect()
     }
 }
 impl From<ModifiersVec> for Modifiers {
     fn from(v: ModifiersVec) -> Modifiers {
         let mut r: Modifiers = Default::default();
         for e in v.iter() {
-            if *e != Modifier::None {
-                r.push(*e)
-            }
+            r.push(*e)
         }
         r
     }
@@ -150,35 +184,24 @@ impl From<ModifiersVec> for Modifiers {
 
 impl From<u8> for Modifiers {
     fn from(encoded: u8) -> Self {
-        let mut res: Modifiers = Default::default();
-        if (encoded & (Modifier::LeftControl as u8)) != 0 {
-            res.push(Modifier::LeftControl);
-        }
-        if (encoded & (Modifier::LeftShift as u8)) != 0 {
-            res.push(Modifier::LeftShift);
-        }
-        if (encoded & (Modifier::LeftAlt as u8)) != 0 {
-            res.push(Modifier::LeftAlt);
-        }
-        if (encoded & (Modifier::RightControl as u8)) != 0 {
-            res.push(Modifier::RightControl);
-        }
-        if (encoded & (Modifier::RightShift as u8)) != 0 {
-            res.push(Modifier::RightShift);
-        }
-        if (encoded & (Modifier::RightAlt as u8)) != 0 {
-            res.push(Modifier::RightAlt);
-        }
-        return res;
+        Modifiers(encoded)
     }
 }
 impl From<Modifiers> for u8 {
     fn from(item: Modifiers) -> Self {
-        let mut res: u8 = 0;
-        for v in &item.0 {
-            res |= *v as u8;
-        }
-        res
+        item.0
+    }
+}
+
+impl FromBytes for Modifiers {
+    fn from_bytes(&mut self, src: &[u8], endianness: Endianness) -> Result<usize, String> {
+        self.0.from_bytes(src, endianness)
+    }
+}
+
+impl ToBytes for Modifiers {
+    fn to_bytes(&self, endianness: Endianness) -> Result<Vec<u8>, String> {
+        self.0.to_bytes(endianness)
     }
 }
 
@@ -204,21 +227,308 @@ pub enum MouseButton {
     M4 = 4,
     M5 = 5,
 }
-impl From<u8> for MouseButton {
-    fn from(encoded: u8) -> Self {
+impl std::convert::TryFrom<u8> for MouseButton {
+    type Error = String;
+    fn try_from(encoded: u8) -> Result<Self, Self::Error> {
         match encoded {
-            _z if (encoded == MouseButton::Left as u8) => MouseButton::Left,
-            _z if (encoded == MouseButton::Right as u8) => MouseButton::Right,
-            _z if (encoded == MouseButton::Scroll as u8) => MouseButton::Scroll,
-            _z if (encoded == MouseButton::M4 as u8) => MouseButton::M4,
-            _z if (encoded == MouseButton::M5 as u8) => MouseButton::M5,
-            _ => panic!("couldn't convert mouse button"),
+            _z if (encoded == MouseButton::Left as u8) => Ok(MouseButton::Left),
+            _z if (encoded == MouseButton::Right as u8) => Ok(MouseButton::Right),
+            _z if (encoded == MouseButton::Scroll as u8) => Ok(MouseButton::Scroll),
+            _z if (encoded == MouseButton::M4 as u8) => Ok(MouseButton::M4),
+            _z if (encoded == MouseButton::M5 as u8) => Ok(MouseButton::M5),
+            _ => Err(format!("Couldn't convert mouse button 0x{:02x}", encoded)),
         }
     }
 }
 
 pub type MacroId = u16;
 
+/// The payload of a [`KeyMapping::Unknown`] mapping. The key map commands are 0x0a bytes long,
+/// after the profile, key, type and length that leaves at most 5 bytes for the payload. This is
+/// written as hex bytes in the configuration, like "01 3b".
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RawPayload {
+    len: u8,
+    data: [u8; RawPayload::MAX_LEN],
+}
+
+impl RawPayload {
+    pub const MAX_LEN: usize = 5;
+
+    /// Create a payload from the bytes, errors if there are more than [`RawPayload::MAX_LEN`].
+    pub fn new(bytes: &[u8]) -> Result<RawPayload, String> {
+        if bytes.len() > RawPayload::MAX_LEN {
+            return Err(format!(
+                "Payload of {} bytes is longer than the {} that fit.",
+                bytes.len(),
+                RawPayload::MAX_LEN
+            ));
+        }
+        let mut data = [0; RawPayload::MAX_LEN];
+        data[..bytes.len()].copy_from_slice(bytes);
+        Ok(RawPayload {
+            len: bytes.len() as u8,
+            data,
+        })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl std::fmt::Debug for RawPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self)
+    }
+}
+
+impl std::fmt::Display for RawPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bytes: Vec<String> = self
+            .as_slice()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        write!(f, "{}", bytes.join(" "))
+    }
+}
+
+impl std::str::FromStr for RawPayload {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s
+            .split_whitespace()
+            .map(|b| {
+                u8::from_str_radix(b.trim_start_matches("0x"), 16)
+                    .map_err(|e| format!("Couldn't parse {:?} as hex byte: {}", b, e))
+            })
+            .collect::<Result<Vec<u8>, String>>()?;
+        RawPayload::new(&bytes)
+    }
+}
+
+impl Serialize for RawPayload {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for RawPayload {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+fn hex_serialize<S: serde::Serializer>(v: &u8, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("0x{:02x}", v))
+}
+
+fn hex_deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u8, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum HexOrCode {
+        Hex(String),
+        Code(u8),
+    }
+    match HexOrCode::deserialize(deserializer)? {
+        HexOrCode::Code(v) => Ok(v),
+        HexOrCode::Hex(s) => u8::from_str_radix(s.trim_start_matches("0x"), 16)
+            .map_err(|e| serde::de::Error::custom(format!("Couldn't parse {:?}: {}", s, e))),
+    }
+}
+
+/// Defines an enum for the single byte payload of a mapping. The values that are understood get
+/// a variant and a name, other values are held by the `Raw` variant. It serializes as the name,
+/// or as the number for raw values. The first name is the one that is written, the others are
+/// accepted when reading. If the usages of the HID page are provided, raw values use the names
+/// of the page.
+macro_rules! mapping_code_enum {
+    (@usages) => {
+        &[]
+    };
+    (@usages $usages:expr) => {
+        $usages
+    };
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident $(($usages:expr))? {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $code:literal => [$($variant_name:literal),+],
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value that doesn't have a name (yet).
+            Raw(u8),
+        }
+
+        impl $name {
+            /// The variants that have a name.
+            pub const NAMED: &'static [$name] = &[$($name::$variant),*];
+
+            /// The name this value is serialized as, None for raw values.
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $($name::$variant => Some([$($variant_name),+][0]),)*
+                    $name::Raw(_) => None,
+                }
+            }
+
+            /// Look up a value by any of its names, or the name from the HID page, ignoring case.
+            /// The HID page name of a value that has a variant isn't accepted, the variant's name
+            /// is what it serializes as.
+            pub fn from_name(name: &str) -> Option<$name> {
+                $(
+                    if [$($variant_name),+].iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                        return Some($name::$variant);
+                    }
+                )*
+                match $name::page_value(name) {
+                    Some(v @ $name::Raw(_)) => Some(v),
+                    _ => None,
+                }
+            }
+
+            /// The value of a name from the HID page.
+            fn page_value(name: &str) -> Option<$name> {
+                match name_to_usage($name::page_usages(), name) {
+                    Some(v) if v <= 0xff => Some((v as u8).into()),
+                    _ => None,
+                }
+            }
+
+            /// The usages of the HID page these values are from, if any.
+            fn page_usages() -> &'static [usb_hut::PageUsage] {
+                mapping_code_enum!(@usages $($usages)?)
+            }
+        }
+
+        impl From<u8> for $name {
+            fn from(encoded: u8) -> Self {
+                match encoded {
+                    $($code => $name::$variant,)*
+                    v => $name::Raw(v),
+                }
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(item: $name) -> Self {
+                match item {
+                    $($name::$variant => $code,)*
+                    $name::Raw(v) => v,
+                }
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let v = u8::from(*self);
+                match self.name() {
+                    Some(name) => serializer.serialize_str(name),
+                    None => match usage_to_name($name::page_usages(), v as usize) {
+                        Some(name) => serializer.serialize_str(&name),
+                        None => serializer.serialize_u8(v),
+                    },
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(Deserialize)]
+                #[serde(untagged)]
+                enum NameOrCode {
+                    Name(String),
+                    Code(u8),
+                }
+                match NameOrCode::deserialize(deserializer)? {
+                    NameOrCode::Code(v) => Ok(v.into()),
+                    NameOrCode::Name(name) => $name::from_name(&name).ok_or_else(|| {
+                        if let Some(named) = $name::page_value(&name).and_then(|v| v.name()) {
+                            return serde::de::Error::custom(format!(
+                                "{} {:?} is called {:?}",
+                                stringify!($name),
+                                name,
+                                named
+                            ));
+                        }
+                        let names: Vec<&str> = $name::NAMED.iter().filter_map(|v| v.name()).collect();
+                        serde::de::Error::custom(format!(
+                            "unknown {} {:?}, expected one of {} or a number",
+                            stringify!($name),
+                            name,
+                            names.join(", ")
+                        ))
+                    }),
+                }
+            }
+        }
+    };
+}
+
+mapping_code_enum! {
+    /// The keyboard functions of [`KeyMapping::Special`], these only work on the hypershift layer.
+    pub enum SpecialFunction {
+        /// Toggle game mode, on f10 by default.
+        GameMode = 0x03 => ["game_mode", "toggle_game_mode"],
+        /// Start or stop recording an on-the-fly macro, on f9 by default.
+        MacroRecord = 0x04 => ["macro_record", "otf_macro"],
+        /// Increase the led brightness, on f12 by default.
+        BrightnessUp = 0x08 => ["brightness_up"],
+        /// Decrease the led brightness, on f11 by default.
+        BrightnessDown = 0x09 => ["brightness_down"],
+    }
+}
+
+mapping_code_enum! {
+    /// Switching between the profiles on the device, [`KeyMapping::ProfileInstruction`]. The
+    /// device refuses 0x03 and 0x06.
+    pub enum ProfileInstruction {
+        /// Switch to the next profile, wrapping around; cyan goes to white. On hypershift +
+        /// application by default. Cycling through the profiles is this same device value, there is
+        /// no separate cycle instruction, so `cycle` is accepted as another name for it.
+        Next = 0x04 => ["next", "cycle"],
+        /// Switch to the previous profile; cyan goes to blue.
+        Previous = 0x05 => ["previous"],
+    }
+}
+
+mapping_code_enum! {
+    /// The system controls of the Generic Desktop page (0x01), [`KeyMapping::GenericDesktop`].
+    pub enum GenericDesktopUsage(usb_hut::hid_generic_desktop_page::usages()) {
+        SystemPowerDown = 0x81 => ["system_power_down", "power"],
+        /// On hypershift + pause by default.
+        SystemSleep = 0x82 => ["system_sleep", "sleep"],
+        SystemWakeUp = 0x83 => ["system_wake_up", "wake"],
+    }
+}
+
+mapping_code_enum! {
+    /// Mouse buttons of the Button page (0x09), [`KeyMapping::ButtonPage`].
+    pub enum ButtonPageUsage(usb_hut::hid_button_page::usages()) {
+        /// Emits a double click of the left mouse button.
+        DoubleClick = 0x01 => ["double_click"],
+    }
+}
+
 /// Represent particular mapping for a physical key on the keyboard to produce any of the outputs
 /// from this enum.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -238,22 +548,36 @@ pub enum KeyMapping {
     MacroRepeat(MacroId),
     /// Macro toggle.
     MacroToggle(MacroId),
-    /// Emits a multimedia hid code (Consumer Page 0x0c?)
-    MultiMedia(u16),
-    /// Emits from button page 0x09? Only seen for doubleclick.
-    ButtonPage(u8),
+    /// Emits a multimedia hid code from the Consumer Page (0x0c), by name or number.
+    MultiMedia(
+        #[serde(
+            serialize_with = "consumer_page_serialize",
+            deserialize_with = "consumer_page_deserialize"
+        )]
+        u16,
+    ),
+    /// Emits from the Button page (0x09), see [`ButtonPageUsage`].
+    ButtonPage(ButtonPageUsage),
     /// Repeats mouse clicks using the provided interval.
     TurboMouse { button: MouseButton, interval: u16 },
     /// Repeats keys using the provided interval.
     TurboKey { key: KeyboardKey, interval: u16 },
-    /// Magical special keys, led brightness, game mode etc.
-    Special(u8),
-    /// Generic desktop page 0x01 (System Sleep)
-    GenericDesktop(u8),
-    /// Profile instruction? Seen once in hypershift + application profile cycle (0x04)
-    ProfileInstruction(u8),
+    /// A keyboard function such as toggling game mode or the led brightness, see
+    /// [`SpecialFunction`].
+    Special(SpecialFunction),
+    /// Emits a system control from the Generic Desktop page (0x01), see [`GenericDesktopUsage`].
+    GenericDesktop(GenericDesktopUsage),
+    /// Switches the active profile, see [`ProfileInstruction`].
+    ProfileInstruction(ProfileInstruction),
     /// Maps to enable hypershift when pressed, seems to take 1 byte payload, only seen as 0x01.
     Hypershift,
+    /// A mapping this crate can't decode, holding the type and payload bytes as they were read
+    /// from the device such that they can be written back unchanged.
+    Unknown {
+        #[serde(serialize_with = "hex_serialize", deserialize_with = "hex_deserialize")]
+        kind: u8,
+        payload: RawPayload,
+    },
 }
 
 impl KeyMapping {
@@ -263,7 +587,6 @@ impl KeyMapping {
     const MAP_MACRO: u8 = 0x03;
     const MAP_MACRO_REPEAT: u8 = 0x04;
     const MAP_MACRO_TOGGLE: u8 = 0x05;
-
     const MAP_PROFILE_INSTRUCTION: u8 = 0x07;
 
     const MAP_GENERIC_DESKTOP: u8 = 0x09; // HID page 0x01
@@ -282,10 +605,35 @@ These would also seem... potentially useful when making a gaming keyboard?
 */
 
 impl FromBytes for KeyMapping {
+    /// Mappings that can't be decoded become [`KeyMapping::Unknown`], such that they can be
+    /// written back as they were.
     fn from_bytes(&mut self, src: &[u8], _endianness: Endianness) -> Result<usize, String>
     where
         Self: Sized,
     {
+        if src.len() < 2 || src.len() < 2 + src[1] as usize {
+            return Err(format!(
+                "Mapping doesn't fit in the {} bytes provided.",
+                src.len()
+            ));
+        }
+        match self.decode_known(src) {
+            Ok(used) => Ok(used),
+            Err(_) => {
+                *self = KeyMapping::Unknown {
+                    kind: src[0],
+                    payload: RawPayload::new(&src[2..2 + src[1] as usize])?,
+                };
+                Ok(2 + src[1] as usize)
+            }
+        }
+    }
+}
+
+impl KeyMapping {
+    /// Decode the mapping types that are understood, errors for anything else.
+    fn decode_known(&mut self, src: &[u8]) -> Result<usize, String> {
+        use std::convert::TryInto;
         let specification = src[0];
         let len_byte = src[1];
         match specification {
@@ -314,7 +662,7 @@ impl FromBytes for KeyMapping {
                         1, len_byte
                     ));
                 }
-                *self = KeyMapping::Mouse(src[2].into());
+                *self = KeyMapping::Mouse(src[2].try_into()?);
 
                 return Ok(3);
             }
@@ -392,7 +740,7 @@ impl FromBytes for KeyMapping {
                 let x: [u8; 2] = [src[4], src[3]];
                 let repeat_interval = u16::from_le_bytes(x);
                 *self = KeyMapping::TurboMouse {
-                    button: src[2].into(),
+                    button: src[2].try_into()?,
                     interval: repeat_interval,
                 };
 
@@ -460,16 +808,19 @@ impl FromBytes for KeyMapping {
                 }
                 let res = src[2];
                 if res != 0x01 {
-                    panic!(
+                    return Err(format!(
                         "Hypershift class detected, but value is not 0x01, got 0x{:x}, full: {:?}",
                         res, src
-                    );
+                    ));
                 }
                 *self = KeyMapping::Hypershift;
 
                 return Ok(3);
             }
-            z => panic!("Unhandled keymap code {:?}, total src: {:?}", z, src),
+            z => Err(format!(
+                "Unhandled keymap code {:?}, total src: {:?}",
+                z, src
+            )),
         }
     }
 }
@@ -525,7 +876,7 @@ impl ToBytes for KeyMapping {
             KeyMapping::ButtonPage(v) => {
                 buff.push(KeyMapping::MAP_BUTTON_PAGE);
                 buff.push(1);
-                buff.push(*v);
+                buff.push((*v).into());
             }
             KeyMapping::TurboMouse { button, interval } => {
                 buff.push(KeyMapping::MAP_TURBO_MOUSE);
@@ -547,23 +898,28 @@ impl ToBytes for KeyMapping {
             KeyMapping::Special(id) => {
                 buff.push(KeyMapping::MAP_SPECIAL);
                 buff.push(1);
-                buff.push(*id as u8);
+                buff.push((*id).into());
             }
             KeyMapping::GenericDesktop(id) => {
                 buff.push(KeyMapping::MAP_GENERIC_DESKTOP);
                 buff.push(1);
-                buff.push(*id as u8);
+                buff.push((*id).into());
             }
             KeyMapping::ProfileInstruction(id) => {
                 buff.push(KeyMapping::MAP_PROFILE_INSTRUCTION);
                 buff.push(1);
-                buff.push(*id as u8);
+                buff.push((*id).into());
             }
             KeyMapping::Hypershift => {
                 buff.push(KeyMapping::MAP_HYPERSHIFT);
                 buff.push(1);
                 buff.push(1);
             }
+            KeyMapping::Unknown { kind, payload } => {
+                buff.push(*kind);
+                buff.push(payload.len() as u8);
+                buff.extend(payload.as_slice());
+            }
         }
         Ok(buff)
     }
@@ -752,7 +1108,7 @@ mod tests {
             parse_wireshark_truncated("00:1f:00:00:00:0a:02:0d:01:40:00:0b:01:01:00", 0x4f);
         let res = test_keymap_roundtrip(&right_control_double_click);
         if let KeyMapping::ButtonPage(hid_id) = res.mapping {
-            assert_eq!(hid_id, 0x01);
+            assert_eq!(hid_id, ButtonPageUsage::DoubleClick);
         } else {
             assert_eq!(true, false);
         }
@@ -788,7 +1144,7 @@ mod tests {
             parse_wireshark_truncated("02:1f:00:00:00:06:02:8d:04:78:01:11:01:04:00", 0xe0);
         let res = test_keymap_roundtrip(&f9_otf_macro);
         if let KeyMapping::Special(id) = res.mapping {
-            assert_eq!(id, 0x04);
+            assert_eq!(id, SpecialFunction::MacroRecord);
         } else {
             assert_eq!(true, false);
         }
@@ -798,7 +1154,7 @@ mod tests {
             parse_wireshark_truncated("02:1f:00:00:00:06:02:8d:04:7e:01:09:01:82:00", 0x78);
         let res = test_keymap_roundtrip(&pause_sleep);
         if let KeyMapping::GenericDesktop(id) = res.mapping {
-            assert_eq!(id, 0x82);
+            assert_eq!(id, GenericDesktopUsage::SystemSleep);
         } else {
             assert_eq!(true, false);
         }
@@ -808,7 +1164,7 @@ mod tests {
             parse_wireshark_truncated("02:1f:00:00:00:06:02:8d:01:81:01:07:01:04:00", 0x0a);
         let res = test_keymap_roundtrip(&hypershift_application_cycle_profile);
         if let KeyMapping::ProfileInstruction(id) = res.mapping {
-            assert_eq!(id, 0x04);
+            assert_eq!(id, ProfileInstruction::Next);
         } else {
             assert_eq!(true, false);
         }
@@ -895,4 +1251,176 @@ mod tests {
         print_deserialize::<KeyboardKey>(r#"{"id":"KEY_3","modifiers":["left_control"]}"#);
         print_deserialize::<KeyboardKey>(r#"{"id":"3","modifiers":["left_control", "alt"]}"#);
     }
+
+    #[test]
+    fn test_mappings_unknown() {
+        // A type that isn't known, a mouse button that doesn't exist and a hypershift mapping
+        // with a different value are all kept as they are.
+        for bytes in [
+            vec![0x13, 0x02, 0x01, 0x3b],
+            vec![KeyMapping::MAP_MOUSE, 0x01, 0x09],
+            vec![KeyMapping::MAP_HYPERSHIFT, 0x01, 0x02],
+            vec![0x10, 0x00],
+        ]
+        .iter()
+        {
+            let mapping = KeyMapping::from_be_bytes(bytes).expect("success");
+            if let KeyMapping::Unknown { kind, payload } = mapping {
+                assert_eq!(kind, bytes[0]);
+                assert_eq!(payload.as_slice(), &bytes[2..]);
+            } else {
+                panic!("Expected unknown mapping, got {:?}", mapping);
+            }
+            assert_eq!(&mapping.to_be_bytes().expect("success"), bytes);
+
+            // Through the configuration and back.
+            let yaml = serde_yaml::to_string(&mapping).unwrap();
+            let back: KeyMapping = serde_yaml::from_str(&yaml).unwrap();
+            assert_eq!(back, mapping);
+        }
+
+        let mapping = KeyMapping::Unknown {
+            kind: 0x13,
+            payload: "01 3b".parse().expect("success"),
+        };
+        let yaml = serde_yaml::to_string(&mapping).unwrap();
+        assert!(yaml.contains("kind: \"0x13\""));
+        assert!(yaml.contains("payload: 01 3b"));
+        let read: KeyMapping =
+            serde_yaml::from_str("unknown: { kind: 0x13, payload: \"01 3b\" }").unwrap();
+        assert_eq!(read, mapping);
+
+        // As part of a full key map, as read from the device.
+        let key_map_bytes = [0x01, 0x40, 0x00, 0x13, 0x02, 0x01, 0x3b, 0x00, 0x00, 0x00];
+        let key_map = KeyMap::from_be_bytes(&key_map_bytes).expect("success");
+        assert_eq!(key_map.mapping, mapping);
+        assert_eq!(key_map.to_be_bytes().expect("success"), key_map_bytes);
+
+        // Payloads that don't fit are refused, not truncated.
+        assert!(KeyMapping::from_be_bytes(&[0x13, 0x06, 1, 2, 3, 4, 5, 6]).is_err());
+        assert!(KeyMapping::from_be_bytes(&[0x13, 0x04, 1, 2]).is_err());
+        assert!("01 02 03 04 05 06".parse::<RawPayload>().is_err());
+    }
+
+    #[test]
+    fn test_mappings_code_enums() {
+        assert_eq!(
+            print_serialize(KeyMapping::Special(SpecialFunction::BrightnessUp)),
+            r#"{"special":"brightness_up"}"#
+        );
+        assert_eq!(
+            print_serialize(KeyMapping::Special(SpecialFunction::Raw(0x42))),
+            r#"{"special":66}"#
+        );
+        assert_eq!(
+            print_deserialize::<KeyMapping>(r#"{"profile_instruction":"cycle"}"#),
+            KeyMapping::ProfileInstruction(ProfileInstruction::Next)
+        );
+        assert_eq!(
+            print_deserialize::<KeyMapping>(r#"{"generic_desktop":130}"#),
+            KeyMapping::GenericDesktop(GenericDesktopUsage::SystemSleep)
+        );
+        assert_eq!(
+            print_deserialize::<KeyMapping>(r#"{"button_page":"Double_Click"}"#),
+            KeyMapping::ButtonPage(ButtonPageUsage::DoubleClick)
+        );
+        assert!(serde_json::from_str::<KeyMapping>(r#"{"special":"brightness"}"#).is_err());
+
+        for v in SpecialFunction::NAMED.iter() {
+            assert_eq!(SpecialFunction::from(u8::from(*v)), *v);
+            assert_eq!(SpecialFunction::from_name(v.name().unwrap()), Some(*v));
+        }
+        assert_eq!(u8::from(SpecialFunction::from(0x42)), 0x42);
+
+        // Values without a variant use the names of the HID page.
+        assert_eq!(
+            print_serialize(KeyMapping::GenericDesktop(GenericDesktopUsage::Raw(0x84))),
+            r#"{"generic_desktop":"system_context_menu"}"#
+        );
+        assert_eq!(
+            print_deserialize::<KeyMapping>(r#"{"generic_desktop":"system_context_menu"}"#),
+            KeyMapping::GenericDesktop(GenericDesktopUsage::Raw(0x84))
+        );
+        assert_eq!(
+            print_serialize(KeyMapping::ButtonPage(ButtonPageUsage::Raw(0x02))),
+            r#"{"button_page":"button_2"}"#
+        );
+        assert_eq!(
+            print_deserialize::<KeyMapping>(r#"{"button_page":"button_2"}"#),
+            KeyMapping::ButtonPage(ButtonPageUsage::Raw(0x02))
+        );
+        // The page name of a value that has a variant is refused, instead of changing its name.
+        assert_eq!(ButtonPageUsage::from_name("button_1"), None);
+        let err = serde_json::from_str::<KeyMapping>(r#"{"button_page":"button_1"}"#);
+        assert!(format!("{}", err.unwrap_err()).contains("double_click"));
+        assert_eq!(
+            print_serialize(KeyMapping::MultiMedia(0xe9)),
+            r#"{"multi_media":"volume_increment"}"#
+        );
+        assert_eq!(
+            print_deserialize::<KeyMapping>(r#"{"multi_media":"al_calculator"}"#),
+            KeyMapping::MultiMedia(0x192)
+        );
+        assert_eq!(
+            print_deserialize::<KeyMapping>(r#"{"multi_media":226}"#),
+            KeyMapping::MultiMedia(0xe2)
+        );
+        assert_eq!(
+            print_serialize(KeyMapping::MultiMedia(0x07)),
+            r#"{"multi_media":7}"#
+        );
+        assert!(serde_json::from_str::<KeyMapping>(r#"{"multi_media":"volume"}"#).is_ok());
+        assert!(serde_json::from_str::<KeyMapping>(r#"{"multi_media":"loud"}"#).is_err());
+    }
+
+    #[test]
+    fn test_mappings_modifiers() {
+        // Every bitmask survives the trip through the wire format and serde.
+        for bits in 0..=255u8 {
+            let modifiers = Modifiers::from(bits);
+            assert_eq!(u8::from(modifiers), bits);
+            let bytes = modifiers.to_le_bytes().expect("success");
+            assert_eq!(bytes, [bits]);
+            assert_eq!(
+                Modifiers::from_le_bytes(&bytes).expect("success"),
+                modifiers
+            );
+            let serialized = serde_json::to_string(&modifiers).unwrap();
+            let deserialized: Modifiers = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(deserialized, modifiers);
+        }
+
+        let super_v: KeyboardKey =
+            print_deserialize(r#"{"id":"v","modifiers":["super", "right_win"]}"#);
+        assert_eq!(super_v.modifiers.bits(), 0x88);
+        assert!(super_v.modifiers.contains(Modifier::LeftGui));
+        assert!(super_v.modifiers.contains(Modifier::RightGui));
+        assert_eq!(
+            print_serialize(super_v.modifiers),
+            r#"["left_gui","right_gui"]"#
+        );
+        for name in ["gui", "win", "meta", "left_super"].iter() {
+            let m: Modifier = serde_json::from_str(&format!("\"{}\"", name)).unwrap();
+            assert_eq!(m, Modifier::LeftGui);
+        }
+
+        // Super + v, the modifier byte holds the left gui bit.
+        let mut mapping: KeyMapping = Default::default();
+        mapping
+            .from_bytes(&[0x02, 0x02, 0x08, 0x19], Endianness::Little)
+            .expect("success");
+        assert_eq!(
+            mapping,
+            KeyMapping::Key(KeyboardKey {
+                id: 0x19,
+                modifiers: Modifiers::gui(),
+            })
+        );
+
+        let mut modifiers = Modifiers::shift();
+        modifiers.push(Modifier::RightGui);
+        assert!(!modifiers.is_only(Modifier::LeftShift));
+        modifiers.remove(Modifier::RightGui);
+        assert!(modifiers.is_only(Modifier::LeftShift));
+    }
 }
@@ -1,5 +1,7 @@
 use struct_helper::*;
 
+use super::macros::{name_from_bytes, Uuid};
+
 pub type ProfileId = u8;
 
 #[derive(Inspectable, FromBytes, ToBytes, Clone, Copy, Debug, Default)]
@@ -49,6 +51,67 @@ pub struct ProfileCurrent {
     pub profile_id: ProfileId,
 }
 
+/// Length of the profile metadata. Not known from a capture, assumed to be the same as that of
+/// the macro metadata.
+pub const PROFILE_METADATA_SIZE: usize = 0xfa;
+
+/// Bytes of metadata in a single [`ProfileMetadata`] command, the remainder of the payload after
+/// the profile id and page. Not known from a capture.
+const PROFILE_METADATA_CHUNK: usize = 0x4d;
+
+#[derive(Inspectable, FromBytes, ToBytes, Clone, Copy, Debug)]
+#[repr(C, packed)]
+/// Payload for retrieving the profile metadata 0x0588, holds a chunk of the metadata, see
+/// [`ProfileInfo`] for its contents. Only the profile id and page are known from the captures,
+/// the rest of this layout is a guess.
+pub struct ProfileMetadata {
+    pub profile_id: ProfileId,
+    /// The page of this chunk, it is unknown what unit it is in. Used as a byte offset in the
+    /// metadata, like the page offset of the macro metadata.
+    pub page: u16,
+    #[inspect(dissect_additional_type = "bytes", dissection_hide = "true")]
+    pub data: [u8; PROFILE_METADATA_CHUNK],
+}
+impl Default for ProfileMetadata {
+    fn default() -> ProfileMetadata {
+        ProfileMetadata {
+            profile_id: 0,
+            page: 0,
+            data: [0; PROFILE_METADATA_CHUNK],
+        }
+    }
+}
+
+/// The metadata the vendor software stores with a profile, the keyboard itself doesn't use it.
+/// Assumed to start with the uuid, followed by the null terminated name, like the macro metadata.
+/// This is only parsed, it isn't confirmed enough to write it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProfileInfo {
+    pub uuid: Uuid,
+    pub name: String,
+}
+
+impl ProfileInfo {
+    /// Offset of the name in the metadata.
+    const NAME_OFFSET: usize = 16;
+
+    /// Parse the metadata as stored on the device.
+    pub fn from_bytes(bytes: &[u8]) -> Result<ProfileInfo, String> {
+        if bytes.len() < ProfileInfo::NAME_OFFSET {
+            return Err(format!(
+                "Profile metadata of {} bytes is too short.",
+                bytes.len()
+            ));
+        }
+        let mut info: ProfileInfo = Default::default();
+        info.uuid
+            .uuid
+            .copy_from_slice(&bytes[..ProfileInfo::NAME_OFFSET]);
+        info.name = name_from_bytes(&bytes[ProfileInfo::NAME_OFFSET..]);
+        Ok(info)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -78,4 +141,26 @@ mod tests {
         let parsed = ProfileDelete::from_be_bytes(&expected[PAYLOAD_START..]).expect("success");
         assert_eq!(parsed.profile_id, 3);
     }
+
+    #[test]
+    pub fn test_profiles_metadata() {
+        // Not a capture, there is none of the profile metadata yet. This checks the request only
+        // holds the profile id and the page, followed by the data.
+        let mut cmd = ProfileMetadata {
+            profile_id: 3,
+            page: PROFILE_METADATA_CHUNK as u16,
+            ..Default::default()
+        };
+        cmd.data[..3].copy_from_slice(b"ide");
+        let wire = cmd.to_be_bytes().expect("success");
+        assert_eq!(&wire[..6], &[0x03, 0x00, 0x4d, b'i', b'd', b'e']);
+
+        let mut bytes = vec![0u8; PROFILE_METADATA_SIZE];
+        bytes[0] = 0x42;
+        bytes[16..22].copy_from_slice(b"gaming");
+        let info = ProfileInfo::from_bytes(&bytes).expect("success");
+        assert_eq!(info.uuid.uuid[0], 0x42);
+        assert_eq!(info.name, "gaming");
+        assert!(ProfileInfo::from_bytes(&bytes[..10]).is_err());
+    }
 }
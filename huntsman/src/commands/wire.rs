@@ -1,6 +1,19 @@
+use serde::{Deserialize, Serialize};
 use struct_helper::*;
 
-#[derive(Inspectable, FromBytes, ToBytes, Default, Copy, Clone, Debug)]
+#[derive(
+    Inspectable,
+    FromBytes,
+    ToBytes,
+    Default,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+)]
 #[repr(C)]
 /// Denotes a Red, Green and Blue color value.
 pub struct RGB {
@@ -122,7 +135,19 @@ pub struct SetKeyOverride {
     pub mapping_third: u8,
 }
 
-#[derive(Inspectable, FromBytes, ToBytes, Default, Copy, Clone, Debug)]
+#[derive(
+    Inspectable,
+    FromBytes,
+    ToBytes,
+    Default,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+)]
 #[repr(C)]
 /// Payload to set an led effect.
 pub struct SetLedEffect {
@@ -137,13 +162,17 @@ pub struct SetLedEffect {
 
 #[derive(Inspectable, FromBytes, ToBytes, Default, Copy, Clone, Debug)]
 #[repr(C, packed)]
-/// Payload to set an led effect.
+/// Response with the macro storage statistics, all values are big endian and in bytes.
 pub struct GetStorageStatistics {
     pub something: u16,
     #[inspect(dissection_display = "dec")]
     pub total: u32,
+    /// Taken to be the free bytes, it is the only value that stays below the total in both
+    /// captures.
     #[inspect(dissection_display = "dec")]
     pub free1: u32,
+    /// Unknown, equal to free1 in one capture and larger than the total in the other. The vendor
+    /// software logs the sum of free1 and free2 as free.
     #[inspect(dissection_display = "dec")]
     pub free2: u32,
 }
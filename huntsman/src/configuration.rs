@@ -1,6 +1,14 @@
-pub use crate::commands::mappings::{Key, KeyMapping, KeyboardKey, Modifier, Modifiers};
+pub use crate::commands::mappings::{
+    ButtonPageUsage, GenericDesktopUsage, Key, KeyMapping, KeyboardKey, Modifier, Modifiers,
+    ProfileInstruction, SpecialFunction,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::commands::{Duration, SetLedEffect, RGB};
+use crate::devices::EffectKind;
+use crate::Error;
+use std::collections::BTreeMap;
+
 pub use crate::commands::macros::MacroAction;
 pub use crate::keymap_util::{at101_keys, default_keymaps, get_default_keymap, hypershift_keymaps};
 
@@ -9,7 +17,7 @@ pub use crate::hut_util::{at101_to_key_name, key_name_to_at101, keyboard_hid_to_
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct KeyConfig {
     /// Profile to set for this key.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<u8>,
     /// Human readable key name, should match against name from usb_hut.
     #[serde(flatten)]
@@ -18,25 +26,65 @@ pub struct KeyConfig {
     pub mapping: KeyMapping,
 }
 
-pub fn load_mappings(filename: &str) -> Result<Vec<KeyConfig>, Box<dyn std::error::Error>> {
-    let file = std::fs::File::open(filename).expect("file should be opened");
+/// A [`KeyConfig`] as written in mapping files, the mapping may refer to a macro by its name.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct KeyConfigSpec {
+    /// Profile to set for this key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<u8>,
+    #[serde(flatten)]
+    pub key: Key,
+    /// Action this key maps to.
+    pub mapping: MappingSpec,
+}
+
+impl KeyConfigSpec {
+    /// Resolve macro names to their ids, see [`MappingSpec::resolve`].
+    pub fn resolve(&self, lookup: &dyn Fn(&str) -> Option<u16>) -> Result<KeyConfig, Error> {
+        Ok(KeyConfig {
+            profile: self.profile,
+            key: self.key,
+            mapping: self.mapping.resolve(lookup)?,
+        })
+    }
+}
+
+/// Load mappings, mappings to macros by name are an error, see [`load_mappings_named`].
+pub fn load_mappings(filename: &str) -> Result<Vec<KeyConfig>, Error> {
+    load_mappings_named(filename, &|_| None)
+}
+
+/// Load mappings, the lookup provides the ids of the macros that are referred to by name.
+pub fn load_mappings_named(
+    filename: &str,
+    lookup: &dyn Fn(&str) -> Option<u16>,
+) -> Result<Vec<KeyConfig>, Error> {
+    let file = std::fs::File::open(filename)?;
     if filename.ends_with("yaml") {
         let yaml: serde_yaml::Value =
-            serde_yaml::from_reader(file).expect("file should be proper yaml");
-        let effects: Vec<KeyConfig> = serde_yaml::from_value(
-            yaml.get("mappings")
-                .expect("file should have mappings key")
-                .clone(),
-        )?;
-        return Ok(effects);
-    }
-    Err(Box::new(std::io::Error::new(
-        std::io::ErrorKind::Other,
+            serde_yaml::from_reader(file).map_err(|e| Error::config_yaml(filename, e))?;
+        let mappings = yaml
+            .get("mappings")
+            .ok_or_else(|| Error::config(filename, "file should have mappings key"))?;
+        let mappings: Vec<KeyConfigSpec> = serde_yaml::from_value(mappings.clone())
+            .map_err(|e| Error::config_yaml(filename, e))?;
+        return mappings
+            .iter()
+            .map(|m| {
+                m.resolve(lookup).map_err(|e| match e {
+                    Error::InvalidArgument(details) => Error::config(filename, &details),
+                    e => e,
+                })
+            })
+            .collect();
+    }
+    Err(Error::config(
+        filename,
         "File type not supported. Use .yaml.",
-    )))
+    ))
 }
 
-pub fn read_mapping(input: &str, key: &Key) -> Result<KeyMapping, Box<dyn std::error::Error>> {
+pub fn read_mapping(input: &str, key: &Key) -> Result<KeyMapping, Error> {
     if input.to_lowercase() == "default" {
         return Ok(get_default_keymap(key));
     }
@@ -52,30 +100,230 @@ pub fn read_mapping(input: &str, key: &Key) -> Result<KeyMapping, Box<dyn std::e
         };
         return Ok(get_default_keymap(&new_key));
     }
-    let yaml: serde_yaml::Value = serde_yaml::from_str(input).unwrap();
-    let effects: KeyMapping = serde_yaml::from_value(yaml)?;
+    let effects: KeyMapping =
+        serde_yaml::from_str(input).map_err(|e| Error::config_yaml("<inline>", e))?;
     return Ok(effects);
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct MacroConfig {
+    /// Name to refer to this macro by from mappings, an id is allocated if macro_id is omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     #[serde(default)]
     pub macro_id: u16,
     pub events: Vec<MacroAction>,
 }
 
-pub fn load_macro(filename: &str) -> Result<MacroConfig, Box<dyn std::error::Error>> {
-    let file = std::fs::File::open(filename).expect("file should be opened");
+pub fn load_macro(filename: &str) -> Result<MacroConfig, Error> {
+    let file = std::fs::File::open(filename)?;
     if filename.ends_with("yaml") {
-        let yaml: serde_yaml::Value =
-            serde_yaml::from_reader(file).expect("file should be proper yaml");
-        let macro_config: MacroConfig = serde_yaml::from_value(yaml.clone())?;
+        let macro_config: MacroConfig =
+            serde_yaml::from_reader(file).map_err(|e| Error::config_yaml(filename, e))?;
         return Ok(macro_config);
     }
-    Err(Box::new(std::io::Error::new(
-        std::io::ErrorKind::Other,
+    Err(Error::config(
+        filename,
+        "File type not supported. Use .yaml.",
+    ))
+}
+
+/// A mapping to a macro by its name, instead of by id. See [`MappingSpec`].
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroReference {
+    /// Macro 'n' times.
+    Macro {
+        name: String,
+        #[serde(default = "default_macro_count")]
+        count: u8,
+    },
+    /// Macro repeat while pressed.
+    MacroRepeat { name: String },
+    /// Macro toggle.
+    MacroToggle { name: String },
+}
+
+fn default_macro_count() -> u8 {
+    1
+}
+
+impl MacroReference {
+    /// The name of the macro that is referred to.
+    pub fn name(&self) -> &str {
+        match self {
+            MacroReference::Macro { name, .. }
+            | MacroReference::MacroRepeat { name }
+            | MacroReference::MacroToggle { name } => name,
+        }
+    }
+
+    /// Create the mapping, using the id the macro was given.
+    pub fn to_mapping(&self, macro_id: u16) -> KeyMapping {
+        match self {
+            MacroReference::Macro { count, .. } => KeyMapping::Macro {
+                macro_id,
+                count: *count,
+            },
+            MacroReference::MacroRepeat { .. } => KeyMapping::MacroRepeat(macro_id),
+            MacroReference::MacroToggle { .. } => KeyMapping::MacroToggle(macro_id),
+        }
+    }
+}
+
+/// A mapping as written in configuration files, macros can be referred to by name; for example
+/// `macro: { name: paste_sig }`. Anything else is a [`KeyMapping`].
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MappingSpec {
+    Named(MacroReference),
+    Mapping(KeyMapping),
+}
+
+impl MappingSpec {
+    /// Resolve to a [`KeyMapping`], the lookup provides the id for a macro name.
+    pub fn resolve(&self, lookup: &dyn Fn(&str) -> Option<u16>) -> Result<KeyMapping, Error> {
+        match self {
+            MappingSpec::Mapping(mapping) => Ok(*mapping),
+            MappingSpec::Named(reference) => match lookup(reference.name()) {
+                Some(macro_id) => Ok(reference.to_mapping(macro_id)),
+                None => Err(Error::InvalidArgument(format!(
+                    "Mapping refers to macro {:?}, which is not defined.",
+                    reference.name()
+                ))),
+            },
+        }
+    }
+}
+
+/// A key and the mapping to set on it, used in the profiles of [`KeyboardConfig`].
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct MappingConfig {
+    #[serde(flatten)]
+    pub key: Key,
+    pub mapping: MappingSpec,
+}
+
+/// The led effects, as written in configuration files. See [`crate::commands::SetLedEffect`]
+/// for their meaning.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectConfig {
+    Off,
+    Fixed {
+        color: RGB,
+    },
+    Breathing {
+        #[serde(default)]
+        colors: Vec<RGB>,
+    },
+    Spectrum,
+    Wave {
+        #[serde(default)]
+        reverse: bool,
+        #[serde(default = "default_wave_delay")]
+        delay: u8,
+    },
+    Reactive {
+        duration: Duration,
+        #[serde(default)]
+        colors: Vec<RGB>,
+    },
+    Ripple {
+        #[serde(default)]
+        colors: Vec<RGB>,
+    },
+    Starlight {
+        duration: Duration,
+        #[serde(default)]
+        colors: Vec<RGB>,
+    },
+    Custom,
+}
+
+fn default_wave_delay() -> u8 {
+    100
+}
+
+impl EffectConfig {
+    /// The kind of effect, to check whether a model supports it.
+    pub fn kind(&self) -> EffectKind {
+        match self {
+            EffectConfig::Off => EffectKind::Off,
+            EffectConfig::Fixed { .. } => EffectKind::Fixed,
+            EffectConfig::Breathing { .. } => EffectKind::Breathing,
+            EffectConfig::Spectrum => EffectKind::Spectrum,
+            EffectConfig::Wave { .. } => EffectKind::Wave,
+            EffectConfig::Reactive { .. } => EffectKind::Reactive,
+            EffectConfig::Ripple { .. } => EffectKind::Ripple,
+            EffectConfig::Starlight { .. } => EffectKind::Starlight,
+            EffectConfig::Custom => EffectKind::Custom,
+        }
+    }
+
+    /// Create the command that sets this effect on the profile.
+    pub fn to_command(&self, profile: u8) -> SetLedEffect {
+        let mut cmd = match self {
+            EffectConfig::Off => SetLedEffect::off(),
+            EffectConfig::Fixed { color } => SetLedEffect::fixed(color),
+            EffectConfig::Breathing { colors } => SetLedEffect::breathing(colors),
+            EffectConfig::Spectrum => SetLedEffect::spectrum(),
+            EffectConfig::Wave { reverse, delay } => SetLedEffect::wave(*reverse, *delay),
+            EffectConfig::Reactive { duration, colors } => {
+                SetLedEffect::reactive(*duration, colors)
+            }
+            EffectConfig::Ripple { colors } => SetLedEffect::ripple(colors),
+            EffectConfig::Starlight { duration, colors } => {
+                SetLedEffect::starlight(*duration, colors)
+            }
+            EffectConfig::Custom => SetLedEffect::custom(),
+        };
+        cmd.set_profile(profile);
+        cmd
+    }
+}
+
+/// The configuration of a single profile in a [`KeyboardConfig`].
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct ProfileConfig {
+    /// Led effect, left as is if not provided.
+    #[serde(default)]
+    pub effect: Option<EffectConfig>,
+    /// Brightness in [0, 1.0], left as is if not provided.
+    #[serde(default)]
+    pub brightness: Option<f32>,
+    /// The mappings that differ from the default, keys that are not listed get their default.
+    #[serde(default)]
+    pub mappings: Vec<MappingConfig>,
+}
+
+/// Describes the whole keyboard; the profiles with their mappings, effect and brightness, the
+/// macros by name and the game mode. Profiles and macros that are not listed are removed when
+/// the configuration is applied, see [`crate::apply`].
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub struct KeyboardConfig {
+    /// Game mode, left as is if not provided.
+    #[serde(default)]
+    pub game_mode: Option<bool>,
+    /// The macros by name, the ids are allocated when applying.
+    #[serde(default)]
+    pub macros: BTreeMap<String, Vec<MacroAction>>,
+    /// The profiles by id, the first profile always exists.
+    #[serde(default)]
+    pub profiles: BTreeMap<u8, ProfileConfig>,
+}
+
+pub fn load_keyboard(filename: &str) -> Result<KeyboardConfig, Error> {
+    let file = std::fs::File::open(filename)?;
+    if filename.ends_with("yaml") {
+        let config: KeyboardConfig =
+            serde_yaml::from_reader(file).map_err(|e| Error::config_yaml(filename, e))?;
+        return Ok(config);
+    }
+    Err(Error::config(
+        filename,
         "File type not supported. Use .yaml.",
-    )))
+    ))
 }
 
 #[cfg(test)]
@@ -125,6 +373,17 @@ mod tests {
             }
         );
 
+        // Modifier only, as used by the default mappings of the modifier keys.
+        let z = read_mapping("key: { id: none , modifiers:[shift] }", &k).unwrap();
+        assert_eq!(
+            z,
+            KeyMapping::Key(KeyboardKey {
+                id: 0,
+                modifiers: Modifiers::shift(),
+            })
+        );
+        assert!(print_serialize(z).contains("id: none"));
+
         // specials
         let z = read_mapping("disabled", &k).unwrap();
         assert_eq!(z, KeyMapping::Disabled);
@@ -146,5 +405,118 @@ mod tests {
                 modifiers: Modifiers::none(),
             })
         );
+
+        // Functions by name, or by number.
+        let z = read_mapping("special: brightness_down", &k).unwrap();
+        assert_eq!(z, KeyMapping::Special(SpecialFunction::BrightnessDown));
+        assert!(print_serialize(z).contains("special: brightness_down"));
+        let z = read_mapping("special: 0x03", &k).unwrap();
+        assert_eq!(z, KeyMapping::Special(SpecialFunction::GameMode));
+        let z = read_mapping("profile_instruction: previous", &k).unwrap();
+        assert_eq!(
+            z,
+            KeyMapping::ProfileInstruction(ProfileInstruction::Previous)
+        );
+        let z = read_mapping("generic_desktop: 0x7f", &k).unwrap();
+        assert_eq!(
+            z,
+            KeyMapping::GenericDesktop(GenericDesktopUsage::Raw(0x7f))
+        );
+        assert!(print_serialize(z).contains("generic_desktop: 127"));
+        let z = read_mapping("multi_media: volume_increment", &k).unwrap();
+        assert_eq!(z, KeyMapping::MultiMedia(0xe9));
+        let z = read_mapping("multi_media: 0xe2", &k).unwrap();
+        assert!(print_serialize(z).contains("multi_media: mute"));
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let k = Key {
+            id: 0x04,
+            hypershift: false,
+        };
+        match read_mapping("key: { id: not_a_key }", &k) {
+            Err(Error::Config { line, column, .. }) => {
+                assert_eq!(line, Some(1));
+                assert!(column.is_some());
+            }
+            z => panic!("Expected config error, got {:?}", z),
+        }
+        assert!(matches!(
+            read_mapping("this_is_not_a_key", &k),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            load_mappings("/this/file/does/not/exist.yaml"),
+            Err(Error::Io(_))
+        ));
+        assert!(matches!(
+            load_macro("/this/file/does/not/exist.yaml"),
+            Err(Error::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_macro_config_round_trip() {
+        // The output of macro dump should be accepted by load_macro.
+        let macro_config = MacroConfig {
+            name: None,
+            macro_id: 0x1337,
+            events: vec![
+                MacroAction::KeyboardMake { hid: 0x04 },
+                MacroAction::Delay(100),
+                MacroAction::KeyboardBreak { hid: 0x04 },
+            ],
+        };
+        let path =
+            std::env::temp_dir().join(format!("huntsman_test_macro_{}.yaml", std::process::id()));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, serde_yaml::to_string(&macro_config).unwrap()).expect("success");
+        assert_eq!(load_macro(path).expect("success"), macro_config);
+        std::fs::remove_file(path).expect("success");
+    }
+
+    #[test]
+    fn test_named_macro_mapping() {
+        let spec: Vec<KeyConfigSpec> = serde_yaml::from_str(
+            r#"
+- id: right_control
+  mapping: { macro: { name: paste_sig } }
+- id: caps_lock
+  mapping: { macro_repeat: { name: paste_sig } }
+- id: right_alt
+  mapping: { macro: { macro_id: 0x1337, count: 2 } }
+"#,
+        )
+        .expect("valid mappings");
+        let lookup = |name: &str| {
+            if name == "paste_sig" {
+                Some(0x42)
+            } else {
+                None
+            }
+        };
+        let mappings: Vec<KeyMapping> = spec
+            .iter()
+            .map(|m| m.resolve(&lookup).expect("resolves").mapping)
+            .collect();
+        assert_eq!(
+            mappings,
+            vec![
+                KeyMapping::Macro {
+                    macro_id: 0x42,
+                    count: 1
+                },
+                KeyMapping::MacroRepeat(0x42),
+                KeyMapping::Macro {
+                    macro_id: 0x1337,
+                    count: 2
+                },
+            ]
+        );
+        assert!(matches!(
+            spec[0].resolve(&|_| None),
+            Err(Error::InvalidArgument(_))
+        ));
     }
 }
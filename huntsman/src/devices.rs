@@ -0,0 +1,152 @@
+//! Describes the keyboard models that speak the same protocol as the Huntsman Elite.
+
+use crate::commands::ProfileId;
+
+/// Usb vendor id used by all Razer devices.
+pub const RAZER_VENDOR_ID: u16 = 0x1532;
+
+/// The led effects that can be set with [`crate::commands::SetLedEffect`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EffectKind {
+    Off,
+    Fixed,
+    Breathing,
+    Spectrum,
+    Wave,
+    Reactive,
+    Ripple,
+    Starlight,
+    Custom,
+}
+
+/// All the effects, as found on the Huntsman Elite.
+const ALL_EFFECTS: [EffectKind; 9] = [
+    EffectKind::Off,
+    EffectKind::Fixed,
+    EffectKind::Breathing,
+    EffectKind::Spectrum,
+    EffectKind::Wave,
+    EffectKind::Reactive,
+    EffectKind::Ripple,
+    EffectKind::Starlight,
+    EffectKind::Custom,
+];
+
+/// The at101 key ids present on a tenkeyless keyboard, this is [`crate::keymap_util::at101_keys`]
+/// without the numpad.
+const TENKEYLESS_KEYS: [u8; 85] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0f, 0x10, 0x11,
+    0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20, 0x21,
+    0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2b, 0x2c, 0x2e, 0x2f, 0x30, 0x31, 0x32, 0x33,
+    0x34, 0x35, 0x36, 0x37, 0x39, 0x3a, 0x3c, 0x3d, 0x3e, 0x40, 0x4b, 0x4c, 0x4f, 0x50, 0x51, 0x53,
+    0x54, 0x55, 0x56, 0x59, 0x6e, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a,
+    0x7b, 0x7c, 0x7d, 0x7e, 0x81,
+];
+
+/// Describes a keyboard model, its usb identification and capabilities.
+#[derive(Debug, PartialEq)]
+pub struct DeviceModel {
+    /// Human readable name of the model.
+    pub name: &'static str,
+    /// The usb product id.
+    pub product_id: u16,
+    /// The interface that accepts the feature reports.
+    pub interface: u32,
+    /// Number of led rows that can be set with [`crate::commands::SetLedState`], this includes
+    /// the edge lighting and armrest on the Elite, see the table on that struct.
+    pub led_rows: u8,
+    /// Number of leds in each row.
+    pub led_columns: u8,
+    /// The led effects the firmware supports.
+    pub effects: &'static [EffectKind],
+    /// The at101 key ids of the keys that are present.
+    pub keys: &'static [u8],
+    /// The profile ids the device can hold, the first one always exists.
+    pub profiles: std::ops::RangeInclusive<ProfileId>,
+    /// False if the descriptor is based on other sources and wasn't tested against a device.
+    pub verified: bool,
+}
+
+impl DeviceModel {
+    /// Returns true if this model supports the effect.
+    pub fn supports(&self, effect: EffectKind) -> bool {
+        self.effects.contains(&effect)
+    }
+
+    /// Returns true if the key is present on this model.
+    pub fn has_key(&self, at101: u8) -> bool {
+        self.keys.contains(&at101)
+    }
+}
+
+/// The Huntsman Elite, this is the model this crate was written against.
+pub static HUNTSMAN_ELITE: DeviceModel = DeviceModel {
+    name: "Razer Huntsman Elite",
+    product_id: 0x0226,
+    interface: 2,
+    led_rows: 9,
+    led_columns: 23,
+    effects: &ALL_EFFECTS,
+    keys: &crate::keymap_util::AT101_KEYS,
+    profiles: 1..=5,
+    verified: true,
+};
+
+/// The Huntsman Tournament Edition, a tenkeyless board without edge lighting.
+pub static HUNTSMAN_TOURNAMENT_EDITION: DeviceModel = DeviceModel {
+    name: "Razer Huntsman Tournament Edition",
+    product_id: 0x0243,
+    interface: 2,
+    led_rows: 6,
+    led_columns: 18,
+    effects: &ALL_EFFECTS,
+    keys: &TENKEYLESS_KEYS,
+    profiles: 1..=5,
+    verified: false,
+};
+
+/// The BlackWidow V3, full size without edge lighting.
+pub static BLACKWIDOW_V3: DeviceModel = DeviceModel {
+    name: "Razer BlackWidow V3",
+    product_id: 0x024e,
+    interface: 2,
+    led_rows: 6,
+    led_columns: 22,
+    effects: &ALL_EFFECTS,
+    keys: &crate::keymap_util::AT101_KEYS,
+    profiles: 1..=5,
+    verified: false,
+};
+
+/// All models known to this crate, in the order they are tried when connecting.
+pub static MODELS: [&DeviceModel; 3] = [
+    &HUNTSMAN_ELITE,
+    &HUNTSMAN_TOURNAMENT_EDITION,
+    &BLACKWIDOW_V3,
+];
+
+/// Find the model with the provided usb product id.
+pub fn find_model(product_id: u16) -> Option<&'static DeviceModel> {
+    MODELS.iter().find(|m| m.product_id == product_id).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_models() {
+        assert_eq!(find_model(0x0226), Some(&HUNTSMAN_ELITE));
+        assert_eq!(find_model(0x0000), None);
+        for m in MODELS.iter() {
+            // Rows must fit in the SetLedState payload.
+            assert!(m.led_columns as usize <= crate::commands::SetLedState::default().leds.len());
+            assert!(m.supports(EffectKind::Custom));
+        }
+        // The tenkeyless key set has no numpad, but is otherwise equal.
+        for k in HUNTSMAN_ELITE.keys.iter() {
+            let numpad = (0x5a..=0x6c).contains(k);
+            assert_eq!(HUNTSMAN_TOURNAMENT_EDITION.has_key(*k), !numpad);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Compares mapping and macro configurations against what the device reports, such that one can
+//! see what loading them will change, and only write what differs.
+
+use crate::commands::macros::MacroAction;
+use crate::commands::mappings::{Key, KeyMapping};
+use crate::commands::{MacroId, ProfileId};
+use crate::configuration::{at101_to_key_name, get_default_keymap, KeyConfig, MacroConfig};
+use crate::{Error, Huntsman};
+
+/// A difference between the device and the configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change<T> {
+    /// The device holds the default, the configuration sets something else.
+    Added { new: T },
+    /// The device holds something that isn't the default, the configuration sets another value.
+    Changed { old: T, new: T },
+    /// The device holds something that isn't the default, the configuration reverts it.
+    Removed { old: T },
+}
+
+/// A key mapping that differs between the device and the configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingChange {
+    pub profile: ProfileId,
+    pub key: Key,
+    pub change: Change<KeyMapping>,
+}
+
+impl MappingChange {
+    /// The mapping the configuration sets.
+    pub fn mapping(&self) -> KeyMapping {
+        match self.change {
+            Change::Added { new } | Change::Changed { new, .. } => new,
+            Change::Removed { .. } => get_default_keymap(&self.key),
+        }
+    }
+}
+
+impl std::fmt::Display for MappingChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = at101_to_key_name(self.key.id).unwrap_or("unknown key");
+        let hypershift = if self.key.hypershift {
+            ":hypershift"
+        } else {
+            ""
+        };
+        let key = format!("profile {} {}{}", self.profile, name, hypershift);
+        match &self.change {
+            Change::Added { new } => write!(f, "+ {}: {:?}", key, new),
+            Change::Changed { old, new } => write!(f, "~ {}: {:?} -> {:?}", key, old, new),
+            Change::Removed { old } => write!(f, "- {}: {:?} (back to default)", key, old),
+        }
+    }
+}
+
+/// A macro that differs between the device and the configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MacroChange {
+    pub macro_id: MacroId,
+    pub change: Change<Vec<MacroAction>>,
+}
+
+impl std::fmt::Display for MacroChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.change {
+            Change::Added { new } => {
+                write!(f, "+ macro 0x{:0>4x}: {} actions", self.macro_id, new.len())
+            }
+            Change::Changed { old, new } => write!(
+                f,
+                "~ macro 0x{:0>4x}: {} actions -> {} actions",
+                self.macro_id,
+                old.len(),
+                new.len()
+            ),
+            Change::Removed { old } => {
+                write!(f, "- macro 0x{:0>4x}: {} actions", self.macro_id, old.len())
+            }
+        }
+    }
+}
+
+/// Compare a single mapping, returns None if the device already holds the configured mapping.
+pub fn compare_mapping(
+    profile: ProfileId,
+    key: Key,
+    device: KeyMapping,
+    configured: KeyMapping,
+) -> Option<MappingChange> {
+    if device == configured {
+        return None;
+    }
+    let default = get_default_keymap(&key);
+    let change = if device == default {
+        Change::Added { new: configured }
+    } else if configured == default {
+        Change::Removed { old: device }
+    } else {
+        Change::Changed {
+            old: device,
+            new: configured,
+        }
+    };
+    Some(MappingChange {
+        profile,
+        key,
+        change,
+    })
+}
+
+/// Retrieve the mappings from the device and return those that differ from the configuration.
+/// All entries are compared against the provided profile, like `mapping load` writes them.
+pub fn diff_mappings(
+    h: &mut Huntsman,
+    profile: ProfileId,
+    mappings: &[KeyConfig],
+) -> Result<Vec<MappingChange>, Error> {
+    let mut changes = vec![];
+    for m in mappings.iter() {
+        let device = h.get_mapping(profile, m.key)?;
+        if let Some(change) = compare_mapping(profile, m.key, device.mapping, m.mapping) {
+            changes.push(change);
+        }
+    }
+    Ok(changes)
+}
+
+/// Retrieve the macros from the device and return those that differ from the configuration.
+/// Macros on the device that are not configured are not reported, loading macros keeps them.
+pub fn diff_macros(h: &mut Huntsman, macros: &[MacroConfig]) -> Result<Vec<MacroChange>, Error> {
+    let on_device = h.macro_list()?;
+    let mut changes = vec![];
+    for m in macros.iter() {
+        if !on_device.contains(&m.macro_id) {
+            changes.push(MacroChange {
+                macro_id: m.macro_id,
+                change: Change::Added {
+                    new: m.events.clone(),
+                },
+            });
+            continue;
+        }
+        let device = h.macro_get(m.macro_id)?;
+        if device != m.events {
+            changes.push(MacroChange {
+                macro_id: m.macro_id,
+                change: Change::Changed {
+                    old: device,
+                    new: m.events.clone(),
+                },
+            });
+        }
+    }
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::mappings::MouseButton;
+    use crate::CommandStatus;
+
+    #[test]
+    fn test_compare_mapping() {
+        let key = Key {
+            id: 0x40,
+            hypershift: false,
+        };
+        let default = get_default_keymap(&key);
+        let right = KeyMapping::Mouse(MouseButton::Right);
+        let left = KeyMapping::Mouse(MouseButton::Left);
+        assert_eq!(compare_mapping(1, key, default, default), None);
+        assert_eq!(
+            compare_mapping(1, key, default, right).unwrap().change,
+            Change::Added { new: right }
+        );
+        assert_eq!(
+            compare_mapping(1, key, right, left).unwrap().change,
+            Change::Changed {
+                old: right,
+                new: left
+            }
+        );
+        let removed = compare_mapping(1, key, right, default).unwrap();
+        assert_eq!(removed.change, Change::Removed { old: right });
+        assert_eq!(removed.mapping(), default);
+        assert!(format!("{}", removed).starts_with("- profile 1 "));
+    }
+
+    #[test]
+    fn test_diff_device() {
+        let mut h = Huntsman::virtual_new().expect("success");
+        let key = Key {
+            id: 0x40,
+            hypershift: false,
+        };
+        let right = KeyMapping::Mouse(MouseButton::Right);
+        let mappings = vec![
+            KeyConfig {
+                profile: None,
+                key,
+                mapping: right,
+            },
+            KeyConfig {
+                profile: None,
+                key: Key { id: 0x01, ..key },
+                mapping: get_default_keymap(&Key { id: 0x01, ..key }),
+            },
+        ];
+        let changes = diff_mappings(&mut h, 1, &mappings).expect("success");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].change, Change::Added { new: right });
+        assert_eq!(
+            h.set_mapping(1, key, right).expect("success"),
+            CommandStatus::Ok
+        );
+        assert!(diff_mappings(&mut h, 1, &mappings)
+            .expect("success")
+            .is_empty());
+
+        let config = MacroConfig {
+            name: None,
+            macro_id: 0x1337,
+            events: vec![MacroAction::KeyboardMake { hid: 0x04 }],
+        };
+        let changes = diff_macros(&mut h, std::slice::from_ref(&config)).expect("success");
+        assert!(matches!(changes[0].change, Change::Added { .. }));
+        assert_eq!(
+            h.macro_create_actions(config.macro_id, &config.events)
+                .expect("success"),
+            CommandStatus::Ok
+        );
+        assert!(diff_macros(&mut h, std::slice::from_ref(&config))
+            .expect("success")
+            .is_empty());
+        let changed = MacroConfig {
+            events: vec![MacroAction::KeyboardBreak { hid: 0x04 }],
+            ..config
+        };
+        let changes = diff_macros(&mut h, &[changed]).expect("success");
+        assert!(matches!(changes[0].change, Change::Changed { .. }));
+    }
+}
@@ -0,0 +1,122 @@
+//! The error type used throughout this crate.
+
+use crate::commands::{Cmd, CommandStatus};
+
+/// Enum to denote everything that can go wrong when talking to the keyboard or loading
+/// configuration files.
+#[derive(Debug)]
+pub enum Error {
+    /// The transport (usb / hid layer) failed to deliver or retrieve a report.
+    Transport(String),
+    /// The checksum in a response did not match the checksum calculated from its contents.
+    ChecksumMismatch { expected: u8, received: u8 },
+    /// The device did not report success for a command whose response we need.
+    Rejected { cmd: Cmd, status: CommandStatus },
+    /// The device did not finish processing the command within the retry policy.
+    Timeout { cmd: Cmd, attempts: usize },
+    /// An argument was out of range or otherwise not acceptable for the device.
+    InvalidArgument(String),
+    /// Bytes could not be converted into the expected structure.
+    Decode(String),
+    /// A configuration file could not be parsed, line and column are 1-based if known.
+    Config {
+        path: String,
+        line: Option<usize>,
+        column: Option<usize>,
+        message: String,
+    },
+    /// Reading or writing a file failed.
+    Io(std::io::Error),
+    /// The device doesn't have enough free storage for the macro, in bytes.
+    InsufficientStorage { required: u32, available: u32 },
+}
+
+impl Error {
+    /// Create a configuration error from a serde_yaml error, retaining the location in the file.
+    pub(crate) fn config_yaml(path: &str, e: serde_yaml::Error) -> Error {
+        let location = e.location();
+        Error::Config {
+            path: path.to_string(),
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+            message: format!("{}", e),
+        }
+    }
+
+    /// Create a configuration error that does not relate to a particular location in the file.
+    pub(crate) fn config(path: &str, message: &str) -> Error {
+        Error::Config {
+            path: path.to_string(),
+            line: None,
+            column: None,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Transport(details) => write!(f, "transport error: {}", details),
+            Error::ChecksumMismatch { expected, received } => write!(
+                f,
+                "checksum mismatch, expected 0x{:0>2x}, received 0x{:0>2x}",
+                expected, received
+            ),
+            Error::Rejected { cmd, status } => write!(
+                f,
+                "command 0x{:0>2x}{:0>2x} rejected by device: {}",
+                cmd.major, cmd.minor, status
+            ),
+            Error::Timeout { cmd, attempts } => write!(
+                f,
+                "command 0x{:0>2x}{:0>2x} did not complete after {} attempts",
+                cmd.major, cmd.minor, attempts
+            ),
+            Error::InvalidArgument(details) => write!(f, "invalid argument: {}", details),
+            Error::Decode(details) => write!(f, "decode error: {}", details),
+            Error::Config {
+                path,
+                line,
+                column,
+                message,
+            } => match (line, column) {
+                (Some(line), Some(column)) => {
+                    write!(f, "{}:{}:{}: {}", path, line, column, message)
+                }
+                _ => write!(f, "{}: {}", path, message),
+            },
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::InsufficientStorage {
+                required,
+                available,
+            } => write!(
+                f,
+                "insufficient storage, {} bytes required but {} bytes available",
+                required, available
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// The [`struct_helper`] conversions report failures as strings.
+impl From<String> for Error {
+    fn from(details: String) -> Self {
+        Error::Decode(details)
+    }
+}
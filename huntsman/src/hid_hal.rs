@@ -1,54 +1,103 @@
 ///! Encapsulate the hardware interaction in the HidApiHal object.
+#[cfg(feature = "hidapi")]
 extern crate hidapi;
 
-#[derive(Debug)]
-struct HalError {
-    details: String,
+use crate::commands::{to_wireshark_value, WIRESHARK_PAYLOAD_START};
+use crate::Error;
+use serde::{Deserialize, Serialize};
+
+/// Information about a device that matches the vendor id, product id and endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// The platform specific path to the hid device.
+    pub path: String,
+    /// The serial number of the usb device, if it reports one.
+    pub serial: Option<String>,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub interface: i32,
 }
 
-impl HalError {
-    fn new(msg: &str) -> HalError {
-        HalError {
-            details: msg.to_string(),
-        }
-    }
-    fn boxed(msg: &str) -> Box<HalError> {
-        Box::new(HalError::new(msg))
-    }
+/// Selects which device to connect to if multiple devices match.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeviceSelector {
+    /// The first device that is found.
+    First,
+    /// The device with this hid path.
+    Path(String),
+    /// The device with this serial number.
+    Serial(String),
 }
-impl std::fmt::Display for HalError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "HalError {}", self.details)
+
+impl DeviceSelector {
+    /// Returns true if the selector selects this device.
+    pub fn matches(&self, info: &DeviceInfo) -> bool {
+        match self {
+            DeviceSelector::First => true,
+            DeviceSelector::Path(path) => info.path == *path,
+            DeviceSelector::Serial(serial) => info.serial.as_ref() == Some(serial),
+        }
     }
 }
-impl std::error::Error for HalError {
-    fn description(&self) -> &str {
-        &self.details
+
+impl std::str::FromStr for DeviceSelector {
+    type Err = Error;
+    /// Parses `path:<path>` or `serial:<serial>`. Without prefix, values starting with a '/' are
+    /// taken to be paths, anything else is taken to be a serial number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(Error::InvalidArgument(
+                "Device selector can't be empty.".to_string(),
+            ));
+        }
+        if let Some(path) = s.strip_prefix("path:") {
+            return Ok(DeviceSelector::Path(path.to_string()));
+        }
+        if let Some(serial) = s.strip_prefix("serial:") {
+            return Ok(DeviceSelector::Serial(serial.to_string()));
+        }
+        if s.starts_with('/') {
+            return Ok(DeviceSelector::Path(s.to_string()));
+        }
+        Ok(DeviceSelector::Serial(s.to_string()))
     }
 }
 
+/// Trait for the transport to the keyboard, implement this to communicate with the device in
+/// other ways, see [`crate::Huntsman::with_hal`].
 pub trait HidHal {
-    /// Connect to a particular usb device and endpoint id.
+    /// List the devices with a particular usb vendor id, product id and endpoint id.
+    fn enumerate(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+        endpoint_id: u32,
+    ) -> Result<Vec<DeviceInfo>, Error>;
+    /// Connect to a particular usb device and endpoint id, the selector picks between multiple
+    /// matching devices.
     fn connect(
         &mut self,
         vendor_id: u16,
         product_id: u16,
         endpoint_id: u32,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+        selector: &DeviceSelector,
+    ) -> Result<(), Error>;
     /// Send bytes as a control message.
-    fn control(&mut self, payload: &[u8]) -> Result<(), Box<dyn std::error::Error>>;
+    fn control(&mut self, payload: &[u8]) -> Result<(), Error>;
     /// Retrieve the report after sending a control message. This is an echo / ack?
-    fn get_report(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
+    fn get_report(&mut self) -> Result<Vec<u8>, Error>;
 }
 
 /// Struct to provide hardware / crate abstraction layer.
+#[cfg(feature = "hidapi")]
 pub struct HidApiHal {
     api: hidapi::HidApi,
     connected_device: Option<hidapi::HidDevice>,
 }
 
 /// Helper function to prepend a zero to a byte slice, send_feature_report requires this.
-fn prepend_zero(v: &[u8]) -> Vec<u8> {
+#[cfg(any(feature = "hidapi", feature = "hidraw"))]
+pub(crate) fn prepend_zero(v: &[u8]) -> Vec<u8> {
     let mut new_v: Vec<u8> = Vec::new();
     new_v.push(0);
     for i in 0..v.len() {
@@ -56,11 +105,12 @@ fn prepend_zero(v: &[u8]) -> Vec<u8> {
     }
     return new_v;
 }
+#[cfg(feature = "hidapi")]
 impl HidApiHal {
     /// Attempt to instantiate the hid api.
-    pub fn new() -> Result<Box<dyn HidHal>, Box<dyn std::error::Error>> {
+    pub fn new() -> Result<Box<dyn HidHal>, Error> {
         match hidapi::HidApi::new() {
-            Err(e) => Err(Box::new(e)),
+            Err(e) => Err(Error::Transport(format!("{}", e))),
             Ok(api) => Ok(Box::new(HidApiHal {
                 api: api,
                 connected_device: None,
@@ -69,17 +119,53 @@ impl HidApiHal {
     }
 }
 
+/// Helper function to convert the hidapi device information.
+#[cfg(feature = "hidapi")]
+fn to_device_info(device: &hidapi::DeviceInfo) -> DeviceInfo {
+    DeviceInfo {
+        path: device.path().to_string_lossy().to_string(),
+        serial: device.serial_number().map(|s| s.to_string()),
+        vendor_id: device.vendor_id(),
+        product_id: device.product_id(),
+        interface: device.interface_number(),
+    }
+}
+
+#[cfg(feature = "hidapi")]
 impl HidHal for HidApiHal {
+    fn enumerate(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+        endpoint_id: u32,
+    ) -> Result<Vec<DeviceInfo>, Error> {
+        if let Err(e) = self.api.refresh_devices() {
+            return Err(Error::Transport(format!("{}", e)));
+        }
+        Ok(self
+            .api
+            .device_list()
+            .filter(|device| {
+                device.vendor_id() == vendor_id
+                    && device.product_id() == product_id
+                    && device.interface_number() == endpoint_id as i32
+            })
+            .map(to_device_info)
+            .collect())
+    }
+
     fn connect(
         &mut self,
         vendor_id: u16,
         product_id: u16,
         endpoint_id: u32,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+        selector: &DeviceSelector,
+    ) -> Result<(), Error> {
         for device in self.api.device_list() {
             if device.vendor_id() == vendor_id
                 && device.product_id() == product_id
                 && device.interface_number() == endpoint_id as i32
+                && selector.matches(&to_device_info(device))
             {
                 match device.open_device(&self.api) {
                     Ok(d) => {
@@ -87,31 +173,37 @@ impl HidHal for HidApiHal {
                         return Ok(());
                     }
                     Err(z) => {
-                        return Err(Box::new(z));
+                        return Err(Error::Transport(format!("{}", z)));
                     }
                 }
             }
         }
-        return Err(HalError::boxed("No device found."));
+        match selector {
+            DeviceSelector::First => Err(Error::Transport("No device found.".to_string())),
+            _ => Err(Error::Transport(format!(
+                "No device found matching {:?}.",
+                selector
+            ))),
+        }
     }
 
-    fn control(&mut self, payload: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    fn control(&mut self, payload: &[u8]) -> Result<(), Error> {
         match &mut self.connected_device {
-            None => Err(HalError::boxed("No connected device.")),
+            None => Err(Error::Transport("No connected device.".to_string())),
             Some(d) => match d.send_feature_report(prepend_zero(payload).as_slice()) {
-                Err(e) => Err(Box::new(e)),
+                Err(e) => Err(Error::Transport(format!("{}", e))),
                 Ok(()) => Ok(()),
             },
         }
     }
 
-    fn get_report(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    fn get_report(&mut self) -> Result<Vec<u8>, Error> {
         let mut buff: [u8; 91] = [0; 91]; // This also specifies the length.
         buff[0] = 0;
         match &mut self.connected_device {
-            None => Err(HalError::boxed("No connected device.")),
+            None => Err(Error::Transport("No connected device.".to_string())),
             Some(d) => match d.get_feature_report(&mut buff) {
-                Err(e) => Err(HalError::boxed(&format!("{:?}", e))),
+                Err(e) => Err(Error::Transport(format!("{}", e))),
                 Ok(len) => {
                     let mut z: Vec<u8> = Vec::new();
                     for i in 1..len {
@@ -131,7 +223,7 @@ pub struct DryHidHal {
 
 impl DryHidHal {
     /// Attempt to instantiate the hid api.
-    pub fn new() -> Result<Box<dyn HidHal>, Box<dyn std::error::Error>> {
+    pub fn new() -> Result<Box<dyn HidHal>, Error> {
         Ok(Box::new(DryHidHal {
             buffer: [0; 90],
             len: 0,
@@ -140,17 +232,27 @@ impl DryHidHal {
 }
 
 impl HidHal for DryHidHal {
+    fn enumerate(
+        &mut self,
+        _vendor_id: u16,
+        _product_id: u16,
+        _endpoint_id: u32,
+    ) -> Result<Vec<DeviceInfo>, Error> {
+        Ok(vec![])
+    }
+
     fn connect(
         &mut self,
         _vendor_id: u16,
         _product_id: u16,
         _endpoint_id: u32,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+        _selector: &DeviceSelector,
+    ) -> Result<(), Error> {
         Ok(())
     }
 
     /// Send bytes as a control message.
-    fn control(&mut self, payload: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    fn control(&mut self, payload: &[u8]) -> Result<(), Error> {
         self.buffer.fill(0);
         for (i, x) in payload.iter().enumerate() {
             self.buffer[i] = *x;
@@ -160,8 +262,212 @@ impl HidHal for DryHidHal {
     }
 
     /// Retrieve the report after sending a control message. This is an echo / ack?
-    fn get_report(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    fn get_report(&mut self) -> Result<Vec<u8>, Error> {
         self.buffer[0] = 0x02;
         return Ok(self.buffer.to_vec());
     }
 }
+
+/// A single command sent to the device and the report that was read back, stored as hex strings
+/// in the same notation as wireshark uses.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct Exchange {
+    request: String,
+    response: String,
+}
+
+/// Wraps another hal and records every exchange with the device to a yaml file. Exchanges are
+/// appended to the file as soon as the next command is sent and when the hal is dropped, so an
+/// aborted program loses at most the last exchange.
+pub struct RecordingHidHal {
+    inner: Box<dyn HidHal>,
+    path: String,
+    file: std::fs::File,
+    pending: Option<Exchange>,
+}
+
+impl RecordingHidHal {
+    /// Wrap the provided hal, recording to the file at path, which is truncated.
+    pub fn new(inner: Box<dyn HidHal>, path: &str) -> Result<RecordingHidHal, Error> {
+        let file = std::fs::File::create(path)?;
+        Ok(RecordingHidHal {
+            inner,
+            path: path.to_string(),
+            file,
+            pending: None,
+        })
+    }
+
+    /// Append the pending exchange to the file as an entry of the top level sequence.
+    fn flush(&mut self) -> Result<(), Error> {
+        use std::io::Write;
+        if let Some(exchange) = self.pending.take() {
+            let entry = serde_yaml::to_string(&[exchange])
+                .map_err(|e| Error::config(&self.path, &format!("{}", e)))?;
+            let entry = entry.strip_prefix("---\n").unwrap_or(&entry);
+            writeln!(self.file, "{}", entry.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for RecordingHidHal {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            eprintln!("Failed to write the last exchange to {}: {}", self.path, e);
+        }
+    }
+}
+
+impl HidHal for RecordingHidHal {
+    fn enumerate(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+        endpoint_id: u32,
+    ) -> Result<Vec<DeviceInfo>, Error> {
+        self.inner.enumerate(vendor_id, product_id, endpoint_id)
+    }
+
+    fn connect(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+        endpoint_id: u32,
+        selector: &DeviceSelector,
+    ) -> Result<(), Error> {
+        self.inner
+            .connect(vendor_id, product_id, endpoint_id, selector)
+    }
+
+    fn control(&mut self, payload: &[u8]) -> Result<(), Error> {
+        self.flush()?;
+        self.inner.control(payload)?;
+        self.pending = Some(Exchange {
+            request: to_wireshark_value(payload),
+            response: String::new(),
+        });
+        Ok(())
+    }
+
+    /// If the report is read multiple times, only the last one is retained.
+    fn get_report(&mut self) -> Result<Vec<u8>, Error> {
+        let report = self.inner.get_report()?;
+        if let Some(exchange) = self.pending.as_mut() {
+            exchange.response = to_wireshark_value(&report);
+        }
+        Ok(report)
+    }
+}
+
+/// Serves the responses from a file written by the [`RecordingHidHal`]. Requests are matched on
+/// their command register and payload, recorded exchanges are served in order, if all matching
+/// exchanges were used the last one is served again.
+pub struct ReplayHidHal {
+    exchanges: Vec<(Vec<u8>, Vec<u8>)>,
+    used: Vec<bool>,
+    current: Option<usize>,
+}
+
+/// Offset of the checksum in a report.
+const CHECKSUM_OFFSET: usize = 88;
+
+/// The part of a request that has to match, command register and payload; the status byte and
+/// checksum are ignored.
+fn request_key(request: &[u8]) -> &[u8] {
+    let start = std::cmp::min(WIRESHARK_PAYLOAD_START - 2, request.len());
+    let end = std::cmp::max(start, std::cmp::min(CHECKSUM_OFFSET, request.len()));
+    &request[start..end]
+}
+
+/// Parse a colon separated hex string, like [`crate::commands::parse_wireshark_value`] but
+/// returning an error instead of panicking.
+fn parse_hex(path: &str, z: &str) -> Result<Vec<u8>, Error> {
+    let mut r: Vec<u8> = Vec::new();
+    if z.is_empty() {
+        return Ok(r);
+    }
+    for b in z.split(':') {
+        match u8::from_str_radix(b, 16) {
+            Ok(number) => r.push(number),
+            Err(e) => {
+                return Err(Error::config(
+                    path,
+                    &format!("Can't parse {:?} as hex byte: {}", b, e),
+                ))
+            }
+        }
+    }
+    Ok(r)
+}
+
+impl ReplayHidHal {
+    /// Load the exchanges from a file written by the [`RecordingHidHal`].
+    pub fn new(path: &str) -> Result<ReplayHidHal, Error> {
+        let file = std::fs::File::open(path)?;
+        let recorded: Vec<Exchange> =
+            serde_yaml::from_reader(file).map_err(|e| Error::config_yaml(path, e))?;
+        let mut exchanges = vec![];
+        for exchange in recorded.iter() {
+            exchanges.push((
+                parse_hex(path, &exchange.request)?,
+                parse_hex(path, &exchange.response)?,
+            ));
+        }
+        Ok(ReplayHidHal {
+            used: vec![false; exchanges.len()],
+            exchanges,
+            current: None,
+        })
+    }
+}
+
+impl HidHal for ReplayHidHal {
+    fn enumerate(
+        &mut self,
+        _vendor_id: u16,
+        _product_id: u16,
+        _endpoint_id: u32,
+    ) -> Result<Vec<DeviceInfo>, Error> {
+        Ok(vec![])
+    }
+
+    fn connect(
+        &mut self,
+        _vendor_id: u16,
+        _product_id: u16,
+        _endpoint_id: u32,
+        _selector: &DeviceSelector,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn control(&mut self, payload: &[u8]) -> Result<(), Error> {
+        let key = request_key(payload);
+        let matching: Vec<usize> = (0..self.exchanges.len())
+            .filter(|i| request_key(&self.exchanges[*i].0) == key)
+            .collect();
+        self.current = matching
+            .iter()
+            .find(|i| !self.used[**i])
+            .or_else(|| matching.last())
+            .copied();
+        match self.current {
+            Some(i) => {
+                self.used[i] = true;
+                Ok(())
+            }
+            None => Err(Error::Transport(format!(
+                "No recorded response for request {}",
+                to_wireshark_value(payload)
+            ))),
+        }
+    }
+
+    fn get_report(&mut self) -> Result<Vec<u8>, Error> {
+        match self.current {
+            Some(i) => Ok(self.exchanges[i].1.clone()),
+            None => Err(Error::Transport("No request was sent.".to_string())),
+        }
+    }
+}
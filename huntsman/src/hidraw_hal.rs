@@ -0,0 +1,231 @@
+//! A [`HidHal`] that talks to the linux hidraw driver directly, without hidapi or libusb.
+//!
+//! Devices are found through sysfs, each `/sys/class/hidraw/hidrawN/device/uevent` holds the
+//! same vendor and product id that the udev rules in `misc/udev` match on. Feature reports are
+//! exchanged with the `HIDIOCSFEATURE` and `HIDIOCGFEATURE` ioctls on `/dev/hidrawN`.
+extern crate libc;
+
+use crate::hid_hal::{prepend_zero, DeviceInfo, DeviceSelector, HidHal};
+use crate::Error;
+use std::os::unix::io::AsRawFd;
+
+/// Directory in which the kernel lists the hidraw devices.
+const SYSFS_HIDRAW: &str = "/sys/class/hidraw";
+
+/// Length of a feature report, including the report id.
+const REPORT_LENGTH: usize = 91;
+
+/// Equivalent of the `_IOC(_IOC_READ | _IOC_WRITE, 'H', nr, len)` macro from the kernel headers.
+const fn hid_ioctl(nr: u64, len: usize) -> u64 {
+    const IOC_READ_WRITE: u64 = 3;
+    (IOC_READ_WRITE << 30) | ((len as u64) << 16) | ((b'H' as u64) << 8) | nr
+}
+
+/// `HIDIOCSFEATURE(len)`, sends a feature report.
+const fn hidiocsfeature(len: usize) -> u64 {
+    hid_ioctl(0x06, len)
+}
+
+/// `HIDIOCGFEATURE(len)`, retrieves a feature report.
+const fn hidiocgfeature(len: usize) -> u64 {
+    hid_ioctl(0x07, len)
+}
+
+/// Parse the contents of a hidraw device's uevent file, the path is the `/dev` node to use.
+/// Returns None if the required keys are missing, or if the device isn't a usb device.
+fn parse_uevent(path: &str, uevent: &str) -> Option<DeviceInfo> {
+    let mut ids: Option<(u16, u16)> = None;
+    let mut interface: Option<i32> = None;
+    let mut serial: Option<String> = None;
+    for line in uevent.lines() {
+        let (key, value) = match line.split_once('=') {
+            Some(kv) => kv,
+            None => continue,
+        };
+        match key {
+            // Bus, vendor and product as hexadecimal; HID_ID=0003:00001532:00000226
+            "HID_ID" => {
+                let parts: Vec<&str> = value.split(':').collect();
+                if parts.len() != 3 || u32::from_str_radix(parts[0], 16).ok()? != 0x03 {
+                    return None;
+                }
+                ids = Some((
+                    u32::from_str_radix(parts[1], 16).ok()? as u16,
+                    u32::from_str_radix(parts[2], 16).ok()? as u16,
+                ));
+            }
+            // The physical path ends with the interface; HID_PHYS=usb-0000:00:14.0-2/input2
+            "HID_PHYS" => {
+                interface = value
+                    .rsplit_once("/input")
+                    .and_then(|(_, i)| i.parse::<i32>().ok());
+            }
+            "HID_UNIQ" if !value.is_empty() => serial = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    let (vendor_id, product_id) = ids?;
+    Some(DeviceInfo {
+        path: path.to_string(),
+        serial,
+        vendor_id,
+        product_id,
+        interface: interface?,
+    })
+}
+
+/// Struct to communicate with a device through `/dev/hidrawN`.
+pub struct HidrawHal {
+    connected_device: Option<std::fs::File>,
+}
+
+impl HidrawHal {
+    /// Create the hal, this doesn't touch any devices yet.
+    pub fn new() -> Result<HidrawHal, Error> {
+        Ok(HidrawHal {
+            connected_device: None,
+        })
+    }
+
+    /// Read the uevent files of all hidraw devices, devices that can't be read are skipped.
+    fn devices(&self) -> Result<Vec<DeviceInfo>, Error> {
+        let mut devices = vec![];
+        let entries = std::fs::read_dir(SYSFS_HIDRAW)
+            .map_err(|e| Error::Transport(format!("Can't list {}: {}", SYSFS_HIDRAW, e)))?;
+        for entry in entries {
+            let entry = entry?;
+            let uevent = match std::fs::read_to_string(entry.path().join("device/uevent")) {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            let path = format!("/dev/{}", entry.file_name().to_string_lossy());
+            if let Some(info) = parse_uevent(&path, &uevent) {
+                devices.push(info);
+            }
+        }
+        devices.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(devices)
+    }
+
+    /// Perform a feature report ioctl on the connected device, returns the ioctl's result.
+    fn feature_ioctl(&mut self, request: u64, buffer: &mut [u8]) -> Result<usize, Error> {
+        let fd = match &self.connected_device {
+            None => return Err(Error::Transport("No connected device.".to_string())),
+            Some(f) => f.as_raw_fd(),
+        };
+        let res = unsafe { libc::ioctl(fd, request as _, buffer.as_mut_ptr()) };
+        if res < 0 {
+            return Err(Error::Transport(format!(
+                "{}",
+                std::io::Error::last_os_error()
+            )));
+        }
+        Ok(res as usize)
+    }
+}
+
+impl HidHal for HidrawHal {
+    fn enumerate(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+        endpoint_id: u32,
+    ) -> Result<Vec<DeviceInfo>, Error> {
+        Ok(self
+            .devices()?
+            .into_iter()
+            .filter(|d| {
+                d.vendor_id == vendor_id
+                    && d.product_id == product_id
+                    && d.interface == endpoint_id as i32
+            })
+            .collect())
+    }
+
+    fn connect(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+        endpoint_id: u32,
+        selector: &DeviceSelector,
+    ) -> Result<(), Error> {
+        let found = self
+            .enumerate(vendor_id, product_id, endpoint_id)?
+            .into_iter()
+            .find(|d| selector.matches(d));
+        match found {
+            Some(d) => {
+                let file = std::fs::OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .open(&d.path)
+                    .map_err(|e| Error::Transport(format!("Can't open {}: {}", d.path, e)))?;
+                self.connected_device = Some(file);
+                Ok(())
+            }
+            None => match selector {
+                DeviceSelector::First => Err(Error::Transport("No device found.".to_string())),
+                _ => Err(Error::Transport(format!(
+                    "No device found matching {:?}.",
+                    selector
+                ))),
+            },
+        }
+    }
+
+    fn control(&mut self, payload: &[u8]) -> Result<(), Error> {
+        let mut buffer = prepend_zero(payload);
+        let request = hidiocsfeature(buffer.len());
+        self.feature_ioctl(request, &mut buffer)?;
+        Ok(())
+    }
+
+    fn get_report(&mut self) -> Result<Vec<u8>, Error> {
+        // The first byte is the report id, this also specifies the length.
+        let mut buffer = [0u8; REPORT_LENGTH];
+        let len = self.feature_ioctl(hidiocgfeature(REPORT_LENGTH), &mut buffer)?;
+        Ok(buffer[1..len.clamp(1, REPORT_LENGTH)].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ioctl_numbers() {
+        // Values as computed by the kernel headers.
+        assert_eq!(hidiocsfeature(91), 0xc05b4806);
+        assert_eq!(hidiocgfeature(91), 0xc05b4807);
+    }
+
+    #[test]
+    fn test_parse_uevent() {
+        let uevent = "DRIVER=hid-generic\n\
+                      HID_ID=0003:00001532:00000226\n\
+                      HID_NAME=Razer Razer Huntsman Elite\n\
+                      HID_PHYS=usb-0000:00:14.0-2/input2\n\
+                      HID_UNIQ=\n\
+                      MODALIAS=hid:b0003g0001v00001532p00000226\n";
+        let info = parse_uevent("/dev/hidraw3", uevent).expect("should parse");
+        assert_eq!(
+            info,
+            DeviceInfo {
+                path: "/dev/hidraw3".to_string(),
+                serial: None,
+                vendor_id: 0x1532,
+                product_id: 0x0226,
+                interface: 2,
+            }
+        );
+
+        let with_serial = uevent.replace("HID_UNIQ=", "HID_UNIQ=PM1234");
+        let info = parse_uevent("/dev/hidraw3", &with_serial).expect("should parse");
+        assert_eq!(info.serial, Some("PM1234".to_string()));
+
+        // Bluetooth devices and incomplete files are ignored.
+        let bluetooth = uevent.replace("HID_ID=0003", "HID_ID=0005");
+        assert_eq!(parse_uevent("/dev/hidraw3", &bluetooth), None);
+        assert_eq!(parse_uevent("/dev/hidraw3", "DRIVER=hid-generic\n"), None);
+    }
+}
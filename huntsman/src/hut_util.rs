@@ -1,35 +1,11 @@
 use serde::de::Deserializer;
 use serde::ser::Serializer;
 use serde::Deserialize;
-use usb_hut::hid_keyboard_page;
+use usb_hut::{hid_consumer_page, hid_keyboard_page};
 
-#[derive(Debug)]
-struct KeyError {
-    details: String,
-}
-
-impl KeyError {
-    fn new(msg: &str) -> KeyError {
-        KeyError {
-            details: msg.to_string(),
-        }
-    }
-    fn boxed(msg: String) -> Box<KeyError> {
-        Box::new(KeyError::new(msg.as_str()))
-    }
-}
-impl std::fmt::Display for KeyError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "KeyError {}", self.details)
-    }
-}
-impl std::error::Error for KeyError {
-    fn description(&self) -> &str {
-        &self.details
-    }
-}
+use crate::Error;
 
-fn key_name_to_key(key: &str) -> Result<usb_hut::Key, Box<dyn std::error::Error>> {
+fn key_name_to_key(key: &str) -> Result<usb_hut::Key, Error> {
     // try to find a key that matches our self.key.
     let key_uppercase = key.to_uppercase();
     let with_key = "KEY_".to_string() + &key_uppercase;
@@ -41,16 +17,19 @@ fn key_name_to_key(key: &str) -> Result<usb_hut::Key, Box<dyn std::error::Error>
             return Ok(*k);
         }
     }
-    Err(KeyError::boxed(format!("Key not found, got {}.", key)))
+    Err(Error::InvalidArgument(format!(
+        "Key not found, got {}.",
+        key
+    )))
 }
 
 /// Function to look up a key by name and return the scan code.
-pub fn key_name_to_at101(key: &str) -> Result<u8, Box<dyn std::error::Error>> {
+pub fn key_name_to_at101(key: &str) -> Result<u8, Error> {
     let k = key_name_to_key(key)?;
     if let Some(code) = k.at101 {
         return Ok(code as u8);
     } else {
-        return Err(KeyError::boxed(format!(
+        return Err(Error::InvalidArgument(format!(
             "Key {}, found, but this key has no at101 scan code.",
             key
         )));
@@ -58,7 +37,7 @@ pub fn key_name_to_at101(key: &str) -> Result<u8, Box<dyn std::error::Error>> {
 }
 
 /// Function to go from at101 code to a key name.
-pub fn at101_to_key_name(scan_code: u8) -> Result<&'static str, Box<dyn std::error::Error>> {
+pub fn at101_to_key_name(scan_code: u8) -> Result<&'static str, Error> {
     for k in hid_keyboard_page::keys() {
         if let Some(key_code) = k.at101 {
             if key_code == scan_code as usize {
@@ -66,27 +45,27 @@ pub fn at101_to_key_name(scan_code: u8) -> Result<&'static str, Box<dyn std::err
             }
         }
     }
-    Err(KeyError::boxed(format!(
+    Err(Error::InvalidArgument(format!(
         "Could not find key for at101/scan_code: {}.",
         scan_code
     )))
 }
 
 /// Function to go from a keyboard hid id to a key name.
-pub fn keyboard_hid_to_key_name(hid_id: u8) -> Result<&'static str, Box<dyn std::error::Error>> {
+pub fn keyboard_hid_to_key_name(hid_id: u8) -> Result<&'static str, Error> {
     for k in hid_keyboard_page::keys() {
         if k.hid == hid_id as usize {
             return Ok(&k.name);
         }
     }
-    Err(KeyError::boxed(format!(
+    Err(Error::InvalidArgument(format!(
         "Could not find key for usb hid id: {}.",
         hid_id
     )))
 }
 
 /// Function to look up a key by name and return the scan code.
-pub fn key_name_to_keyboard_hid(key: &str) -> Result<u8, Box<dyn std::error::Error>> {
+pub fn key_name_to_keyboard_hid(key: &str) -> Result<u8, Error> {
     let k = key_name_to_key(key)?;
     Ok(k.hid as u8)
 }
@@ -110,11 +89,17 @@ where
     Ok(r)
 }
 
+/// Name for hid id 0, which isn't a key; mappings that only press modifiers use it.
+const NO_KEY_NAME: &str = "none";
+
 pub fn keyboard_page_serialize<S>(scan_code: &u8, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
     use serde::ser::Error;
+    if *scan_code == 0 {
+        return serializer.serialize_str(NO_KEY_NAME);
+    }
     serializer.serialize_str(keyboard_hid_to_key_name(*scan_code).map_err(Error::custom)?)
 }
 
@@ -124,10 +109,59 @@ where
 {
     let s: String = Deserialize::deserialize(deserializer)?;
     use serde::de::Error;
+    if s.to_lowercase() == NO_KEY_NAME {
+        return Ok(0);
+    }
     let r = key_name_to_keyboard_hid(&s).map_err(Error::custom)?;
     Ok(r)
 }
 
+/// Name of a usage on one of the pages without keys, in lowercase as it's used in the
+/// configuration files.
+pub fn usage_to_name(usages: &[usb_hut::PageUsage], id: usize) -> Option<String> {
+    usages
+        .iter()
+        .find(|u| u.id == id)
+        .map(|u| u.name.to_lowercase())
+}
+
+/// Look up the id of a usage on one of the pages without keys by its name, ignoring case.
+pub fn name_to_usage(usages: &[usb_hut::PageUsage], name: &str) -> Option<usize> {
+    usages
+        .iter()
+        .find(|u| u.name.eq_ignore_ascii_case(name))
+        .map(|u| u.id)
+}
+
+pub fn consumer_page_serialize<S>(hid_id: &u16, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match usage_to_name(hid_consumer_page::usages(), *hid_id as usize) {
+        Some(name) => serializer.serialize_str(&name),
+        None => serializer.serialize_u16(*hid_id),
+    }
+}
+
+pub fn consumer_page_deserialize<'de, D>(deserializer: D) -> Result<u16, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NameOrCode {
+        Name(String),
+        Code(u16),
+    }
+    use serde::de::Error;
+    match NameOrCode::deserialize(deserializer)? {
+        NameOrCode::Code(v) => Ok(v),
+        NameOrCode::Name(s) => name_to_usage(hid_consumer_page::usages(), &s)
+            .map(|v| v as u16)
+            .ok_or_else(|| Error::custom(format!("Consumer page usage not found, got {}.", s))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,4 +186,14 @@ mod tests {
         assert!(key_name_to_at101("kpd_c").is_err()); // no at101 code
         assert!(key_name_to_at101("this is not a key").is_err()); // no key found
     }
+
+    #[test]
+    fn test_usage_lookup() {
+        let usages = hid_consumer_page::usages();
+        assert_eq!(name_to_usage(usages, "volume_increment"), Some(0xe9));
+        assert_eq!(name_to_usage(usages, "AL_Calculator"), Some(0x192));
+        assert_eq!(name_to_usage(usages, "not a usage"), None);
+        assert_eq!(usage_to_name(usages, 0xe2), Some("mute".to_string()));
+        assert_eq!(usage_to_name(usages, 0x07), None); // reserved
+    }
 }
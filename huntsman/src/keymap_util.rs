@@ -1,17 +1,22 @@
-pub use crate::commands::mappings::{Key, KeyMapping, KeyboardKey, Modifier, Modifiers};
+pub use crate::commands::mappings::{
+    GenericDesktopUsage, Key, KeyMapping, KeyboardKey, Modifier, Modifiers, ProfileInstruction,
+    SpecialFunction,
+};
 use crate::configuration::KeyConfig;
 
+/// The at101 key ids present on the Huntsman Elite.
+pub const AT101_KEYS: [u8; 102] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0f, 0x10, 0x11,
+    0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20, 0x21,
+    0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2b, 0x2c, 0x2e, 0x2f, 0x30, 0x31, 0x32, 0x33,
+    0x34, 0x35, 0x36, 0x37, 0x39, 0x3a, 0x3c, 0x3d, 0x3e, 0x40, 0x4b, 0x4c, 0x4f, 0x50, 0x51, 0x53,
+    0x54, 0x55, 0x56, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5f, 0x60, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6a, 0x6c, 0x6e, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7a, 0x7b, 0x7c, 0x7d, 0x7e, 0x81,
+];
+
 pub fn at101_keys() -> &'static [u8] {
-    const KEYS_PRESENT: [u8; 102] = [
-        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0f, 0x10,
-        0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
-        0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2b, 0x2c, 0x2e, 0x2f, 0x30,
-        0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x39, 0x3a, 0x3c, 0x3d, 0x3e, 0x40, 0x4b, 0x4c,
-        0x4f, 0x50, 0x51, 0x53, 0x54, 0x55, 0x56, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5f, 0x60, 0x61,
-        0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6a, 0x6c, 0x6e, 0x70, 0x71, 0x72, 0x73,
-        0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7a, 0x7b, 0x7c, 0x7d, 0x7e, 0x81,
-    ];
-    &KEYS_PRESENT
+    &AT101_KEYS
 }
 
 pub fn get_default_keymap(key: &Key) -> KeyMapping {
@@ -2199,7 +2204,7 @@ pub fn hypershift_keymaps() -> &'static [KeyConfig] {
                 id: 120,
                 hypershift: true,
             },
-            mapping: KeyMapping::Special(4),
+            mapping: KeyMapping::Special(SpecialFunction::MacroRecord),
         },
         KeyConfig {
             profile: None,
@@ -2207,7 +2212,7 @@ pub fn hypershift_keymaps() -> &'static [KeyConfig] {
                 id: 121,
                 hypershift: true,
             },
-            mapping: KeyMapping::Special(3),
+            mapping: KeyMapping::Special(SpecialFunction::GameMode),
         },
         KeyConfig {
             profile: None,
@@ -2215,7 +2220,7 @@ pub fn hypershift_keymaps() -> &'static [KeyConfig] {
                 id: 122,
                 hypershift: true,
             },
-            mapping: KeyMapping::Special(9),
+            mapping: KeyMapping::Special(SpecialFunction::BrightnessDown),
         },
         KeyConfig {
             profile: None,
@@ -2223,7 +2228,7 @@ pub fn hypershift_keymaps() -> &'static [KeyConfig] {
                 id: 123,
                 hypershift: true,
             },
-            mapping: KeyMapping::Special(8),
+            mapping: KeyMapping::Special(SpecialFunction::BrightnessUp),
         },
         KeyConfig {
             profile: None,
@@ -2253,7 +2258,7 @@ pub fn hypershift_keymaps() -> &'static [KeyConfig] {
                 id: 126,
                 hypershift: true,
             },
-            mapping: KeyMapping::GenericDesktop(130),
+            mapping: KeyMapping::GenericDesktop(GenericDesktopUsage::SystemSleep),
         },
         KeyConfig {
             profile: None,
@@ -2261,7 +2266,7 @@ pub fn hypershift_keymaps() -> &'static [KeyConfig] {
                 id: 129,
                 hypershift: true,
             },
-            mapping: KeyMapping::ProfileInstruction(4),
+            mapping: KeyMapping::ProfileInstruction(ProfileInstruction::Next),
         },
     ];
     &HYPERSHIFT_KEYMAPS
@@ -1,53 +1,246 @@
 //! This crate provides an object to interface with the keyboard, it also provides a command line
 //! utility that makes use of this object.
 
-mod hid_hal;
+pub mod hid_hal;
+pub use hid_hal::{DeviceInfo, DeviceSelector, HidHal};
+#[cfg(all(feature = "hidraw", target_os = "linux"))]
+pub mod hidraw_hal;
 
 pub mod commands;
 
+pub mod error;
+pub use error::Error;
+
+pub use commands::CommandStatus;
 pub use commands::RGB;
 
+pub mod apply;
 pub mod configuration;
+pub mod devices;
+pub mod diff;
+pub mod macro_names;
+pub mod snapshot;
+pub use devices::{DeviceModel, EffectKind};
 mod hut_util;
 mod keymap_util;
+pub mod virtual_keyboard;
+
+use struct_helper::FromBytes;
+
+/// Determines how the report is read back after sending a command. The device may still be busy
+/// processing the command, or return the report of the previous command, in which case the
+/// report is read again until it settles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Time to wait before reading the report again.
+    pub poll_interval: std::time::Duration,
+    /// Maximum number of times the report is read for a single command.
+    pub max_attempts: usize,
+    /// Maximum time to wait for the report to settle after sending the command.
+    pub deadline: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            poll_interval: std::time::Duration::from_millis(5),
+            max_attempts: 20,
+            deadline: std::time::Duration::from_millis(500),
+        }
+    }
+}
 
-/// Object to interface with the Huntsman Elite keyboard.
+/// The storage the keyboard has for macros, in bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StorageStats {
+    pub total: u32,
+    pub free: u32,
+    pub used: u32,
+}
+
+/// Selects the mappings [`Huntsman::get_all_mappings`] returns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct MappingFilter {
+    /// Leave out the keys that hold their default mapping.
+    pub non_default_only: bool,
+    /// Only read this layer, both the normal and the hypershift layer are read if None.
+    pub hypershift: Option<bool>,
+}
+
+/// Object to interface with the Huntsman Elite keyboard, or one of the other [`devices::MODELS`].
+///
+/// Methods that only write to the device return the [`CommandStatus`] it reported, methods that
+/// read from the device return [`Error::Rejected`] if it didn't report success.
 pub struct Huntsman {
     hal: Box<dyn hid_hal::HidHal>,
+    model: &'static DeviceModel,
     print_comm: bool,
     print_retrieve: bool,
+    retry_policy: RetryPolicy,
 }
 
-type Error = Box<dyn std::error::Error>;
-
 impl Huntsman {
     /// Construct a new Huntsman instance, this tries to connect to the usb device and errors if it can't be found.
     pub fn new() -> Result<Huntsman, Error> {
-        match hid_hal::HidApiHal::new() {
-            Ok(mut hal) => match hal.connect(0x1532, 0x226, 2) {
-                Ok(()) => Ok(Huntsman {
-                    hal: hal,
-                    print_comm: false,
-                    print_retrieve: false,
-                }),
-                Err(e) => Err(e),
-            },
-            Err(e) => Err(e),
+        Huntsman::open(&DeviceSelector::First)
+    }
+
+    /// Construct a new Huntsman instance connected to the device chosen by the selector, the
+    /// known models are tried in order.
+    pub fn open(selector: &DeviceSelector) -> Result<Huntsman, Error> {
+        let (hal, model) = Huntsman::connect_hal(selector)?;
+        Ok(Huntsman::from_hal(hal, model))
+    }
+
+    /// Like [`Huntsman::open`], but records all communication with the device to the file at
+    /// path, such that it can be replayed with [`Huntsman::replay_new`].
+    pub fn open_recording(selector: &DeviceSelector, path: &str) -> Result<Huntsman, Error> {
+        let (hal, model) = Huntsman::connect_hal(selector)?;
+        let hal = hid_hal::RecordingHidHal::new(hal, path)?;
+        Ok(Huntsman::from_hal(Box::new(hal), model))
+    }
+
+    /// Construct an instance that doesn't connect to a device, but serves the responses from a
+    /// file recorded with [`Huntsman::open_recording`].
+    pub fn replay_new(path: &str) -> Result<Huntsman, Error> {
+        let hal = hid_hal::ReplayHidHal::new(path)?;
+        Ok(Huntsman::from_hal(Box::new(hal), &devices::HUNTSMAN_ELITE))
+    }
+
+    /// Construct an instance backed by a [`virtual_keyboard::VirtualKeyboard`] of the Huntsman
+    /// Elite instead of a real device.
+    pub fn virtual_new() -> Result<Huntsman, Error> {
+        let model = &devices::HUNTSMAN_ELITE;
+        let hal = virtual_keyboard::VirtualKeyboard::new(model);
+        Ok(Huntsman::from_hal(Box::new(hal), model))
+    }
+
+    /// Instantiate the hal for the enabled backend, hidraw is preferred over hidapi.
+    #[cfg(all(feature = "hidraw", target_os = "linux"))]
+    fn system_hal() -> Result<Box<dyn hid_hal::HidHal>, Error> {
+        Ok(Box::new(hidraw_hal::HidrawHal::new()?))
+    }
+
+    #[cfg(all(feature = "hidapi", not(all(feature = "hidraw", target_os = "linux"))))]
+    fn system_hal() -> Result<Box<dyn hid_hal::HidHal>, Error> {
+        hid_hal::HidApiHal::new()
+    }
+
+    #[cfg(not(any(feature = "hidapi", all(feature = "hidraw", target_os = "linux"))))]
+    fn system_hal() -> Result<Box<dyn hid_hal::HidHal>, Error> {
+        Err(Error::Transport(
+            "Built without a hid backend, enable the hidapi or hidraw feature.".to_string(),
+        ))
+    }
+
+    /// Connect to the device chosen by the selector, returning the hal and the model.
+    fn connect_hal(
+        selector: &DeviceSelector,
+    ) -> Result<(Box<dyn hid_hal::HidHal>, &'static DeviceModel), Error> {
+        let mut hal = Huntsman::system_hal()?;
+        let mut last_error = Error::Transport("No device found.".to_string());
+        for model in devices::MODELS.iter() {
+            match hal.connect(
+                devices::RAZER_VENDOR_ID,
+                model.product_id,
+                model.interface,
+                selector,
+            ) {
+                Ok(()) => return Ok((hal, model)),
+                Err(e) => last_error = e,
+            }
         }
+        Err(last_error)
+    }
+
+    /// List all connected keyboards of the known models, without connecting to them.
+    pub fn enumerate() -> Result<Vec<DeviceInfo>, Error> {
+        let mut hal = Huntsman::system_hal()?;
+        let mut devices = vec![];
+        for model in devices::MODELS.iter() {
+            devices.extend(hal.enumerate(
+                devices::RAZER_VENDOR_ID,
+                model.product_id,
+                model.interface,
+            )?);
+        }
+        Ok(devices)
     }
 
     pub fn dry_new() -> Result<Huntsman, Error> {
-        match hid_hal::DryHidHal::new() {
-            Ok(mut hal) => match hal.connect(0x1532, 0x226, 2) {
-                Ok(()) => Ok(Huntsman {
-                    hal: hal,
-                    print_comm: false,
-                    print_retrieve: false,
-                }),
-                Err(e) => Err(e),
-            },
-            Err(e) => Err(e),
+        let model = &devices::HUNTSMAN_ELITE;
+        let mut hal = hid_hal::DryHidHal::new()?;
+        hal.connect(
+            devices::RAZER_VENDOR_ID,
+            model.product_id,
+            model.interface,
+            &DeviceSelector::First,
+        )?;
+        Ok(Huntsman::from_hal(hal, model))
+    }
+
+    /// Construct an instance that uses the provided hal to communicate with a Huntsman Elite,
+    /// the hal is expected to be connected already.
+    pub fn with_hal(hal: Box<dyn HidHal>) -> Huntsman {
+        Huntsman::from_hal(hal, &devices::HUNTSMAN_ELITE)
+    }
+
+    /// Like [`Huntsman::with_hal`], but for the provided keyboard model.
+    pub fn with_hal_and_model(hal: Box<dyn HidHal>, model: &'static DeviceModel) -> Huntsman {
+        Huntsman::from_hal(hal, model)
+    }
+
+    /// Create the object from a connected hal.
+    fn from_hal(hal: Box<dyn HidHal>, model: &'static DeviceModel) -> Huntsman {
+        Huntsman {
+            hal,
+            model,
+            print_comm: false,
+            print_retrieve: false,
+            retry_policy: Default::default(),
+        }
+    }
+
+    /// The model of the keyboard that is connected.
+    pub fn model(&self) -> &'static DeviceModel {
+        self.model
+    }
+
+    /// Returns an error if the connected model doesn't support the effect.
+    fn check_effect(&self, effect: EffectKind) -> Result<(), Error> {
+        if self.model.supports(effect) {
+            return Ok(());
         }
+        Err(Error::InvalidArgument(format!(
+            "The {} doesn't support the {:?} effect.",
+            self.model.name, effect
+        )))
+    }
+
+    /// Returns an error if the led row doesn't exist on the connected model.
+    fn check_row(&self, row: u8) -> Result<(), Error> {
+        if row >= self.model.led_rows {
+            return Err(Error::InvalidArgument(format!(
+                "Row {} doesn't exist, the {} has {} rows.",
+                row, self.model.name, self.model.led_rows
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the profile id can't be created or deleted, the first profile always
+    /// exists.
+    fn check_removable_profile(&self, profile_id: commands::ProfileId) -> Result<(), Error> {
+        let profiles = &self.model.profiles;
+        if profile_id <= *profiles.start() || profile_id > *profiles.end() {
+            return Err(Error::InvalidArgument(format!(
+                "Profile ids must be in [{}, {}], got {}.",
+                profiles.start() + 1,
+                profiles.end(),
+                profile_id
+            )));
+        }
+        Ok(())
     }
 
     /// Toggle printing the outgoing communication on or off.
@@ -60,6 +253,44 @@ impl Huntsman {
         self.print_retrieve = state;
     }
 
+    /// Set the policy that determines how often the report is read back while the device is busy.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Retrieve the report for the command that was just sent, reading it again while the device
+    /// reports busy or while the report belongs to another command. A report on the same register
+    /// that doesn't echo the request is left over from the previous command.
+    fn retrieve_report(&mut self, command: &dyn commands::Command) -> Result<Vec<u8>, Error> {
+        let start = std::time::Instant::now();
+        let mut attempts = 0;
+        loop {
+            let res = self.hal.get_report()?;
+            attempts += 1;
+            let settled = match commands::wire::Command::from_le_bytes(&res) {
+                Ok(wire) => {
+                    wire.cmd == command.register()
+                        && !CommandStatus::from(wire.status).is_busy()
+                        && command.echoed_by(&wire.payload[..])
+                }
+                // Not a report we can interpret, let the response processing deal with that.
+                Err(_) => true,
+            };
+            if settled {
+                return Ok(res);
+            }
+            if attempts >= self.retry_policy.max_attempts
+                || start.elapsed() + self.retry_policy.poll_interval > self.retry_policy.deadline
+            {
+                return Err(Error::Timeout {
+                    cmd: command.register(),
+                    attempts,
+                });
+            }
+            std::thread::sleep(self.retry_policy.poll_interval);
+        }
+    }
+
     /// Function to send a command to the control endpoint.
     fn set_command(&mut self, command: &dyn commands::Command) -> Result<Option<Vec<u8>>, Error> {
         let v = command.serialize();
@@ -76,7 +307,7 @@ impl Huntsman {
         }
         let r = self.hal.control(&v.as_slice());
         if r.is_ok() {
-            let result = self.hal.get_report();
+            let result = self.retrieve_report(command);
             if self.print_retrieve {
                 println!("<- {:?}", result);
             }
@@ -109,6 +340,12 @@ impl Huntsman {
         self.set_command(boxed_command.as_ref())
     }
 
+    /// Function to send a command and decode the status the device reports for it.
+    fn command_status(&mut self, command: &dyn commands::Command) -> Result<CommandStatus, Error> {
+        let result = self.set_command(command)?;
+        command.status(&result.unwrap())
+    }
+
     /// Function that sends a single SetLedState instruction, index is the row, start is the column, count is the number
     /// of leds to set from the index.
     pub fn set_color_single(
@@ -117,8 +354,15 @@ impl Huntsman {
         count: u8,
         index: u8,
         start: u8,
-    ) -> Result<(), Error> {
+    ) -> Result<CommandStatus, Error> {
         let mut leds: commands::SetLedState = Default::default();
+        self.check_row(index)?;
+        if start as usize + count as usize > self.model.led_columns as usize {
+            return Err(Error::InvalidArgument(format!(
+                "Can't set {} leds from column {}, row only has {} leds.",
+                count, start, self.model.led_columns
+            )));
+        }
         leds.count = start + count;
         leds.id = index;
         for l in start as usize..leds.count as usize {
@@ -126,11 +370,19 @@ impl Huntsman {
             leds.leds[l].g = color.g;
             leds.leds[l].b = color.b;
         }
-        return self.set_command(&leds).and_then(|_v| Ok(()));
+        return self.command_status(&leds);
     }
 
-    pub fn set_color(&mut self, row: u8, color: &[RGB]) -> Result<(), Error> {
+    pub fn set_color(&mut self, row: u8, color: &[RGB]) -> Result<CommandStatus, Error> {
         let mut leds: commands::SetLedState = Default::default();
+        self.check_row(row)?;
+        if color.len() > self.model.led_columns as usize {
+            return Err(Error::InvalidArgument(format!(
+                "Got {} colors, row only has {} leds.",
+                color.len(),
+                self.model.led_columns
+            )));
+        }
         leds.count = color.len() as u8;
         leds.id = row;
         for l in 0..leds.count as usize {
@@ -138,31 +390,52 @@ impl Huntsman {
             leds.leds[l].g = color[l].g;
             leds.leds[l].b = color[l].b;
         }
-        return self.set_command(&leds).and_then(|_v| Ok(()));
+        return self.command_status(&leds);
     }
 
     /// Set the brightness of the entire keyboard, specify as [0, 1.0].
-    pub fn set_brightness(&mut self, profile: u8, value: f32) -> Result<(), Error> {
+    pub fn set_brightness(&mut self, profile: u8, value: f32) -> Result<CommandStatus, Error> {
         let mut cmd: commands::SetLedBrightness = Default::default();
         cmd.profile = profile;
         cmd.value = value;
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Toggle game mode on or off.
-    pub fn set_game_mode(&mut self, value: bool) -> Result<(), Error> {
+    pub fn set_game_mode(&mut self, value: bool) -> Result<CommandStatus, Error> {
         let mut cmd: commands::SetGameMode = Default::default();
         cmd.value = value;
-        let r = self.set_command(&cmd).and_then(|_v| Ok(()));
-        return r;
+        return self.command_status(&cmd);
+    }
+
+    /// Retrieve the brightness of a profile, in [0, 1.0].
+    pub fn get_brightness(&mut self, profile: u8) -> Result<f32, Error> {
+        let mut cmd: commands::GetLedBrightness = Default::default();
+        cmd.0.profile = profile;
+        let result = self.set_command(&cmd)?;
+        let response = commands::Command::response(&cmd, &result.unwrap())?;
+        let response = response
+            .downcast_ref::<commands::GetLedBrightness>()
+            .unwrap();
+        Ok(response.0.value as f32 / 255.0)
+    }
+
+    /// Retrieve whether game mode is enabled.
+    pub fn get_game_mode(&mut self) -> Result<bool, Error> {
+        let cmd: commands::GetGameMode = Default::default();
+        let result = self.set_command(&cmd)?;
+        let response = commands::Command::response(&cmd, &result.unwrap())?;
+        let response = response.downcast_ref::<commands::GetGameMode>().unwrap();
+        Ok(response.0.game_mode_enabled != 0)
     }
 
     /// Dev function exposed to the commandline utility.
-    pub fn dev_run(&mut self) -> Result<(), Error> {
+    pub fn dev_run(&mut self) -> Result<CommandStatus, Error> {
         self.set_print_comm(true);
         self.set_print_retrieve(true);
         let cmd = commands::dev_run_cmd();
-        return self.set_command_box(&cmd).and_then(|_v| Ok(()));
+        let result = self.set_command_box(&cmd)?;
+        cmd.status(&result.unwrap())
     }
 
     /// Retrieve the serial number
@@ -183,16 +456,13 @@ impl Huntsman {
         profile: u8,
         key: commands::mappings::Key,
         mapping: commands::mappings::KeyMapping,
-    ) -> Result<(), Error> {
+    ) -> Result<CommandStatus, Error> {
         let cmd: commands::SetKeyMap = commands::SetKeyMap(commands::mappings::KeyMap {
             profile,
             key,
             mapping,
         });
-        let result = self.set_command(&cmd)?;
-        let response = commands::Command::response(&cmd, &result.unwrap())?;
-        let _response = response.downcast_ref::<commands::SetKeyMap>().unwrap();
-        Ok(())
+        self.command_status(&cmd)
     }
 
     /// Dump keymappings.
@@ -213,34 +483,121 @@ impl Huntsman {
         Ok(response.0)
     }
 
+    /// Retrieve the mappings of all keys of the model in a profile, first the normal layer, then
+    /// the hypershift layer. Each key takes a round trip, progress is called with the number of
+    /// keys read and the total after each one.
+    pub fn get_all_mappings(
+        &mut self,
+        profile: u8,
+        filter: MappingFilter,
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<Vec<configuration::KeyConfig>, Error> {
+        let layers: &[bool] = match filter.hypershift {
+            Some(false) => &[false],
+            Some(true) => &[true],
+            None => &[false, true],
+        };
+        let total = layers.len() * self.model.keys.len();
+        let mut done = 0;
+        let mut mappings = vec![];
+        for hypershift in layers.iter() {
+            for id in self.model.keys.iter() {
+                let key = commands::mappings::Key {
+                    id: *id,
+                    hypershift: *hypershift,
+                };
+                let mapping = self.get_mapping(profile, key)?.mapping;
+                done += 1;
+                progress(done, total);
+                if filter.non_default_only && mapping == configuration::get_default_keymap(&key) {
+                    continue;
+                }
+                mappings.push(configuration::KeyConfig {
+                    profile: None,
+                    key,
+                    mapping,
+                });
+            }
+        }
+        Ok(mappings)
+    }
+
+    /// Restore a profile to the factory state; the default mapping for every key on both layers,
+    /// the spectrum cycle effect and full brightness. If only_changed is set the mappings are read
+    /// first and only the keys that differ from their default are written.
+    /// Stops at the first command that doesn't succeed and returns its status.
+    pub fn reset_profile(
+        &mut self,
+        profile: u8,
+        only_changed: bool,
+    ) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Spectrum)?;
+        let keys: Vec<commands::mappings::Key> = if only_changed {
+            let filter = MappingFilter {
+                non_default_only: true,
+                hypershift: None,
+            };
+            self.get_all_mappings(profile, filter, &mut |_, _| {})?
+                .iter()
+                .map(|m| m.key)
+                .collect()
+        } else {
+            configuration::default_keymaps()
+                .iter()
+                .chain(configuration::hypershift_keymaps().iter())
+                .map(|m| m.key)
+                .filter(|k| self.model.has_key(k.id))
+                .collect()
+        };
+        for key in keys {
+            let mapping = configuration::get_default_keymap(&key);
+            let status = self.set_mapping(profile, key, mapping)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        let mut effect = commands::SetLedEffect::spectrum();
+        effect.set_profile(profile);
+        let status = self.command_status(&effect)?;
+        if !status.is_ok() {
+            return Ok(status);
+        }
+        self.set_brightness(profile, 1.0)
+    }
+
     /// Disables led effects, turning off each led. See also [`commands::SetLedEffect::off()`]
-    pub fn effect_off(&mut self) -> Result<(), Error> {
+    pub fn effect_off(&mut self) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Off)?;
         let cmd = commands::SetLedEffect::off();
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Sets a fixed color on all leds. See also [`commands::SetLedEffect::fixed()`]
-    pub fn effect_fixed(&mut self, color: &RGB) -> Result<(), Error> {
+    pub fn effect_fixed(&mut self, color: &RGB) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Fixed)?;
         let cmd = commands::SetLedEffect::fixed(&color);
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Applies the breathing effect, fading colors in and out sequentially. See also [`commands::SetLedEffect::breathing()`]
-    pub fn effect_breathing(&mut self, colors: &Vec<RGB>) -> Result<(), Error> {
+    pub fn effect_breathing(&mut self, colors: &Vec<RGB>) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Breathing)?;
         let cmd = commands::SetLedEffect::breathing(&colors);
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Spectrum cycle, entire keyboard cycles the hue. See also [`commands::SetLedEffect::spectrum()`]
-    pub fn effect_spectrum(&mut self) -> Result<(), Error> {
+    pub fn effect_spectrum(&mut self) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Spectrum)?;
         let cmd = commands::SetLedEffect::spectrum();
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// A hue wave moved over the keyboard. See also [`commands::SetLedEffect::wave()`]
-    pub fn effect_wave(&mut self, direction: bool, delay: u8) -> Result<(), Error> {
+    pub fn effect_wave(&mut self, direction: bool, delay: u8) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Wave)?;
         let cmd = commands::SetLedEffect::wave(direction, delay);
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Lights up keys after they are pressed. See also [`commands::SetLedEffect::reactive()`]
@@ -249,16 +606,18 @@ impl Huntsman {
         &mut self,
         duration: commands::Duration,
         colors: &Vec<RGB>,
-    ) -> Result<(), Error> {
+    ) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Reactive)?;
         let cmd = commands::SetLedEffect::reactive(duration, colors);
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Waves propagate outwards from pressed keys. See also [`commands::SetLedEffect::ripple()`]
     /// Only takes a single color
-    pub fn effect_ripple(&mut self, colors: &Vec<RGB>) -> Result<(), Error> {
+    pub fn effect_ripple(&mut self, colors: &Vec<RGB>) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Ripple)?;
         let cmd = commands::SetLedEffect::ripple(&colors);
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Keys light up randomly. See also [`commands::SetLedEffect::starlight()`]
@@ -267,15 +626,29 @@ impl Huntsman {
         &mut self,
         duration: commands::Duration,
         colors: &Vec<RGB>,
-    ) -> Result<(), Error> {
+    ) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Starlight)?;
         let cmd = commands::SetLedEffect::starlight(duration, colors);
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
     }
 
     /// Display the custom frame. See also [`commands::SetLedEffect::custom()`]
-    pub fn effect_custom(&mut self) -> Result<(), Error> {
+    pub fn effect_custom(&mut self) -> Result<CommandStatus, Error> {
+        self.check_effect(EffectKind::Custom)?;
         let cmd = commands::SetLedEffect::custom();
-        return self.set_command(&cmd).and_then(|_v| Ok(()));
+        return self.command_status(&cmd);
+    }
+
+    /// Retrieve the led effect of a profile, profile 0 holds the effect that is currently shown.
+    pub fn get_effect(&mut self, profile: u8) -> Result<commands::SetLedEffect, Error> {
+        let mut cmd: commands::GetLedEffect = Default::default();
+        cmd.0.profile = profile;
+        let result = self.set_command(&cmd)?;
+        let response = commands::Command::response(&cmd, &result.unwrap())?;
+        let response = response.downcast_ref::<commands::GetLedEffect>().unwrap();
+        Ok(commands::SetLedEffect {
+            payload: response.0,
+        })
     }
 
     /// Method to retrieve the macros currently on the device.
@@ -299,45 +672,230 @@ impl Huntsman {
         Ok(response.0.count)
     }
 
-    /// Delete macro by its id.
-    pub fn macro_delete(&mut self, macro_id: u16) -> Result<(), Error> {
+    /// Retrieve the number of bytes allocated for the events of a macro. The register isn't
+    /// confirmed with a capture, so a response that doesn't echo the macro id or holds no events
+    /// is returned as [`Error::Decode`] instead of being trusted.
+    pub fn macro_size(&mut self, macro_id: u16) -> Result<usize, Error> {
+        let mut cmd: commands::GetMacroSize = Default::default();
+        cmd.0.macro_id = macro_id;
+        let result = self.set_command(&cmd)?;
+        let response = commands::Command::response(&cmd, &result.unwrap())?;
+        let response = response.downcast_ref::<commands::GetMacroSize>().unwrap();
+        if response.0.macro_id != macro_id || response.0.event_bytes == 0 {
+            return Err(Error::Decode(format!(
+                "Size read for macro 0x{:0>4x} looks wrong: {:?}",
+                macro_id, response.0
+            )));
+        }
+        Ok(response.0.event_bytes as usize)
+    }
+
+    /// Retrieve the total and free storage for macros. The response holds a second free value
+    /// that isn't understood, it is ignored, see [`commands::wire::GetStorageStatistics`].
+    pub fn storage_stats(&mut self) -> Result<StorageStats, Error> {
+        let cmd: commands::GetStorageStatistics = Default::default();
+        let result = self.set_command(&cmd)?;
+        let response = commands::Command::response(&cmd, &result.unwrap())?;
+        let response = response
+            .downcast_ref::<commands::GetStorageStatistics>()
+            .unwrap();
+        let total = response.0.total;
+        let free = response.0.free1;
+        Ok(StorageStats {
+            total,
+            free,
+            used: total.saturating_sub(free),
+        })
+    }
+
+    /// Retrieve the actions of a macro, reading the allocated size and then the events in chunks.
+    /// The events have to account for exactly the size that was read, otherwise this returns
+    /// [`Error::Decode`].
+    pub fn macro_get(
+        &mut self,
+        macro_id: u16,
+    ) -> Result<Vec<commands::macros::MacroAction>, Error> {
+        let size = self.macro_size(macro_id)?;
+        let mut bytes: Vec<u8> = Vec::new();
+        while bytes.len() < size {
+            let mut cmd: commands::GetMacroActionsPayload = Default::default();
+            cmd.0.macro_id = macro_id;
+            cmd.0.position = bytes.len() as u32;
+            let result = self.set_command(&cmd)?;
+            let response = commands::Command::response(&cmd, &result.unwrap())?;
+            let chunk = response
+                .downcast_ref::<commands::GetMacroActionsPayload>()
+                .unwrap();
+            let len = std::cmp::min(chunk.0.event_bytes_in_msg as usize, size - bytes.len());
+            if len == 0 || chunk.0.macro_id != macro_id {
+                return Err(Error::Decode(format!(
+                    "Macro 0x{:0>4x} returned no events at position {}.",
+                    macro_id,
+                    bytes.len()
+                )));
+            }
+            bytes.extend(&chunk.0.events[..len]);
+        }
+        let events = commands::macros::macro_bytes_to_events(&bytes)?;
+        if commands::macros::macro_events_to_size(&events) != size {
+            return Err(Error::Decode(format!(
+                "Events of macro 0x{:0>4x} don't match the {} bytes read.",
+                macro_id, size
+            )));
+        }
+        Ok(events)
+    }
+
+    /// Delete macro by its id. The device reports a failure for a macro that doesn't exist, that
+    /// is returned as [`CommandStatus::NotFound`] if the macro is indeed absent from the list.
+    pub fn macro_delete(&mut self, macro_id: u16) -> Result<CommandStatus, Error> {
         let mut cmd: commands::MacroDelete = Default::default();
         cmd.0.macro_id = macro_id;
-        let _result = self.set_command(&cmd)?;
-        Ok(())
+        let status = self.command_status(&cmd)?;
+        if status == CommandStatus::Failure && !self.macro_list()?.contains(&macro_id) {
+            return Ok(CommandStatus::NotFound);
+        }
+        Ok(status)
     }
 
     /// Create a macro of a certain size, not setting the payload.
-    pub fn macro_create(&mut self, macro_id: u16, size: usize) -> Result<(), Error> {
+    pub fn macro_create(&mut self, macro_id: u16, size: usize) -> Result<CommandStatus, Error> {
         let mut cmd: commands::MacroCreate = Default::default();
         cmd.0.macro_id = macro_id;
         cmd.0.event_bytes = size as u32;
-        let _result = self.set_command(&cmd)?;
+        self.command_status(&cmd)
+    }
+
+    /// Check whether the required bytes fit before touching anything, the space of the macro it
+    /// replaces becomes available. The size of that macro is read with an unconfirmed register, if
+    /// that read fails its space isn't counted, which only makes the check stricter.
+    ///
+    /// A report of zero total bytes isn't checked; the device always reports its capacity, zero is
+    /// what the dry run hal returns as it echoes the empty request, and there is nothing to check
+    /// against in that case.
+    fn check_macro_storage(&mut self, macro_id: u16, required: usize) -> Result<(), Error> {
+        let stats = self.storage_stats()?;
+        if stats.total != 0 {
+            let mut available = stats.free as usize;
+            if self.macro_list()?.contains(&macro_id) {
+                match self.macro_size(macro_id) {
+                    Ok(size) => available += size,
+                    Err(Error::Decode(_))
+                    | Err(Error::Rejected { .. })
+                    | Err(Error::Timeout { .. }) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            if required > available {
+                return Err(Error::InsufficientStorage {
+                    required: required as u32,
+                    available: available as u32,
+                });
+            }
+        }
         Ok(())
     }
 
-    /// Create macro of correct size for acctions, allocate & assign actions.
+    /// Create macro of correct size for acctions, allocate & assign actions. Returns
+    /// [`Error::InsufficientStorage`] without changing anything if the actions don't fit.
     pub fn macro_create_actions(
         &mut self,
         macro_id: u16,
         actions: &Vec<commands::macros::MacroAction>,
-    ) -> Result<(), Error> {
-        // First, delete the macro if it already exists.
-        let _ = self.macro_delete(macro_id); // ignore the result of this, it can fail if the id doesn't exist.
+    ) -> Result<CommandStatus, Error> {
+        let total_bytes = commands::macros::macro_events_to_size(actions);
+        self.check_macro_storage(macro_id, total_bytes)?;
+        self.macro_upload(macro_id, actions)
+    }
+
+    /// Delete, create and fill the macro, without checking the storage.
+    fn macro_upload(
+        &mut self,
+        macro_id: u16,
+        actions: &Vec<commands::macros::MacroAction>,
+    ) -> Result<CommandStatus, Error> {
         let total_bytes = commands::macros::macro_events_to_size(&actions);
 
+        // First, delete the macro if it already exists, not found is fine here.
+        let status = self.macro_delete(macro_id)?;
+        if !status.is_ok() && status != CommandStatus::NotFound {
+            return Ok(status);
+        }
+
         // Create the macro
-        self.macro_create(macro_id, total_bytes)?;
+        let status = self.macro_create(macro_id, total_bytes)?;
+        if !status.is_ok() {
+            return Ok(status);
+        }
 
         // Then, set the payloads
         let mut payloads = commands::macros::macro_events_to_payloads(macro_id, &actions);
         for payload in payloads.drain(..) {
             let mut cmd: commands::MacroActionsPayload = Default::default();
             cmd.0 = payload;
-            let _ = self.set_command(&cmd)?; // set the payload chunk
+            let status = self.command_status(&cmd)?; // set the payload chunk
+            if !status.is_ok() {
+                return Ok(status);
+            }
         }
         // should be it... :O
-        Ok(())
+        Ok(CommandStatus::Ok)
+    }
+
+    /// Create a macro like [`Self::macro_create_actions`], and write its metadata with the name,
+    /// like the vendor software does. The storage check includes the metadata, it isn't known
+    /// whether the metadata takes from the same storage.
+    pub fn macro_create_named(
+        &mut self,
+        macro_id: u16,
+        name: &str,
+        actions: &Vec<commands::macros::MacroAction>,
+    ) -> Result<CommandStatus, Error> {
+        let total_bytes =
+            commands::macros::macro_events_to_size(actions) + commands::macros::MACRO_METADATA_SIZE;
+        self.check_macro_storage(macro_id, total_bytes)?;
+        let status = self.macro_upload(macro_id, actions)?;
+        if !status.is_ok() {
+            return Ok(status);
+        }
+        let info = commands::macros::MacroInfo::new(name, macro_id, actions);
+        self.macro_set_metadata(macro_id, &info)
+    }
+
+    /// Write the metadata of a macro, the keyboard itself doesn't use this.
+    pub fn macro_set_metadata(
+        &mut self,
+        macro_id: u16,
+        info: &commands::macros::MacroInfo,
+    ) -> Result<CommandStatus, Error> {
+        for payload in commands::macros::macro_info_to_payloads(macro_id, info) {
+            let status = self.command_status(&commands::MacroMetadata(payload))?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        Ok(CommandStatus::Ok)
+    }
+
+    /// Retrieve the metadata of a macro, reading it in chunks.
+    pub fn macro_get_metadata(
+        &mut self,
+        macro_id: u16,
+    ) -> Result<commands::macros::MacroInfo, Error> {
+        let mut bytes: Vec<u8> = Vec::new();
+        while bytes.len() < commands::macros::MACRO_METADATA_SIZE {
+            let mut cmd: commands::GetMacroMetadata = Default::default();
+            cmd.0.macro_id = macro_id;
+            cmd.0.page_offset = bytes.len() as u16;
+            let result = self.set_command(&cmd)?;
+            let response = commands::Command::response(&cmd, &result.unwrap())?;
+            let chunk = response
+                .downcast_ref::<commands::GetMacroMetadata>()
+                .unwrap();
+            bytes.extend(&chunk.0.data);
+        }
+        bytes.truncate(commands::macros::MACRO_METADATA_SIZE);
+        Ok(commands::macros::MacroInfo::from_bytes(&bytes)?)
     }
 
     /// Method to retrieve the profiles currently on the device.
@@ -362,28 +920,26 @@ impl Huntsman {
     }
 
     /// Delete profile by its id, one cannot delete the currently active profile.
-    pub fn profile_delete(&mut self, profile_id: commands::ProfileId) -> Result<(), Error> {
-        if profile_id < 2 || profile_id > 5 {
-            // Not too sure what happens if we throw out 1...
-            panic!("Profile ids must be 2, 3, 4 or 5.");
-        }
+    pub fn profile_delete(
+        &mut self,
+        profile_id: commands::ProfileId,
+    ) -> Result<CommandStatus, Error> {
+        // Not too sure what happens if we throw out 1...
+        self.check_removable_profile(profile_id)?;
         let mut cmd: commands::ProfileDelete = Default::default();
         cmd.0.profile_id = profile_id;
-        let result = self.set_command(&cmd)?;
-        let response = commands::Command::response(&cmd, &result.unwrap())?;
-        let _response = response.downcast_ref::<commands::ProfileDelete>().unwrap();
-        Ok(())
+        self.command_status(&cmd)
     }
 
     /// Create a profile by the provided id..
-    pub fn profile_create(&mut self, profile_id: commands::ProfileId) -> Result<(), Error> {
-        if profile_id < 2 || profile_id > 5 {
-            panic!("Profile ids must be 2, 3, 4 or 5.");
-        }
+    pub fn profile_create(
+        &mut self,
+        profile_id: commands::ProfileId,
+    ) -> Result<CommandStatus, Error> {
+        self.check_removable_profile(profile_id)?;
         let mut cmd: commands::ProfileCreate = Default::default();
         cmd.0.profile_id = profile_id;
-        let _result = self.set_command(&cmd)?;
-        Ok(())
+        self.command_status(&cmd)
     }
 
     /// Retrieve the currently active profile.
@@ -397,10 +953,418 @@ impl Huntsman {
         Ok(response.0.profile_id)
     }
     /// Set the active profile to the id provided.
-    pub fn profile_set_current(&mut self, profile_id: commands::ProfileId) -> Result<(), Error> {
+    pub fn profile_set_current(
+        &mut self,
+        profile_id: commands::ProfileId,
+    ) -> Result<CommandStatus, Error> {
         let mut cmd: commands::SetProfileCurrent = Default::default();
         cmd.0.profile_id = profile_id;
-        let _result = self.set_command(&cmd)?;
-        Ok(())
+        self.command_status(&cmd)
+    }
+
+    /// Retrieve the metadata of a profile, reading it in chunks. The layout isn't confirmed with a
+    /// capture, see [`commands::profiles::ProfileMetadata`], so treat the result as a hint; a chunk
+    /// that doesn't echo the profile id and page is returned as [`Error::Decode`]. There is no
+    /// counterpart to write it, such that the metadata the vendor software reads is never
+    /// overwritten with a guessed layout.
+    pub fn profile_get_metadata(
+        &mut self,
+        profile_id: commands::ProfileId,
+    ) -> Result<commands::profiles::ProfileInfo, Error> {
+        let mut bytes: Vec<u8> = Vec::new();
+        while bytes.len() < commands::profiles::PROFILE_METADATA_SIZE {
+            let mut cmd: commands::GetProfileMetadata = Default::default();
+            cmd.0.profile_id = profile_id;
+            cmd.0.page = bytes.len() as u16;
+            let result = self.set_command(&cmd)?;
+            let response = commands::Command::response(&cmd, &result.unwrap())?;
+            let chunk = response
+                .downcast_ref::<commands::GetProfileMetadata>()
+                .unwrap();
+            let page = chunk.0.page;
+            if chunk.0.profile_id != profile_id || page as usize != bytes.len() {
+                return Err(Error::Decode(format!(
+                    "Profile {} returned the metadata of profile {} at page {}, expected page {}.",
+                    profile_id,
+                    chunk.0.profile_id,
+                    page,
+                    bytes.len()
+                )));
+            }
+            bytes.extend(&chunk.0.data);
+        }
+        bytes.truncate(commands::profiles::PROFILE_METADATA_SIZE);
+        Ok(commands::profiles::ProfileInfo::from_bytes(&bytes)?)
+    }
+
+    /// Read the complete configuration of the keyboard; all profiles with their mappings, effect
+    /// and brightness, and all macros. The macro names are only read from the macro metadata if
+    /// metadata is set, that read isn't confirmed against a capture.
+    pub fn snapshot(&mut self, metadata: bool) -> Result<snapshot::Snapshot, Error> {
+        let current_profile = self.profile_get_current()?;
+        let mut profiles = vec![];
+        for profile_id in self.profile_list()? {
+            let mappings = self.get_all_mappings(profile_id, Default::default(), &mut |_, _| {})?;
+            profiles.push(snapshot::ProfileSnapshot {
+                profile_id,
+                effect: self.get_effect(profile_id)?.payload,
+                brightness: self.get_brightness(profile_id)?,
+                mappings,
+            });
+        }
+        let mut macros = vec![];
+        for macro_id in self.macro_list()? {
+            // Macros that weren't created with a name may not have metadata.
+            let name = if !metadata {
+                None
+            } else {
+                match self.macro_get_metadata(macro_id) {
+                    Ok(info) if !info.name.is_empty() => Some(info.name),
+                    Ok(_) | Err(Error::Rejected { .. }) => None,
+                    Err(e) => return Err(e),
+                }
+            };
+            macros.push(configuration::MacroConfig {
+                name,
+                macro_id,
+                events: self.macro_get(macro_id)?,
+            });
+        }
+        Ok(snapshot::Snapshot {
+            version: snapshot::SNAPSHOT_VERSION,
+            model: self.model.name.to_string(),
+            current_profile,
+            profiles,
+            macros,
+        })
+    }
+
+    /// Make the keyboard match the snapshot. Profiles and macros that are not in the snapshot are
+    /// removed, the others are (re)created and their mappings, effect and brightness are set.
+    /// Stops at the first command that doesn't succeed and returns its status.
+    ///
+    /// Macros are deleted and uploaded again, so a snapshot holding a macro without events, which
+    /// means the read when it was taken went wrong, is refused before anything is changed. The
+    /// metadata of named macros is only written if metadata is set.
+    pub fn restore(
+        &mut self,
+        snapshot: &snapshot::Snapshot,
+        metadata: bool,
+    ) -> Result<CommandStatus, Error> {
+        if snapshot.model != self.model.name {
+            return Err(Error::InvalidArgument(format!(
+                "Snapshot was taken from a {}, this is a {}.",
+                snapshot.model, self.model.name
+            )));
+        }
+        if let Some(m) = snapshot.macros.iter().find(|m| m.events.is_empty()) {
+            return Err(Error::InvalidArgument(format!(
+                "Macro 0x{:0>4x} in the snapshot has no events, refusing to restore it.",
+                m.macro_id
+            )));
+        }
+
+        // Profiles first, the current profile can't be removed so switch before deleting.
+        let on_device = self.profile_list()?;
+        for profile in snapshot.profiles.iter() {
+            if !on_device.contains(&profile.profile_id) {
+                let status = self.profile_create(profile.profile_id)?;
+                if !status.is_ok() {
+                    return Ok(status);
+                }
+            }
+        }
+        let status = self.profile_set_current(snapshot.current_profile)?;
+        if !status.is_ok() {
+            return Ok(status);
+        }
+        for profile_id in on_device {
+            if snapshot.profile(profile_id).is_none() {
+                let status = self.profile_delete(profile_id)?;
+                if !status.is_ok() {
+                    return Ok(status);
+                }
+            }
+        }
+
+        // Then the macros, such that the mappings don't point to missing macros.
+        for macro_id in self.macro_list()? {
+            if !snapshot.macros.iter().any(|m| m.macro_id == macro_id) {
+                let status = self.macro_delete(macro_id)?;
+                if !status.is_ok() {
+                    return Ok(status);
+                }
+            }
+        }
+        for macro_config in snapshot.macros.iter() {
+            let status = match &macro_config.name {
+                Some(name) if metadata => {
+                    self.macro_create_named(macro_config.macro_id, name, &macro_config.events)?
+                }
+                _ => self.macro_create_actions(macro_config.macro_id, &macro_config.events)?,
+            };
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+
+        for profile in snapshot.profiles.iter() {
+            for m in profile.mappings.iter() {
+                let status = self.set_mapping(profile.profile_id, m.key, m.mapping)?;
+                if !status.is_ok() {
+                    return Ok(status);
+                }
+            }
+            let mut effect = commands::SetLedEffect {
+                payload: profile.effect,
+            };
+            effect.set_profile(profile.profile_id);
+            let status = self.command_status(&effect)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+            let status = self.set_brightness(profile.profile_id, profile.brightness)?;
+            if !status.is_ok() {
+                return Ok(status);
+            }
+        }
+        Ok(CommandStatus::Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use struct_helper::ToBytes;
+
+    /// Hal that returns the provided reports in order, repeating the last one.
+    struct ScriptedHal {
+        reports: Vec<Vec<u8>>,
+        reads: usize,
+    }
+
+    impl hid_hal::HidHal for ScriptedHal {
+        fn enumerate(
+            &mut self,
+            _vendor_id: u16,
+            _product_id: u16,
+            _endpoint_id: u32,
+        ) -> Result<Vec<DeviceInfo>, Error> {
+            Ok(vec![])
+        }
+        fn connect(
+            &mut self,
+            _vendor_id: u16,
+            _product_id: u16,
+            _endpoint_id: u32,
+            _selector: &DeviceSelector,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+        fn control(&mut self, _payload: &[u8]) -> Result<(), Error> {
+            Ok(())
+        }
+        fn get_report(&mut self) -> Result<Vec<u8>, Error> {
+            let index = std::cmp::min(self.reads, self.reports.len() - 1);
+            self.reads += 1;
+            Ok(self.reports[index].clone())
+        }
+    }
+
+    fn make_report(status: u8, cmd: commands::Cmd) -> Vec<u8> {
+        make_response(status, cmd, &[])
+    }
+
+    fn make_response(status: u8, cmd: commands::Cmd, payload: &[u8]) -> Vec<u8> {
+        let mut wire = commands::wire::Command {
+            status,
+            cmd,
+            len: payload.len() as u8,
+            ..Default::default()
+        };
+        wire.payload[..payload.len()].copy_from_slice(payload);
+        wire.update_checksum();
+        wire.to_le_bytes().expect("Should succeed")
+    }
+
+    fn make_huntsman(reports: Vec<Vec<u8>>) -> Huntsman {
+        let mut h = Huntsman::from_hal(
+            Box::new(ScriptedHal { reports, reads: 0 }),
+            &devices::HUNTSMAN_ELITE,
+        );
+        h.set_retry_policy(RetryPolicy {
+            poll_interval: std::time::Duration::from_millis(0),
+            max_attempts: 5,
+            deadline: std::time::Duration::from_secs(1),
+        });
+        h
+    }
+
+    #[test]
+    fn test_retry_busy_and_stale() {
+        let effect = commands::SetLedEffect::CMD;
+        let mut h = make_huntsman(vec![
+            make_report(0x02, commands::SetGameMode::CMD), // stale report from a previous command.
+            make_report(0x01, effect),                     // busy.
+            make_report(0x02, effect),
+        ]);
+        assert_eq!(h.effect_off().expect("no error"), CommandStatus::Ok);
+
+        let mut h = make_huntsman(vec![make_report(0x01, effect)]);
+        match h.effect_off() {
+            Err(Error::Timeout { cmd, attempts }) => {
+                assert_eq!(cmd, effect);
+                assert_eq!(attempts, 5);
+            }
+            z => panic!("Expected timeout, got {:?}", z),
+        }
+
+        // Reading two keys in a row, the first report is still the one of the previous key.
+        let key = |id| commands::mappings::KeyMap {
+            profile: 1,
+            key: commands::mappings::Key {
+                id,
+                hypershift: false,
+            },
+            mapping: commands::mappings::KeyMapping::Disabled,
+        };
+        let mut h = make_huntsman(vec![
+            make_response(
+                0x02,
+                commands::GetKeyMap::CMD,
+                &key(0x3b).to_be_bytes().unwrap(),
+            ),
+            make_response(
+                0x02,
+                commands::GetKeyMap::CMD,
+                &key(0x3c).to_be_bytes().unwrap(),
+            ),
+        ]);
+        let read = h.get_mapping(1, key(0x3c).key).expect("no error");
+        assert_eq!(read, key(0x3c));
+
+        // A stale report on the same register that never changes is a timeout.
+        let mut h = make_huntsman(vec![make_response(
+            0x02,
+            commands::GetKeyMap::CMD,
+            &key(0x3b).to_be_bytes().unwrap(),
+        )]);
+        assert!(matches!(
+            h.get_mapping(1, key(0x3c).key),
+            Err(Error::Timeout { .. })
+        ));
+    }
+
+    #[test]
+    fn test_macro_size_checked() {
+        // A size response for another macro is never taken as the response, one without any
+        // events is not trusted.
+        for (macro_id, event_bytes) in [(0x1337, 10), (0x0001, 10), (0x1337, 0)].iter() {
+            let mut payload = u16::to_be_bytes(*macro_id).to_vec();
+            payload.extend(&u32::to_be_bytes(*event_bytes));
+            let mut h = make_huntsman(vec![make_response(
+                0x02,
+                commands::GetMacroSize::CMD,
+                &payload,
+            )]);
+            match h.macro_size(0x1337) {
+                Ok(size) => assert_eq!((*macro_id, size), (0x1337, 10)),
+                Err(Error::Timeout { .. }) => assert_eq!(*macro_id, 0x0001),
+                Err(e) => assert!(matches!(e, Error::Decode(_))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_profile_metadata_checked() {
+        // A chunk of another profile, or of another page, is not trusted.
+        for payload in [[0x02, 0x00, 0x00], [0x01, 0x00, 0x4d]].iter() {
+            let mut h = make_huntsman(vec![make_response(
+                0x02,
+                commands::GetProfileMetadata::CMD,
+                payload,
+            )]);
+            assert!(matches!(h.profile_get_metadata(1), Err(Error::Decode(_))));
+        }
+    }
+
+    #[test]
+    fn test_macro_delete_not_found() {
+        // The device fails the delete, only a macro that isn't listed counts as not found.
+        for (listed, expected) in [(1, CommandStatus::Failure), (0, CommandStatus::NotFound)].iter()
+        {
+            let mut list: commands::macros::MacroList = Default::default();
+            list.length = *listed;
+            list.macro_ids[0] = 0x1337;
+            let payload = list.to_be_bytes().expect("Should succeed");
+            let mut h = make_huntsman(vec![
+                make_response(0x03, commands::MacroDelete::CMD, &[0x13, 0x37]),
+                make_response(0x02, commands::GetActiveMacros::CMD, &payload),
+            ]);
+            assert_eq!(h.macro_delete(0x1337).expect("no error"), *expected);
+        }
+    }
+
+    #[test]
+    fn test_record_replay() {
+        let path =
+            std::env::temp_dir().join(format!("huntsman_replay_{}.yaml", std::process::id()));
+        let path = path.to_str().expect("valid path");
+
+        // Record listing the macros against a device that holds 0x1337 and 0x0001.
+        let mut response = commands::wire::Command {
+            status: 0x02,
+            len: 0x42,
+            cmd: commands::GetActiveMacros::CMD,
+            ..Default::default()
+        };
+        response.payload[..6].copy_from_slice(&[0x00, 0x02, 0x13, 0x37, 0x00, 0x01]);
+        response.update_checksum();
+        let reports = vec![response.to_le_bytes().expect("Should succeed")];
+        {
+            let hal =
+                hid_hal::RecordingHidHal::new(Box::new(ScriptedHal { reports, reads: 0 }), path)
+                    .expect("can create file");
+            let mut h = Huntsman::from_hal(Box::new(hal), &devices::HUNTSMAN_ELITE);
+            assert_eq!(h.macro_list().expect("success"), vec![0x1337, 0x0001]);
+            assert_eq!(h.macro_list().expect("success"), vec![0x1337, 0x0001]);
+        }
+        // Each exchange was appended to the file, the last one when the hal was dropped.
+        let recorded: serde_yaml::Value =
+            serde_yaml::from_reader(std::fs::File::open(path).expect("exists")).expect("yaml");
+        assert_eq!(recorded.as_sequence().expect("sequence").len(), 2);
+
+        let mut h = Huntsman::replay_new(path).expect("can load recording");
+        assert_eq!(h.macro_list().expect("success"), vec![0x1337, 0x0001]);
+        // Served again, as the last recorded response for this request.
+        assert_eq!(h.macro_list().expect("success"), vec![0x1337, 0x0001]);
+        // Other requests were never recorded.
+        assert!(matches!(h.macro_count(), Err(Error::Transport(_))));
+        std::fs::remove_file(path).expect("can remove");
+    }
+
+    #[test]
+    fn test_device_selector() {
+        let info = DeviceInfo {
+            path: "/dev/hidraw3".to_string(),
+            serial: Some("PM1234".to_string()),
+            vendor_id: 0x1532,
+            product_id: 0x226,
+            interface: 2,
+        };
+        let path: DeviceSelector = "/dev/hidraw3".parse().expect("valid");
+        assert_eq!(path, DeviceSelector::Path("/dev/hidraw3".to_string()));
+        assert!(path.matches(&info));
+        let serial: DeviceSelector = "PM1234".parse().expect("valid");
+        assert_eq!(serial, DeviceSelector::Serial("PM1234".to_string()));
+        assert!(serial.matches(&info));
+        let prefixed: DeviceSelector = "path:1-2:1.2".parse().expect("valid");
+        assert_eq!(prefixed, DeviceSelector::Path("1-2:1.2".to_string()));
+        assert!(!prefixed.matches(&info));
+        let other: DeviceSelector = "serial:/odd".parse().expect("valid");
+        assert_eq!(other, DeviceSelector::Serial("/odd".to_string()));
+        assert!(!other.matches(&info));
+        assert!(DeviceSelector::First.matches(&info));
+        assert!("".parse::<DeviceSelector>().is_err());
     }
 }
@@ -0,0 +1,170 @@
+//! Keeps track of which id each named macro was given, such that mappings can refer to macros by
+//! their name instead of by a hand picked id. The table is stored in a yaml file, by default in
+//! the user's configuration directory, see [`MacroNames::default_path`].
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::MacroId;
+use crate::Error;
+
+/// The id a macro name prefers, a 16 bit FNV-1a hash of the name such that the id stays the same
+/// between runs, as long as it doesn't collide. Zero is never returned.
+pub fn preferred_macro_id(name: &str) -> MacroId {
+    let mut hash: u32 = 0x811c9dc5;
+    for b in name.as_bytes() {
+        hash ^= *b as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    let id = ((hash >> 16) ^ (hash & 0xffff)) as MacroId;
+    std::cmp::max(id, 1)
+}
+
+/// Allocate ids for the macro names, in order, like [`MacroNames::allocate`] does for names that
+/// are not known yet; none of the ids is in taken.
+pub fn allocate_macro_ids<'a>(
+    names: impl Iterator<Item = &'a String>,
+    taken: &[MacroId],
+) -> BTreeMap<String, MacroId> {
+    let mut scratch: MacroNames = Default::default();
+    for name in names {
+        scratch.allocate(name, taken);
+    }
+    scratch.names
+}
+
+/// Table of macro names to their ids.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct MacroNames {
+    names: BTreeMap<String, MacroId>,
+}
+
+impl MacroNames {
+    /// `$XDG_CONFIG_HOME/huntsman/macro_names.yaml`, or `~/.config/huntsman/macro_names.yaml` if
+    /// that isn't set. None if neither environment variable is set.
+    pub fn default_path() -> Option<PathBuf> {
+        let config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+        Some(config_dir.join("huntsman").join("macro_names.yaml"))
+    }
+
+    /// Load the table from a file, a file that doesn't exist yet results in an empty table.
+    pub fn load(path: &Path) -> Result<MacroNames, Error> {
+        let filename = path.to_string_lossy();
+        let file = match std::fs::File::open(path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Default::default()),
+            Err(e) => return Err(e.into()),
+        };
+        serde_yaml::from_reader(file).map_err(|e| Error::config_yaml(&filename, e))
+    }
+
+    /// Write the table to a file, creating the directory if necessary.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let file = std::fs::File::create(path)?;
+        serde_yaml::to_writer(file, self)
+            .map_err(|e| Error::config_yaml(&path.to_string_lossy(), e))
+    }
+
+    /// The id of the macro by this name.
+    pub fn get(&self, name: &str) -> Option<MacroId> {
+        self.names.get(name).copied()
+    }
+
+    /// The name of the macro with this id.
+    pub fn name(&self, macro_id: MacroId) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|(_, v)| **v == macro_id)
+            .map(|(k, _)| k.as_str())
+    }
+
+    /// Record the id of a macro, a previous name for that id is dropped.
+    pub fn insert(&mut self, name: &str, macro_id: MacroId) {
+        self.remove(macro_id);
+        self.names.insert(name.to_string(), macro_id);
+    }
+
+    /// Forget the name of the macro with this id.
+    pub fn remove(&mut self, macro_id: MacroId) {
+        self.names.retain(|_, v| *v != macro_id);
+    }
+
+    /// Returns the id for this name, if the name is new an id is allocated that is neither on the
+    /// device nor used by another name, and recorded. The name gets its preferred id, see
+    /// [`preferred_macro_id`], unless that is taken, in which case the next free id is used.
+    pub fn allocate(&mut self, name: &str, on_device: &[MacroId]) -> MacroId {
+        if let Some(macro_id) = self.get(name) {
+            return macro_id;
+        }
+        let mut macro_id = preferred_macro_id(name);
+        while on_device.contains(&macro_id) || self.name(macro_id).is_some() {
+            macro_id = std::cmp::max(macro_id.wrapping_add(1), 1);
+        }
+        self.insert(name, macro_id);
+        macro_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_macro_ids() {
+        assert_eq!(
+            preferred_macro_id("paste_sig"),
+            preferred_macro_id("paste_sig")
+        );
+        assert_ne!(
+            preferred_macro_id("paste_sig"),
+            preferred_macro_id("paste_sih")
+        );
+        let names = ["a".to_string(), "b".to_string()];
+        let taken = [preferred_macro_id("a")];
+        let ids = allocate_macro_ids(names.iter(), &taken);
+        assert_ne!(ids["a"], preferred_macro_id("a"));
+        assert_eq!(ids["b"], preferred_macro_id("b"));
+
+        // The table hands out the same ids.
+        let mut table: MacroNames = Default::default();
+        assert_eq!(table.allocate("a", &taken), ids["a"]);
+        assert_eq!(table.allocate("b", &taken), ids["b"]);
+    }
+
+    #[test]
+    fn test_macro_names() {
+        let mut names: MacroNames = Default::default();
+        let paste_sig = names.allocate("paste_sig", &[]);
+        assert_eq!(paste_sig, preferred_macro_id("paste_sig"));
+        assert_eq!(names.allocate("paste_sig", &[paste_sig]), paste_sig);
+        assert_eq!(names.name(paste_sig), Some("paste_sig"));
+
+        // The preferred id is on the device already, under another name.
+        let hello = names.allocate("hello", &[preferred_macro_id("hello")]);
+        assert_ne!(hello, preferred_macro_id("hello"));
+        assert_eq!(names.get("hello"), Some(hello));
+
+        let path = std::env::temp_dir()
+            .join(format!("huntsman_test_names_{}", std::process::id()))
+            .join("macro_names.yaml");
+        assert_eq!(
+            MacroNames::load(&path).expect("success"),
+            Default::default()
+        );
+        names.save(&path).expect("success");
+        assert_eq!(MacroNames::load(&path).expect("success"), names);
+        std::fs::remove_dir_all(path.parent().unwrap()).expect("success");
+
+        names.remove(paste_sig);
+        assert_eq!(names.get("paste_sig"), None);
+    }
+}
@@ -22,6 +22,14 @@ macro_rules! add_colors {
 
 type Error = Box<dyn std::error::Error>;
 
+/// Turn a status other than ok into an error, such that the command line utility fails.
+fn check_status(status: huntsman::CommandStatus) -> Result<(), Error> {
+    if status.is_ok() {
+        return Ok(());
+    }
+    Err(format!("Device reported: {}", status).into())
+}
+
 fn get_colors(matches: &clap::ArgMatches) -> Vec<commands::RGB> {
     let mut res: Vec<commands::RGB> = Vec::new();
     if let Some(z) = matches.values_of("colors") {
@@ -112,6 +120,16 @@ fn get_numeric_u64(matches: &clap::ArgMatches, name: &str) -> Result<u64, String
     Err(format!("Couldn't parse argument {}.", name))
 }
 
+/// The file holding the ids of named macros, see [`huntsman::macro_names::MacroNames`].
+fn get_macro_names_path(matches: &clap::ArgMatches) -> Result<std::path::PathBuf, String> {
+    if let Some(v_in) = matches.value_of("macro_names") {
+        return Ok(std::path::PathBuf::from(v_in));
+    }
+    huntsman::macro_names::MacroNames::default_path().ok_or_else(|| {
+        "Couldn't determine where to store macro names, use --macro-names.".to_string()
+    })
+}
+
 fn get_profile_id(matches: &clap::ArgMatches) -> Result<commands::ProfileId, String> {
     if let Some(v_in) = matches.value_of("profile_id") {
         return Ok(profile_util::str_to_profile_id(&v_in.to_string()));
@@ -137,6 +155,36 @@ pub fn main() -> Result<(), Error> {
                 .short("d")
                 .help("Dry run, don't actually connect to the device, echo all commands."),
         )
+        .arg(
+            Arg::with_name("device")
+                .long("device")
+                .takes_value(true)
+                .global(true)
+                .help("The device to use if multiple are connected, by hid path or serial number (path:.. or serial:.. to be explicit)."),
+        )
+        .arg(
+            Arg::with_name("record")
+                .long("record")
+                .takes_value(true)
+                .global(true)
+                .help("Record all communication with the device to this file."),
+        )
+        .arg(
+            Arg::with_name("replay")
+                .long("replay")
+                .takes_value(true)
+                .global(true)
+                .conflicts_with_all(&["d", "record", "device"])
+                .help("Don't connect to the device, serve the responses from this recording."),
+        )
+        .arg(
+            Arg::with_name("macro_names")
+                .long("macro-names")
+                .takes_value(true)
+                .global(true)
+                .help("File that holds the ids given to named macros, defaults to ~/.config/huntsman/macro_names.yaml."),
+        )
+        .subcommand(SubCommand::with_name("devices").about("Lists the connected keyboards."))
         .subcommand(
             SubCommand::with_name("brightness")
                 .about("Sets the brightness")
@@ -164,7 +212,54 @@ pub fn main() -> Result<(), Error> {
                         .help("The state to set it to."),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("backup")
+                .about("Write all profiles, mappings, effects and macros to a file (.yaml or .json).")
+                .arg(
+                    Arg::with_name("file")
+                        .takes_value(true)
+                        .required(true)
+                        .help("The filename to write the snapshot to."),
+                )
+                .arg(
+                    Arg::with_name("metadata")
+                        .long("metadata")
+                        .help("Read the macro names from the macro metadata on the device, this read isn't confirmed against a capture."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("restore")
+                .about("Make the device match a snapshot written by backup, removes profiles and macros not in it.")
+                .arg(
+                    Arg::with_name("file")
+                        .takes_value(true)
+                        .required(true)
+                        .help("The filename to read the snapshot from."),
+                )
+                .arg(
+                    Arg::with_name("metadata")
+                        .long("metadata")
+                        .help("Write the metadata with the name of named macros, like the vendor software does."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("apply")
+                .about("Make the device match a keyboard configuration file, removes profiles and macros not in it.")
+                .arg(
+                    Arg::with_name("file")
+                        .takes_value(true)
+                        .required(true)
+                        .help("The keyboard configuration to apply (.yaml)."),
+                )
+                .arg(
+                    Arg::with_name("plan")
+                        .long("plan")
+                        .takes_value(false)
+                        .help("Only print the changes, don't write them."),
+                ),
+        )
         .subcommand(SubCommand::with_name("serial_number").about("Retrieves the serial number"))
+        .subcommand(SubCommand::with_name("storage").about("Shows the storage used by macros."))
         .subcommand(SubCommand::with_name("dev_run").about("Runs dev_run"))
         .subcommand(add_colors!(SubCommand::with_name("set_color")
             .about("Sets colors in the custom frame.")
@@ -226,7 +321,13 @@ pub fn main() -> Result<(), Error> {
         .subcommand(
             SubCommand::with_name("macro").setting(clap::AppSettings::SubcommandRequiredElseHelp)
                 .about("Configuration for macros")
-                .subcommand(SubCommand::with_name("list").about("List macros on the device"))
+                .subcommand(
+                    SubCommand::with_name("list").about("List macros on the device").arg(
+                        Arg::with_name("metadata")
+                            .long("metadata")
+                            .help("Read the names from the macro metadata on the device, this read isn't confirmed against a capture."),
+                    ),
+                )
                 .subcommand(
                     SubCommand::with_name("count").about("Show the nr of macros on the device."),
                 )
@@ -248,6 +349,37 @@ pub fn main() -> Result<(), Error> {
                                 .takes_value(true)
                                 .required(true)
                                 .help("The filename to read the macro from."),
+                        )
+                        .arg(
+                            Arg::with_name("apply_changes_only")
+                                .long("apply-changes-only")
+                                .help("Don't upload the macro if the device already holds it."),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("dump")
+                        .about("Read a macro from the device, in the format macro load accepts")
+                        .arg(
+                            Arg::with_name("macro_id")
+                                .takes_value(true)
+                                .required(true)
+                                .help("The macro_id to read."),
+                        )
+                        .arg(
+                            Arg::with_name("file")
+                                .takes_value(true)
+                                .help("The filename to write the macro to, prints it if omitted."),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("diff")
+                        .about("Show which macros differ between the files and the device")
+                        .arg(
+                            Arg::with_name("files")
+                                .takes_value(true)
+                                .multiple(true)
+                                .required(true)
+                                .help("The filenames to read the macros from."),
                         ),
                 ),
         )
@@ -256,7 +388,13 @@ pub fn main() -> Result<(), Error> {
                 .about(
                     "Configuration for profiles, profiles 2/red, 3/green, 4/blue and 5/cyan can be removed / created.",
                 )
-                .subcommand(SubCommand::with_name("list").about("List profiles on the device"))
+                .subcommand(
+                    SubCommand::with_name("list").about("List profiles on the device").arg(
+                        Arg::with_name("metadata")
+                            .long("metadata")
+                            .help("Show the name and uuid from the profile metadata, its layout isn't confirmed against a capture."),
+                    ),
+                )
                 .subcommand(SubCommand::with_name("current").about("Show the currently active profile."))
                 .subcommand(
                     SubCommand::with_name("count").about("Show the nr of profiles on the device."),
@@ -290,6 +428,22 @@ pub fn main() -> Result<(), Error> {
                                 .required(true)
                                 .help("The profile_id to set the keyboard to 1, 2, 3, 4 or 5 (or by color)."),
                         ),
+                )
+                .subcommand(
+                    SubCommand::with_name("reset")
+                        .about("Restore the default mappings, effect and brightness of a profile")
+                        .arg(
+                            Arg::with_name("profile_id")
+                                .takes_value(true)
+                                .required(true)
+                                .help("The profile to reset."),
+                        )
+                        .arg(
+                            Arg::with_name("changed")
+                                .long("changed")
+                                .takes_value(false)
+                                .help("Only write the keys that differ from their default."),
+                        ),
                 ),
         ).subcommand(
             SubCommand::with_name("mapping").setting(clap::AppSettings::SubcommandRequiredElseHelp)
@@ -304,6 +458,27 @@ pub fn main() -> Result<(), Error> {
                             .required(true)
                             .help("The filename to read the mappings from."),
                     )
+                    .arg(
+                        Arg::with_name("profile")
+                            .takes_value(true)
+                            .required(true)
+                            .help("The profile to use."),
+                    )
+                    .arg(
+                        Arg::with_name("apply_changes_only")
+                            .long("apply-changes-only")
+                            .help("Only set the mappings that differ from the device."),
+                    ),
+                )
+                .subcommand(
+                SubCommand::with_name("diff")
+                    .about("Show which mappings loading the file would change")
+                    .arg(
+                        Arg::with_name("file")
+                            .takes_value(true)
+                            .required(true)
+                            .help("The filename to read the mappings from."),
+                    )
                     .arg(
                         Arg::with_name("profile")
                             .takes_value(true)
@@ -381,15 +556,44 @@ some_other_key_identifier - use this key's default value.
         }
     }
 
+    if let Some(_matches) = matches.subcommand_matches("devices") {
+        let devices = huntsman::Huntsman::enumerate()?;
+        if !devices.is_empty() {
+            println!("Connected keyboards:");
+            for d in devices.iter() {
+                let model = huntsman::devices::find_model(d.product_id)
+                    .map(|m| m.name)
+                    .unwrap_or("unknown model");
+                println!(
+                    "   - {} {} (serial: {})",
+                    model,
+                    d.path,
+                    d.serial.as_deref().unwrap_or("unknown")
+                );
+            }
+        } else {
+            println!("No keyboards found.");
+        }
+        return Ok(());
+    }
+
     let dry_run = matches.occurrences_of("d") == 1;
+    let selector = match matches.value_of("device") {
+        Some(v) => v.parse::<huntsman::DeviceSelector>()?,
+        None => huntsman::DeviceSelector::First,
+    };
 
     let mut h: huntsman::Huntsman;
     if dry_run {
         h = huntsman::Huntsman::dry_new()?;
         // h = huntsman::Huntsman::new()?;
+    } else if let Some(recording) = matches.value_of("replay") {
+        h = huntsman::Huntsman::replay_new(recording)?;
+    } else if let Some(recording) = matches.value_of("record") {
+        h = huntsman::Huntsman::open_recording(&selector, recording)?;
     } else {
         // We have a subcommand, try to make the Huntsman object.
-        h = huntsman::Huntsman::new()?;
+        h = huntsman::Huntsman::open(&selector)?;
     }
 
     // Set the print communication flag.
@@ -399,68 +603,115 @@ some_other_key_identifier - use this key's default value.
     // Next, follows the invidual subcommand handling.
 
     if let Some(_matches) = matches.subcommand_matches("dev_run") {
-        h.dev_run()?;
+        check_status(h.dev_run()?)?;
     }
 
     if let Some(_matches) = matches.subcommand_matches("serial_number") {
         h.get_serial_number()?;
     }
 
+    if let Some(_matches) = matches.subcommand_matches("storage") {
+        let stats = h.storage_stats()?;
+        println!(
+            "Storage: {} bytes used, {} bytes free, {} bytes total.",
+            stats.used, stats.free, stats.total
+        );
+    }
+
+    if let Some(matches) = matches.subcommand_matches("backup") {
+        let file = get_value::<String>(matches, "file")?;
+        let snapshot = h.snapshot(matches.is_present("metadata"))?;
+        snapshot.save(&file)?;
+        println!(
+            "Wrote {} profile(s) and {} macro(s) to {}.",
+            snapshot.profiles.len(),
+            snapshot.macros.len(),
+            file
+        );
+    }
+
+    if let Some(matches) = matches.subcommand_matches("restore") {
+        let file = get_value::<String>(matches, "file")?;
+        let snapshot = huntsman::snapshot::Snapshot::load(&file)?;
+        check_status(h.restore(&snapshot, matches.is_present("metadata"))?)?;
+        println!(
+            "Restored {} profile(s) and {} macro(s) from {}.",
+            snapshot.profiles.len(),
+            snapshot.macros.len(),
+            file
+        );
+    }
+
+    if let Some(matches) = matches.subcommand_matches("apply") {
+        let file = get_value::<String>(matches, "file")?;
+        let config = huntsman::configuration::load_keyboard(&file)?;
+        let path = get_macro_names_path(matches)?;
+        let mut names = huntsman::macro_names::MacroNames::load(&path)?;
+        let plan = huntsman::apply::Plan::new(&mut h, &config, &names)?;
+        print!("{}", plan);
+        if !matches.is_present("plan") {
+            check_status(plan.apply(&mut h)?)?;
+            plan.update_names(&mut names);
+            names.save(&path)?;
+            println!("Applied {}.", file);
+        }
+    }
+
     if let Some(matches) = matches.subcommand_matches("brightness") {
         let value = get_value::<f32>(matches, "value")?;
         let profile = get_value::<u8>(matches, "profile")?;
-        h.set_brightness(profile, value)?;
+        check_status(h.set_brightness(profile, value)?)?;
     }
 
     if let Some(matches) = matches.subcommand_matches("game_mode") {
         let value = get_value::<bool>(matches, "value")?;
-        h.set_game_mode(value)?;
+        check_status(h.set_game_mode(value)?)?;
     }
 
     if let Some(matches) = matches.subcommand_matches("effect") {
         match matches.subcommand_name() {
             Some("off") => {
-                h.effect_off()?;
+                check_status(h.effect_off()?)?;
             }
             Some("fixed") => {
                 let subargs = matches.subcommand_matches("fixed").unwrap();
                 let colors = get_colors(subargs);
-                h.effect_fixed(&colors[0])?;
+                check_status(h.effect_fixed(&colors[0])?)?;
             }
             Some("breathing") => {
                 let subargs = matches.subcommand_matches("breathing").unwrap();
                 let colors = get_colors(subargs);
-                h.effect_breathing(&colors)?;
+                check_status(h.effect_breathing(&colors)?)?;
             }
             Some("spectrum") => {
-                h.effect_spectrum()?;
+                check_status(h.effect_spectrum()?)?;
             }
             Some("wave") => {
                 let subargs = matches.subcommand_matches("wave").unwrap();
                 let delay = get_value::<u8>(subargs, "delay")?;
                 let reverse: bool = subargs.occurrences_of("reverse") == 0;
                 println!("Reverse: {}", reverse);
-                h.effect_wave(reverse, delay)?;
+                check_status(h.effect_wave(reverse, delay)?)?;
             }
             Some("reactive") => {
                 let subargs = matches.subcommand_matches("reactive").unwrap();
                 let colors = get_colors(subargs);
                 let duration = get_duration(subargs)?;
-                h.effect_reactive(duration, &colors)?;
+                check_status(h.effect_reactive(duration, &colors)?)?;
             }
             Some("ripple") => {
                 let subargs = matches.subcommand_matches("ripple").unwrap();
                 let colors = get_colors(subargs);
-                h.effect_ripple(&colors)?;
+                check_status(h.effect_ripple(&colors)?)?;
             }
             Some("starlight") => {
                 let subargs = matches.subcommand_matches("starlight").unwrap();
                 let colors = get_colors(subargs);
                 let duration = get_duration(subargs)?;
-                h.effect_starlight(duration, &colors)?;
+                check_status(h.effect_starlight(duration, &colors)?)?;
             }
             Some("custom") => {
-                h.effect_custom()?;
+                check_status(h.effect_custom()?)?;
             }
             None => println!("No subcommand was used"),
             _ => println!("Some other subcommand was used"),
@@ -470,11 +721,28 @@ some_other_key_identifier - use this key's default value.
     if let Some(matches) = matches.subcommand_matches("macro") {
         match matches.subcommand_name() {
             Some("list") => {
+                let submatches = matches.subcommand_matches("list").unwrap();
                 let ids = h.macro_list()?;
+                let names =
+                    huntsman::macro_names::MacroNames::load(&get_macro_names_path(matches)?)?;
                 if ids.len() != 0 {
                     println!("Macro's in memory:");
                     for id in ids.iter() {
-                        println!("   - 0x{:0>4x}", id);
+                        // If asked, prefer the name in the metadata, not all macros have metadata.
+                        let metadata = if submatches.is_present("metadata") {
+                            match h.macro_get_metadata(*id) {
+                                Ok(info) if !info.name.is_empty() => Some(info.name),
+                                Ok(_) | Err(huntsman::Error::Rejected { .. }) => None,
+                                Err(e) => return Err(e.into()),
+                            }
+                        } else {
+                            None
+                        };
+                        let name = metadata.or_else(|| names.name(*id).map(|n| n.to_string()));
+                        match name {
+                            Some(name) => println!("   - 0x{:0>4x} ({})", id, name),
+                            None => println!("   - 0x{:0>4x}", id),
+                        }
                     }
                 } else {
                     println!("No macro's in memory.");
@@ -487,12 +755,96 @@ some_other_key_identifier - use this key's default value.
             Some("load") => {
                 let submatches = matches.subcommand_matches("load").unwrap();
                 let file = get_value::<String>(submatches, "file")?;
-                let macro_config =
-                    huntsman::configuration::load_macro(&file).map_err(|x| format!("{:?}", x))?;
+                let mut macro_config = huntsman::configuration::load_macro(&file)?;
+                let path = get_macro_names_path(submatches)?;
+                let mut names = huntsman::macro_names::MacroNames::load(&path)?;
+                if let Some(name) = &macro_config.name {
+                    if macro_config.macro_id == 0 {
+                        macro_config.macro_id = names.allocate(name, &h.macro_list()?);
+                    } else {
+                        names.insert(name, macro_config.macro_id);
+                    }
+                    println!("Macro {} uses id 0x{:0>4x}.", name, macro_config.macro_id);
+                }
+                if submatches.is_present("apply_changes_only")
+                    && huntsman::diff::diff_macros(&mut h, std::slice::from_ref(&macro_config))?
+                        .is_empty()
+                {
+                    println!(
+                        "Macro 0x{:0>4x} is already on the device.",
+                        macro_config.macro_id
+                    );
+                    if macro_config.name.is_some() {
+                        names.save(&path)?;
+                    }
+                    return Ok(());
+                }
                 println!("Loading macro:\n{:?}", macro_config);
-                h.macro_create_actions(macro_config.macro_id, &macro_config.events)?;
+                let status = match &macro_config.name {
+                    Some(name) => {
+                        h.macro_create_named(macro_config.macro_id, name, &macro_config.events)?
+                    }
+                    None => h.macro_create_actions(macro_config.macro_id, &macro_config.events)?,
+                };
+                check_status(status)?;
+                // Only record the name once the macro is on the device.
+                if macro_config.name.is_some() {
+                    names.save(&path)?;
+                }
                 println!("Macro 0x{:0>4x} succesfully loaded.", macro_config.macro_id);
             }
+            Some("dump") => {
+                let submatches = matches.subcommand_matches("dump").unwrap();
+                let macro_id = get_numeric_u64(submatches, "macro_id")? as u16;
+                if !h.macro_list()?.contains(&macro_id) {
+                    return Err(format!(
+                        "The device doesn't report macro 0x{:0>4x} is present.",
+                        macro_id
+                    )
+                    .into());
+                }
+                let names =
+                    huntsman::macro_names::MacroNames::load(&get_macro_names_path(submatches)?)?;
+                let macro_config = huntsman::configuration::MacroConfig {
+                    name: names.name(macro_id).map(|n| n.to_string()),
+                    macro_id,
+                    events: h.macro_get(macro_id)?,
+                };
+                let yaml = serde_yaml::to_string(&macro_config)?;
+                match submatches.value_of("file") {
+                    Some(file) => {
+                        std::fs::write(file, yaml)?;
+                        println!(
+                            "Wrote macro 0x{:0>4x} with {} actions to {}.",
+                            macro_id,
+                            macro_config.events.len(),
+                            file
+                        );
+                    }
+                    None => print!("{}", yaml),
+                }
+            }
+            Some("diff") => {
+                let submatches = matches.subcommand_matches("diff").unwrap();
+                let mut names =
+                    huntsman::macro_names::MacroNames::load(&get_macro_names_path(submatches)?)?;
+                let on_device = h.macro_list()?;
+                let mut macros = vec![];
+                for file in submatches.values_of("files").unwrap() {
+                    let mut macro_config = huntsman::configuration::load_macro(file)?;
+                    if let (Some(name), 0) = (&macro_config.name, macro_config.macro_id) {
+                        macro_config.macro_id = names.allocate(name, &on_device);
+                    }
+                    macros.push(macro_config);
+                }
+                let changes = huntsman::diff::diff_macros(&mut h, &macros)?;
+                for change in changes.iter() {
+                    println!("{}", change);
+                }
+                if changes.is_empty() {
+                    println!("No changes.");
+                }
+            }
             Some("del") => {
                 let submatches = matches.subcommand_matches("del").unwrap();
                 let macro_id = get_numeric_u64(submatches, "macro_id")? as u16;
@@ -500,7 +852,13 @@ some_other_key_identifier - use this key's default value.
                 for id in ids.iter() {
                     if *id == macro_id {
                         // found the thing, remove it.
-                        h.macro_delete(macro_id)?;
+                        check_status(h.macro_delete(macro_id)?)?;
+                        let path = get_macro_names_path(submatches)?;
+                        let mut names = huntsman::macro_names::MacroNames::load(&path)?;
+                        if names.name(macro_id).is_some() {
+                            names.remove(macro_id);
+                            names.save(&path)?;
+                        }
                         println!("Macro 0x{:0>4x} removed.", macro_id);
                         return Ok(());
                     }
@@ -518,15 +876,35 @@ some_other_key_identifier - use this key's default value.
     if let Some(matches) = matches.subcommand_matches("profile") {
         match matches.subcommand_name() {
             Some("list") => {
+                let submatches = matches.subcommand_matches("list").unwrap();
                 let ids = h.profile_list()?;
                 if ids.len() != 0 {
                     println!("Profiles's in memory:");
                     for id in ids.iter() {
-                        println!(
-                            "   - {} ({})",
-                            id,
-                            profile_util::profile_to_colored_name(*id)
-                        );
+                        // Profiles that were never named may not have metadata.
+                        let info = if submatches.is_present("metadata") {
+                            match h.profile_get_metadata(*id) {
+                                Ok(info) if !info.name.is_empty() => Some(info),
+                                Ok(_) | Err(huntsman::Error::Rejected { .. }) => None,
+                                Err(e) => return Err(e.into()),
+                            }
+                        } else {
+                            None
+                        };
+                        match info {
+                            Some(info) => println!(
+                                "   - {} ({}) {} {}",
+                                id,
+                                profile_util::profile_to_colored_name(*id),
+                                info.name,
+                                info.uuid
+                            ),
+                            None => println!(
+                                "   - {} ({})",
+                                id,
+                                profile_util::profile_to_colored_name(*id)
+                            ),
+                        }
                     }
                 } else {
                     println!("No macro's in memory.");
@@ -547,7 +925,7 @@ some_other_key_identifier - use this key's default value.
             Some("activate") => {
                 let submatches = matches.subcommand_matches("activate").unwrap();
                 let profile_id = get_profile_id(submatches)?;
-                let _ = h.profile_set_current(profile_id)?;
+                check_status(h.profile_set_current(profile_id)?)?;
                 let id = h.profile_get_current()?;
                 println!(
                     "Current profile: {} ({})",
@@ -555,10 +933,21 @@ some_other_key_identifier - use this key's default value.
                     profile_util::profile_to_colored_name(id)
                 );
             }
+            Some("reset") => {
+                let submatches = matches.subcommand_matches("reset").unwrap();
+                let profile_id = get_profile_id(submatches)?;
+                let only_changed = submatches.is_present("changed");
+                check_status(h.reset_profile(profile_id, only_changed)?)?;
+                println!(
+                    "Profile {} ({}) reset to defaults.",
+                    profile_id,
+                    profile_util::profile_to_colored_name(profile_id)
+                );
+            }
             Some("create") => {
                 let submatches = matches.subcommand_matches("create").unwrap();
                 let profile_id = get_profile_id(submatches)?;
-                h.profile_create(profile_id)?;
+                check_status(h.profile_create(profile_id)?)?;
                 println!(
                     "Profile {} ({}) created.",
                     profile_id,
@@ -572,7 +961,7 @@ some_other_key_identifier - use this key's default value.
                 for id in ids.iter() {
                     if *id == profile_id {
                         // found the thing, remove it.
-                        h.profile_delete(profile_id)?;
+                        check_status(h.profile_delete(profile_id)?)?;
                         println!(
                             "Profile {} ({}) removed.",
                             profile_id,
@@ -595,14 +984,47 @@ some_other_key_identifier - use this key's default value.
             Some("load") => {
                 let submatches = matches.subcommand_matches("load").unwrap();
                 let file = get_value::<String>(submatches, "file")?;
-                let profile = get_profile_id(submatches)?;
+                let profile =
+                    profile_util::str_to_profile_id(&get_value::<String>(submatches, "profile")?);
                 println!("println!  {}, {}", file, profile);
-                let mappings = huntsman::configuration::load_mappings(&file)
-                    .map_err(|x| format!("{:?}", x))?;
+                let names =
+                    huntsman::macro_names::MacroNames::load(&get_macro_names_path(submatches)?)?;
+                let mappings =
+                    huntsman::configuration::load_mappings_named(&file, &|n| names.get(n))?;
                 println!("{:?}", mappings);
-                for m in mappings.iter() {
-                    // build the actual configuration.
-                    h.set_mapping(profile, m.key, m.mapping)?;
+                if submatches.is_present("apply_changes_only") {
+                    let changes = huntsman::diff::diff_mappings(&mut h, profile, &mappings)?;
+                    for change in changes.iter() {
+                        println!("{}", change);
+                        check_status(h.set_mapping(
+                            change.profile,
+                            change.key,
+                            change.mapping(),
+                        )?)?;
+                    }
+                    println!("{} mapping(s) changed.", changes.len());
+                } else {
+                    for m in mappings.iter() {
+                        // build the actual configuration.
+                        check_status(h.set_mapping(profile, m.key, m.mapping)?)?;
+                    }
+                }
+            }
+            Some("diff") => {
+                let submatches = matches.subcommand_matches("diff").unwrap();
+                let file = get_value::<String>(submatches, "file")?;
+                let profile =
+                    profile_util::str_to_profile_id(&get_value::<String>(submatches, "profile")?);
+                let names =
+                    huntsman::macro_names::MacroNames::load(&get_macro_names_path(submatches)?)?;
+                let mappings =
+                    huntsman::configuration::load_mappings_named(&file, &|n| names.get(n))?;
+                let changes = huntsman::diff::diff_mappings(&mut h, profile, &mappings)?;
+                for change in changes.iter() {
+                    println!("{}", change);
+                }
+                if changes.is_empty() {
+                    println!("No changes.");
                 }
             }
             Some("retrieve") => {
@@ -627,35 +1049,37 @@ some_other_key_identifier - use this key's default value.
                         profile,
                         profile_util::profile_to_colored_name(*profile)
                     );
-                    for k in huntsman::configuration::at101_keys().iter() {
-                        let key = commands::mappings::Key {
-                            id: *k,
-                            hypershift: hypershift,
-                        };
-                        let x = h.get_mapping(*profile, key)?;
-                        let default_map = huntsman::configuration::get_default_keymap(&key);
-                        if show_all || (default_map != x.mapping) {
-                            let hypershift = if key.hypershift { ":hypershift" } else { "" };
-                            let mapping: String;
-                            match x.mapping {
-                                commands::mappings::KeyMapping::Key(v) => {
-                                    let key_name = if v.id != 0 {
-                                        huntsman::configuration::keyboard_hid_to_key_name(v.id)?
-                                    } else {
-                                        ""
-                                    };
-                                    mapping = format!("{:?} ({})", v, key_name);
-                                }
-                                _ => mapping = format!("{:?}", x.mapping),
-                            }
-                            println!(
-                                "  {: >30}{} {: >3} -> {}",
-                                huntsman::configuration::at101_to_key_name(key.id)?,
-                                hypershift,
-                                key.id,
-                                mapping
-                            );
+                    let filter = huntsman::MappingFilter {
+                        non_default_only: !show_all,
+                        hypershift: Some(hypershift),
+                    };
+                    let mappings = h.get_all_mappings(*profile, filter, &mut |done, total| {
+                        eprint!("\rRead {}/{} keys", done, total);
+                        if done == total {
+                            eprint!("\r\x1b[K");
                         }
+                    })?;
+                    for x in mappings.iter() {
+                        let key = x.key;
+                        let hypershift = if key.hypershift { ":hypershift" } else { "" };
+                        let mapping = match x.mapping {
+                            commands::mappings::KeyMapping::Key(v) => {
+                                let key_name = if v.id != 0 {
+                                    huntsman::configuration::keyboard_hid_to_key_name(v.id)?
+                                } else {
+                                    ""
+                                };
+                                format!("{:?} ({})", v, key_name)
+                            }
+                            _ => format!("{:?}", x.mapping),
+                        };
+                        println!(
+                            "  {: >30}{} {: >3} -> {}",
+                            huntsman::configuration::at101_to_key_name(key.id)?,
+                            hypershift,
+                            key.id,
+                            mapping
+                        );
                     }
                 }
             }
@@ -671,7 +1095,7 @@ some_other_key_identifier - use this key's default value.
                     hypershift: hypershift,
                 };
                 let mapping = huntsman::configuration::read_mapping(mapping.as_str(), &key)?;
-                h.set_mapping(profile, key, mapping)?;
+                check_status(h.set_mapping(profile, key, mapping)?)?;
                 println!("Set key: {:?}, to: {:?}", key_string, mapping);
             }
             _ => println!("Some other subcommand was used"),
@@ -684,7 +1108,7 @@ some_other_key_identifier - use this key's default value.
         let index = get_value::<u8>(matches, "index")?;
         let count = get_value::<u8>(matches, "count")?;
         let start = get_value::<u8>(matches, "start")?;
-        h.set_color_single(&color, count, index, start)?
+        check_status(h.set_color_single(&color, count, index, start)?)?;
     }
 
     return Ok(());
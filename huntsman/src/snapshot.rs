@@ -0,0 +1,184 @@
+//! A snapshot holds the complete onboard configuration of a keyboard, such that it can be written
+//! to a file and restored later, see [`crate::Huntsman::snapshot`] and [`crate::Huntsman::restore`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::{wire, ProfileId};
+use crate::configuration::{KeyConfig, MacroConfig};
+use crate::Error;
+
+/// The version of the snapshot format written by this crate, bump when the format changes.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// The state of a single profile.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ProfileSnapshot {
+    pub profile_id: ProfileId,
+    /// The led effect of this profile.
+    pub effect: wire::SetLedEffect,
+    /// The brightness of this profile, in [0, 1.0].
+    pub brightness: f32,
+    /// Mappings of all keys, with and without hypershift.
+    pub mappings: Vec<KeyConfig>,
+}
+
+/// The complete configuration of a keyboard.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Snapshot {
+    /// Version of the format, see [`SNAPSHOT_VERSION`].
+    pub version: u32,
+    /// Name of the model the snapshot was taken from.
+    pub model: String,
+    /// The profile that was active.
+    pub current_profile: ProfileId,
+    pub profiles: Vec<ProfileSnapshot>,
+    pub macros: Vec<MacroConfig>,
+}
+
+impl Snapshot {
+    /// Load a snapshot from a file, .json files are read as json, anything else as yaml.
+    pub fn load(filename: &str) -> Result<Snapshot, Error> {
+        let file = std::fs::File::open(filename)?;
+        let snapshot: Snapshot = if filename.ends_with(".json") {
+            serde_json::from_reader(file).map_err(|e| Error::Config {
+                path: filename.to_string(),
+                line: Some(e.line()),
+                column: Some(e.column()),
+                message: format!("{}", e),
+            })?
+        } else {
+            serde_yaml::from_reader(file).map_err(|e| Error::config_yaml(filename, e))?
+        };
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(Error::config(
+                filename,
+                &format!(
+                    "Snapshot version {} is not supported, expected {}.",
+                    snapshot.version, SNAPSHOT_VERSION
+                ),
+            ));
+        }
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot to a file, .json files are written as json, anything else as yaml.
+    pub fn save(&self, filename: &str) -> Result<(), Error> {
+        let file = std::fs::File::create(filename)?;
+        if filename.ends_with(".json") {
+            serde_json::to_writer_pretty(file, self)
+                .map_err(|e| Error::config(filename, &format!("{}", e)))
+        } else {
+            serde_yaml::to_writer(file, self).map_err(|e| Error::config_yaml(filename, e))
+        }
+    }
+
+    /// Returns the profile with this id, if it is part of the snapshot.
+    pub fn profile(&self, profile_id: ProfileId) -> Option<&ProfileSnapshot> {
+        self.profiles.iter().find(|p| p.profile_id == profile_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::macros::MacroAction;
+    use crate::commands::mappings::{Key, KeyMapping, MouseButton};
+    use crate::{CommandStatus, Huntsman};
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut h = Huntsman::virtual_new().expect("success");
+        assert_eq!(h.profile_create(3).expect("success"), CommandStatus::Ok);
+        let key = Key {
+            id: 0x40,
+            hypershift: true,
+        };
+        let mapping = KeyMapping::Mouse(MouseButton::Right);
+        assert_eq!(
+            h.set_mapping(3, key, mapping).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(
+            h.set_brightness(3, 0.5).expect("success"),
+            CommandStatus::Ok
+        );
+        let actions = vec![
+            MacroAction::KeyboardMake { hid: 0x05 },
+            MacroAction::Delay(300),
+            MacroAction::KeyboardBreak { hid: 0x05 },
+        ];
+        assert_eq!(
+            h.macro_create_actions(0x1337, &actions).expect("success"),
+            CommandStatus::Ok
+        );
+
+        let snapshot = h.snapshot(false).expect("success");
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+        assert_eq!(snapshot.profiles.len(), 2);
+        assert_eq!(snapshot.macros[0].events, actions);
+        let profile = snapshot.profile(3).expect("profile 3 present");
+        assert_eq!(profile.brightness, 127.0 / 255.0);
+        assert!(profile
+            .mappings
+            .iter()
+            .any(|m| m.key == key && m.mapping == mapping));
+        assert_eq!(profile.mappings.len(), h.model().keys.len() * 2);
+
+        // Round trip through both file formats.
+        for extension in ["yaml", "json"].iter() {
+            let path = std::env::temp_dir().join(format!(
+                "huntsman_test_snapshot_{}.{}",
+                std::process::id(),
+                extension
+            ));
+            let path = path.to_str().unwrap();
+            snapshot.save(path).expect("success");
+            assert_eq!(Snapshot::load(path).expect("success"), snapshot);
+            std::fs::remove_file(path).expect("success");
+        }
+
+        // Restoring on a fresh keyboard results in the same snapshot.
+        let mut fresh = Huntsman::virtual_new().expect("success");
+        assert_eq!(
+            fresh.macro_create_actions(0x42, &actions).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(
+            fresh.restore(&snapshot, false).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(fresh.snapshot(false).expect("success"), snapshot);
+        assert_eq!(fresh.macro_list().expect("success"), vec![0x1337]);
+
+        // A macro without events is refused before anything is changed.
+        let mut broken = snapshot.clone();
+        broken.macros[0].events.clear();
+        broken.profiles.retain(|p| p.profile_id != 3);
+        assert!(matches!(
+            fresh.restore(&broken, false),
+            Err(crate::Error::InvalidArgument(_))
+        ));
+        assert_eq!(fresh.profile_list().expect("success"), vec![1, 3]);
+        assert_eq!(fresh.macro_get(0x1337).expect("success"), actions);
+
+        // The names are only read and written with the metadata.
+        assert_eq!(
+            h.macro_create_named(0x1338, "paste_sig", &actions)
+                .expect("success"),
+            CommandStatus::Ok
+        );
+        let named = h.snapshot(true).expect("success");
+        assert_eq!(named.macros[1].name.as_deref(), Some("paste_sig"));
+        assert_eq!(h.snapshot(false).expect("success").macros[1].name, None);
+        assert_eq!(
+            fresh.restore(&named, false).expect("success"),
+            CommandStatus::Ok
+        );
+        assert!(fresh.macro_get_metadata(0x1338).is_err());
+        assert_eq!(
+            fresh.restore(&named, true).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(fresh.snapshot(true).expect("success"), named);
+    }
+}
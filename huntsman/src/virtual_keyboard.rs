@@ -0,0 +1,709 @@
+//! A software model of the keyboard that can be used instead of the real device.
+
+use crate::commands::{self, macros, mappings, profiles, wire, Cmd, MacroId, ProfileId, RGB};
+use crate::devices::DeviceModel;
+use crate::hid_hal::{DeviceInfo, DeviceSelector, HidHal};
+use crate::Error;
+use std::collections::{BTreeMap, HashMap};
+use struct_helper::{FromBytes, ToBytes};
+
+/// Status bytes as sent by the device, see [`commands::CommandStatus`].
+const STATUS_OK: u8 = 0x02;
+const STATUS_FAILURE: u8 = 0x03;
+const STATUS_UNSUPPORTED: u8 = 0x05;
+
+/// Maximum number of macros the macro list can hold.
+const MAX_MACROS: usize = 0x20;
+
+/// The state that is stored for each profile.
+#[derive(Default, Debug, Clone)]
+struct Profile {
+    /// Mappings that differ from the default, by at101 key id and hypershift.
+    mappings: HashMap<(u8, bool), mappings::KeyMapping>,
+    /// The metadata, if the profile holds any.
+    metadata: Option<Vec<u8>>,
+}
+
+/// Keeps the state of a keyboard and answers the commands like the device would, with framed and
+/// checksummed responses. The profiles, key mappings, macros, led effects, the custom frame,
+/// brightness and game mode are tracked. Commands that can't be executed get a failure status,
+/// unknown commands are answered with the unsupported status.
+pub struct VirtualKeyboard {
+    model: &'static DeviceModel,
+    serial: String,
+    profiles: BTreeMap<ProfileId, Profile>,
+    current_profile: ProfileId,
+    /// The allocated macro memory, by macro id.
+    macros: BTreeMap<MacroId, Vec<u8>>,
+    /// The macro metadata, by macro id.
+    macro_metadata: BTreeMap<MacroId, Vec<u8>>,
+    storage_total: u32,
+    /// Effects and brightness by the profile byte in the command, 0 being the active state.
+    effects: BTreeMap<u8, wire::SetLedEffect>,
+    brightness: BTreeMap<u8, u8>,
+    game_mode: bool,
+    /// The custom frame, by row.
+    frame: Vec<Vec<RGB>>,
+    /// The response to the last command.
+    report: Option<Vec<u8>>,
+}
+
+impl VirtualKeyboard {
+    /// Storage as reported by the Huntsman Elite.
+    const STORAGE_TOTAL: u32 = 102384;
+
+    /// Create a virtual keyboard of the provided model, holding just the first profile and no
+    /// macros.
+    pub fn new(model: &'static DeviceModel) -> VirtualKeyboard {
+        let mut profiles = BTreeMap::new();
+        profiles.insert(*model.profiles.start(), Default::default());
+        VirtualKeyboard {
+            model,
+            serial: "VIRTUAL0000000000001".to_string(),
+            profiles,
+            current_profile: *model.profiles.start(),
+            macros: BTreeMap::new(),
+            macro_metadata: BTreeMap::new(),
+            storage_total: VirtualKeyboard::STORAGE_TOTAL,
+            effects: BTreeMap::new(),
+            brightness: BTreeMap::new(),
+            game_mode: false,
+            frame: vec![
+                vec![Default::default(); model.led_columns as usize];
+                model.led_rows as usize
+            ],
+            report: None,
+        }
+    }
+
+    /// Bytes of macro storage that are not allocated.
+    fn storage_free(&self) -> u32 {
+        let used: usize = self.macros.values().map(|m| m.len()).sum();
+        self.storage_total.saturating_sub(used as u32)
+    }
+
+    /// Process the command, returning the status and optionally the payload to respond with.
+    fn handle(&mut self, cmd: Cmd, payload: &[u8]) -> Result<(u8, Option<Vec<u8>>), String> {
+        let status = match cmd {
+            commands::GetSerialNumber::CMD => {
+                let mut serial = self.serial.as_bytes().to_vec();
+                serial.push(0);
+                return Ok((STATUS_OK, Some(serial)));
+            }
+            commands::SetLedEffect::CMD => {
+                let effect = wire::SetLedEffect::from_le_bytes(payload)?;
+                if effect.profile != 0 && !self.profiles.contains_key(&effect.profile) {
+                    STATUS_FAILURE
+                } else {
+                    self.effects.insert(effect.profile, effect);
+                    STATUS_OK
+                }
+            }
+            commands::GetLedEffect::CMD => {
+                let request = wire::SetLedEffect::from_le_bytes(payload)?;
+                let mut effect = match self.effects.get(&request.profile) {
+                    Some(effect) => *effect,
+                    None if request.profile == 0
+                        || self.profiles.contains_key(&request.profile) =>
+                    {
+                        Default::default()
+                    }
+                    None => return Ok((STATUS_FAILURE, None)),
+                };
+                effect.profile = request.profile;
+                return Ok((STATUS_OK, Some(effect.to_le_bytes()?)));
+            }
+            commands::SetLedState::CMD => {
+                let state = wire::SetLedState::from_le_bytes(payload)?;
+                let count = std::cmp::min(state.count, self.model.led_columns) as usize;
+                match self.frame.get_mut(state.id as usize) {
+                    Some(row) => {
+                        row[..count].copy_from_slice(&state.leds[..count]);
+                        STATUS_OK
+                    }
+                    None => STATUS_FAILURE,
+                }
+            }
+            commands::SetLedBrightness::CMD => {
+                let brightness = wire::SetLedBrightness::from_le_bytes(payload)?;
+                self.brightness.insert(brightness.profile, brightness.value);
+                STATUS_OK
+            }
+            commands::GetLedBrightness::CMD => {
+                let mut brightness = wire::SetLedBrightness::from_le_bytes(payload)?;
+                brightness.value = *self.brightness.get(&brightness.profile).unwrap_or(&0xff);
+                return Ok((STATUS_OK, Some(brightness.to_le_bytes()?)));
+            }
+            commands::SetGameMode::CMD => {
+                let game_mode = wire::SetGameMode::from_le_bytes(payload)?;
+                self.game_mode = game_mode.game_mode_enabled != 0;
+                STATUS_OK
+            }
+            commands::GetGameMode::CMD => {
+                let mut game_mode = wire::SetGameMode::from_le_bytes(payload)?;
+                game_mode.game_mode_enabled = self.game_mode as u8;
+                return Ok((STATUS_OK, Some(game_mode.to_le_bytes()?)));
+            }
+            commands::SetKeyMap::CMD => {
+                let map = mappings::KeyMap::from_be_bytes(payload)?;
+                if !self.model.has_key(map.key.id) {
+                    STATUS_FAILURE
+                } else {
+                    match self.profiles.get_mut(&map.profile) {
+                        Some(profile) => {
+                            profile
+                                .mappings
+                                .insert((map.key.id, map.key.hypershift), map.mapping);
+                            STATUS_OK
+                        }
+                        None => STATUS_FAILURE,
+                    }
+                }
+            }
+            commands::GetKeyMap::CMD => {
+                let mut map = mappings::KeyMap::from_be_bytes(payload)?;
+                if !self.model.has_key(map.key.id) {
+                    return Ok((STATUS_FAILURE, None));
+                }
+                let profile = match self.profiles.get(&map.profile) {
+                    Some(profile) => profile,
+                    None => return Ok((STATUS_FAILURE, None)),
+                };
+                map.mapping = match profile.mappings.get(&(map.key.id, map.key.hypershift)) {
+                    Some(mapping) => *mapping,
+                    None => crate::keymap_util::get_default_keymap(&map.key),
+                };
+                return Ok((STATUS_OK, Some(map.to_be_bytes()?)));
+            }
+            commands::GetActiveProfileCount::CMD => {
+                let count = profiles::ProfileCount {
+                    count: self.profiles.len() as u8,
+                };
+                return Ok((STATUS_OK, Some(count.to_be_bytes()?)));
+            }
+            commands::GetActiveProfiles::CMD => {
+                let mut list: profiles::ProfileList = Default::default();
+                for id in self.profiles.keys() {
+                    list.profile_ids[list.length as usize] = *id;
+                    list.length += 1;
+                }
+                return Ok((STATUS_OK, Some(list.to_be_bytes()?)));
+            }
+            commands::ProfileCreate::CMD => {
+                let create = profiles::ProfileCreate::from_be_bytes(payload)?;
+                if !self.model.profiles.contains(&create.profile_id)
+                    || self.profiles.contains_key(&create.profile_id)
+                {
+                    STATUS_FAILURE
+                } else {
+                    self.profiles.insert(create.profile_id, Default::default());
+                    STATUS_OK
+                }
+            }
+            commands::ProfileDelete::CMD => {
+                let delete = profiles::ProfileDelete::from_be_bytes(payload)?;
+                if delete.profile_id == self.current_profile
+                    || delete.profile_id == *self.model.profiles.start()
+                    || self.profiles.remove(&delete.profile_id).is_none()
+                {
+                    STATUS_FAILURE
+                } else {
+                    self.effects.remove(&delete.profile_id);
+                    self.brightness.remove(&delete.profile_id);
+                    STATUS_OK
+                }
+            }
+            commands::GetProfileCurrent::CMD => {
+                let current = profiles::ProfileCurrent {
+                    profile_id: self.current_profile,
+                };
+                return Ok((STATUS_OK, Some(current.to_be_bytes()?)));
+            }
+            commands::SetProfileCurrent::CMD => {
+                let current = profiles::ProfileCurrent::from_be_bytes(payload)?;
+                if self.profiles.contains_key(&current.profile_id) {
+                    self.current_profile = current.profile_id;
+                    STATUS_OK
+                } else {
+                    STATUS_FAILURE
+                }
+            }
+            commands::GetProfileMetadata::CMD => {
+                let mut metadata = profiles::ProfileMetadata::from_be_bytes(payload)?;
+                let start = metadata.page as usize;
+                let data = self
+                    .profiles
+                    .get(&metadata.profile_id)
+                    .and_then(|p| p.metadata.as_ref());
+                match data {
+                    Some(data) if start < data.len() => {
+                        let len = std::cmp::min(data.len() - start, metadata.data.len());
+                        metadata.data = [0; 0x4d];
+                        metadata.data[..len].copy_from_slice(&data[start..start + len]);
+                        return Ok((STATUS_OK, Some(metadata.to_be_bytes()?)));
+                    }
+                    _ => STATUS_FAILURE,
+                }
+            }
+            commands::GetStorageStatistics::CMD => {
+                let stats = wire::GetStorageStatistics {
+                    something: 0xffff,
+                    total: self.storage_total,
+                    free1: self.storage_free(),
+                    free2: self.storage_free(),
+                };
+                return Ok((STATUS_OK, Some(stats.to_be_bytes()?)));
+            }
+            commands::GetActiveMacros::CMD => {
+                let mut list: macros::MacroList = Default::default();
+                for id in self.macros.keys().take(MAX_MACROS) {
+                    list.macro_ids[list.length as usize] = *id;
+                    list.length += 1;
+                }
+                return Ok((STATUS_OK, Some(list.to_be_bytes()?)));
+            }
+            commands::GetActiveMacroCount::CMD => {
+                let count = macros::MacroCount {
+                    count: self.macros.len() as u16,
+                };
+                return Ok((STATUS_OK, Some(count.to_be_bytes()?)));
+            }
+            commands::MacroCreate::CMD => {
+                let create = macros::MacroCreate::from_be_bytes(payload)?;
+                let macro_id = create.macro_id; // copy out of the packed struct.
+                if self.macros.contains_key(&macro_id)
+                    || self.macros.len() >= MAX_MACROS
+                    || create.event_bytes > self.storage_free()
+                {
+                    STATUS_FAILURE
+                } else {
+                    self.macros
+                        .insert(macro_id, vec![0; create.event_bytes as usize]);
+                    STATUS_OK
+                }
+            }
+            commands::MacroDelete::CMD => {
+                let delete = macros::MacroDelete::from_be_bytes(payload)?;
+                self.macro_metadata.remove(&delete.macro_id);
+                match self.macros.remove(&delete.macro_id) {
+                    Some(_) => STATUS_OK,
+                    None => STATUS_FAILURE,
+                }
+            }
+            commands::MacroActionsPayload::CMD => {
+                let actions = macros::MacroActionsPayload::from_be_bytes(payload)?;
+                let start = actions.position as usize;
+                let end = start + actions.event_bytes_in_msg as usize;
+                let macro_id = actions.macro_id;
+                match self.macros.get_mut(&macro_id) {
+                    Some(data) if end <= data.len() && end - start <= actions.events.len() => {
+                        data[start..end].copy_from_slice(&actions.events[..end - start]);
+                        STATUS_OK
+                    }
+                    _ => STATUS_FAILURE,
+                }
+            }
+            commands::GetMacroSize::CMD => {
+                let mut create = macros::MacroCreate::from_be_bytes(payload)?;
+                let macro_id = create.macro_id;
+                match self.macros.get(&macro_id) {
+                    Some(data) => {
+                        create.event_bytes = data.len() as u32;
+                        return Ok((STATUS_OK, Some(create.to_be_bytes()?)));
+                    }
+                    None => STATUS_FAILURE,
+                }
+            }
+            commands::GetMacroActionsPayload::CMD => {
+                let mut actions = macros::MacroActionsPayload::from_be_bytes(payload)?;
+                let start = actions.position as usize;
+                let macro_id = actions.macro_id;
+                match self.macros.get(&macro_id) {
+                    Some(data) if start <= data.len() => {
+                        let len = std::cmp::min(data.len() - start, actions.events.len());
+                        actions.events = [0; 0x48];
+                        actions.events[..len].copy_from_slice(&data[start..start + len]);
+                        actions.event_bytes_in_msg = len as u8;
+                        return Ok((STATUS_OK, Some(actions.to_be_bytes()?)));
+                    }
+                    _ => STATUS_FAILURE,
+                }
+            }
+            commands::MacroMetadata::CMD => {
+                let metadata = macros::MacroMetadata::from_be_bytes(payload)?;
+                let macro_id = metadata.macro_id;
+                let start = metadata.page_offset as usize;
+                let end = std::cmp::min(start + metadata.data.len(), macros::MACRO_METADATA_SIZE);
+                if !self.macros.contains_key(&macro_id) || start >= end {
+                    STATUS_FAILURE
+                } else {
+                    let data = self
+                        .macro_metadata
+                        .entry(macro_id)
+                        .or_insert_with(|| vec![0; macros::MACRO_METADATA_SIZE]);
+                    data[start..end].copy_from_slice(&metadata.data[..end - start]);
+                    STATUS_OK
+                }
+            }
+            commands::GetMacroMetadata::CMD => {
+                let mut metadata = macros::MacroMetadata::from_be_bytes(payload)?;
+                let macro_id = metadata.macro_id;
+                let start = metadata.page_offset as usize;
+                match self.macro_metadata.get(&macro_id) {
+                    Some(data) if start < data.len() => {
+                        let len = std::cmp::min(data.len() - start, metadata.data.len());
+                        metadata.data = [0; 0x4a];
+                        metadata.data[..len].copy_from_slice(&data[start..start + len]);
+                        return Ok((STATUS_OK, Some(metadata.to_be_bytes()?)));
+                    }
+                    _ => STATUS_FAILURE,
+                }
+            }
+            _ => STATUS_UNSUPPORTED,
+        };
+        Ok((status, None))
+    }
+}
+
+impl HidHal for VirtualKeyboard {
+    fn enumerate(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+        endpoint_id: u32,
+    ) -> Result<Vec<DeviceInfo>, Error> {
+        if product_id != self.model.product_id || endpoint_id != self.model.interface {
+            return Ok(vec![]);
+        }
+        Ok(vec![DeviceInfo {
+            path: "virtual".to_string(),
+            serial: Some(self.serial.clone()),
+            vendor_id,
+            product_id,
+            interface: endpoint_id as i32,
+        }])
+    }
+
+    fn connect(
+        &mut self,
+        _vendor_id: u16,
+        _product_id: u16,
+        _endpoint_id: u32,
+        _selector: &DeviceSelector,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn control(&mut self, payload: &[u8]) -> Result<(), Error> {
+        let mut wire = wire::Command::from_le_bytes(payload)?;
+        let checksum = wire.checksum;
+        wire.update_checksum();
+        let (status, response) = if checksum != wire.checksum {
+            (STATUS_FAILURE, None)
+        } else {
+            let len = std::cmp::min(wire.len as usize, wire.payload.len());
+            match self.handle(wire.cmd, &wire.payload[..]) {
+                Ok(v) => v,
+                // Payload that can't be interpreted.
+                Err(_) => (STATUS_UNSUPPORTED, Some(wire.payload[..len].to_vec())),
+            }
+        };
+        wire.status = status;
+        if let Some(response) = response {
+            let len = std::cmp::min(response.len(), wire.payload.len());
+            wire.payload = [0; 80];
+            wire.payload[..len].copy_from_slice(&response[..len]);
+        }
+        wire.update_checksum();
+        self.report = Some(wire.to_le_bytes()?);
+        Ok(())
+    }
+
+    fn get_report(&mut self) -> Result<Vec<u8>, Error> {
+        match &self.report {
+            Some(report) => Ok(report.clone()),
+            None => Err(Error::Transport("No request was sent.".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CommandStatus, Huntsman};
+
+    #[test]
+    fn test_virtual_profiles() {
+        let mut h = Huntsman::virtual_new().expect("success");
+        assert_eq!(h.profile_list().expect("success"), vec![1]);
+        assert_eq!(h.profile_create(3).expect("success"), CommandStatus::Ok);
+        assert_eq!(
+            h.profile_create(3).expect("success"),
+            CommandStatus::Failure
+        );
+        assert_eq!(h.profile_list().expect("success"), vec![1, 3]);
+        assert_eq!(h.profile_count().expect("success"), 2);
+        assert_eq!(
+            h.profile_set_current(3).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(h.profile_get_current().expect("success"), 3);
+        // Can't delete the active profile, or one that doesn't exist.
+        assert_eq!(
+            h.profile_delete(3).expect("success"),
+            CommandStatus::Failure
+        );
+        assert_eq!(
+            h.profile_delete(4).expect("success"),
+            CommandStatus::Failure
+        );
+        assert_eq!(
+            h.profile_set_current(4).expect("success"),
+            CommandStatus::Failure
+        );
+        assert_eq!(
+            h.profile_set_current(1).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(h.profile_delete(3).expect("success"), CommandStatus::Ok);
+        assert_eq!(h.profile_list().expect("success"), vec![1]);
+
+        // This crate doesn't write the profile metadata, so it is put on the keyboard directly.
+        let model = &crate::devices::HUNTSMAN_ELITE;
+        let mut keyboard = VirtualKeyboard::new(model);
+        let mut metadata = vec![0; profiles::PROFILE_METADATA_SIZE];
+        metadata[16..22].copy_from_slice(b"gaming");
+        keyboard.profiles.get_mut(&1).unwrap().metadata = Some(metadata);
+        let mut h = Huntsman::from_hal(Box::new(keyboard), model);
+        assert_eq!(h.profile_get_metadata(1).expect("success").name, "gaming");
+        assert_eq!(h.profile_create(3).expect("success"), CommandStatus::Ok);
+        assert!(matches!(
+            h.profile_get_metadata(3),
+            Err(Error::Rejected { .. })
+        ));
+    }
+
+    #[test]
+    fn test_virtual_mappings() {
+        let mut h = Huntsman::virtual_new().expect("success");
+        let key = mappings::Key {
+            id: 0x40,
+            hypershift: false,
+        };
+        let default = crate::keymap_util::get_default_keymap(&key);
+        assert_eq!(h.get_mapping(1, key).expect("success").mapping, default);
+        let mapping = mappings::KeyMapping::Mouse(mappings::MouseButton::Right);
+        assert_eq!(
+            h.set_mapping(1, key, mapping).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(h.get_mapping(1, key).expect("success").mapping, mapping);
+        // Every key present on the model can be retrieved.
+        let total = 2 * h.model().keys.len();
+        let mut reported = vec![];
+        let all = h
+            .get_all_mappings(1, Default::default(), &mut |done, total| {
+                reported.push((done, total))
+            })
+            .expect("success");
+        assert_eq!(all.len(), total);
+        assert_eq!(reported.len(), total);
+        assert_eq!(reported.last(), Some(&(total, total)));
+        let filter = crate::MappingFilter {
+            non_default_only: true,
+            hypershift: None,
+        };
+        let changed = h
+            .get_all_mappings(1, filter, &mut |_, _| {})
+            .expect("success");
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].key, key);
+        assert_eq!(changed[0].mapping, mapping);
+        let filter = crate::MappingFilter {
+            non_default_only: false,
+            hypershift: Some(true),
+        };
+        let hypershift = h
+            .get_all_mappings(1, filter, &mut |_, _| {})
+            .expect("success");
+        assert_eq!(hypershift.len(), h.model().keys.len());
+        assert!(hypershift.iter().all(|m| m.key.hypershift));
+        // Resetting the profile brings back the defaults.
+        assert_eq!(
+            h.set_brightness(1, 0.5).expect("success"),
+            CommandStatus::Ok
+        );
+        for only_changed in [true, false].iter() {
+            assert_eq!(
+                h.set_mapping(1, key, mapping).expect("success"),
+                CommandStatus::Ok
+            );
+            assert_eq!(
+                h.reset_profile(1, *only_changed).expect("success"),
+                CommandStatus::Ok
+            );
+            assert_eq!(h.get_mapping(1, key).expect("success").mapping, default);
+            assert_eq!(h.get_brightness(1).expect("success"), 1.0);
+            assert_eq!(
+                h.get_effect(1).expect("success").payload.effect,
+                crate::commands::SetLedEffect::spectrum().payload.effect
+            );
+        }
+        // Profile 2 doesn't exist.
+        assert_eq!(
+            h.set_mapping(2, key, mapping).expect("success"),
+            CommandStatus::Failure
+        );
+        assert!(matches!(
+            h.get_mapping(2, key),
+            Err(Error::Rejected {
+                status: CommandStatus::Failure,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_virtual_macros() {
+        let mut h = Huntsman::virtual_new().expect("success");
+        let actions = vec![
+            macros::MacroAction::KeyboardMake { hid: 0x05 },
+            macros::MacroAction::KeyboardBreak { hid: 0x05 },
+        ];
+        assert_eq!(
+            h.macro_create_actions(0x1337, &actions).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(h.macro_list().expect("success"), vec![0x1337]);
+        assert_eq!(h.macro_count().expect("success"), 1);
+        assert!(matches!(
+            h.macro_get_metadata(0x1337),
+            Err(Error::Rejected { .. })
+        ));
+        assert_eq!(
+            h.macro_create_named(0x1337, "paste_sig", &actions)
+                .expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(
+            h.macro_get_metadata(0x1337).expect("success"),
+            macros::MacroInfo::new("paste_sig", 0x1337, &actions)
+        );
+        assert_eq!(h.macro_delete(0x1337).expect("success"), CommandStatus::Ok);
+        assert_eq!(
+            h.macro_delete(0x1337).expect("success"),
+            CommandStatus::NotFound
+        );
+
+        // The storage statistics track the allocated macros.
+        let empty = h.storage_stats().expect("success");
+        assert_eq!(empty.total, VirtualKeyboard::STORAGE_TOTAL);
+        assert_eq!(empty.free, empty.total);
+        assert_eq!(
+            h.macro_create_actions(0x1337, &actions).expect("success"),
+            CommandStatus::Ok
+        );
+        let stats = h.storage_stats().expect("success");
+        assert_eq!(stats.used, 4);
+        assert_eq!(stats.free, empty.total - 4);
+
+        // A macro that doesn't fit is refused before the existing one is removed.
+        let too_large = vec![actions[0].clone(); VirtualKeyboard::STORAGE_TOTAL as usize / 2 + 1];
+        match h.macro_create_actions(0x1337, &too_large) {
+            Err(Error::InsufficientStorage {
+                required,
+                available,
+            }) => {
+                assert_eq!(required, VirtualKeyboard::STORAGE_TOTAL + 2);
+                assert_eq!(available, VirtualKeyboard::STORAGE_TOTAL);
+            }
+            z => panic!("Expected insufficient storage, got {:?}", z),
+        }
+        assert_eq!(h.macro_get(0x1337).expect("success"), actions);
+        assert_eq!(h.macro_delete(0x1337).expect("success"), CommandStatus::Ok);
+
+        // The metadata of a named macro counts as well, these events fit but not with it.
+        let almost_full =
+            vec![actions[0].clone(); VirtualKeyboard::STORAGE_TOTAL as usize / 2 - 10];
+        assert!(matches!(
+            h.macro_create_named(0x1337, "large", &almost_full),
+            Err(Error::InsufficientStorage { .. })
+        ));
+        assert!(h.macro_list().expect("success").is_empty());
+
+        // The size of an empty macro can't be read, it is replaced without counting its space.
+        assert_eq!(
+            h.macro_create_actions(0x1337, &vec![]).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(
+            h.macro_create_actions(0x1337, &actions).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(h.macro_get(0x1337).expect("success"), actions);
+        assert_eq!(h.macro_delete(0x1337).expect("success"), CommandStatus::Ok);
+
+        // Can't allocate more than the storage holds.
+        let size = VirtualKeyboard::STORAGE_TOTAL as usize + 1;
+        assert_eq!(
+            h.macro_create(1, size).expect("success"),
+            CommandStatus::Failure
+        );
+        assert!(h.macro_list().expect("success").is_empty());
+    }
+
+    #[test]
+    fn test_virtual_read_back() {
+        let mut h = Huntsman::virtual_new().expect("success");
+        assert!(!h.get_game_mode().expect("success"));
+        assert_eq!(h.set_game_mode(true).expect("success"), CommandStatus::Ok);
+        assert!(h.get_game_mode().expect("success"));
+
+        assert_eq!(
+            h.set_brightness(1, 0.5).expect("success"),
+            CommandStatus::Ok
+        );
+        assert_eq!(h.get_brightness(1).expect("success"), 127.0 / 255.0);
+
+        let color = RGB { r: 1, g: 2, b: 3 };
+        assert_eq!(h.effect_fixed(&color).expect("success"), CommandStatus::Ok);
+        let effect = h.get_effect(0).expect("success");
+        assert_eq!(effect.payload.effect, 0x01);
+        assert_eq!(effect.payload.colors[0].g, 2);
+        assert!(matches!(
+            h.get_effect(3),
+            Err(Error::Rejected {
+                status: CommandStatus::Failure,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_virtual_state() {
+        let mut keyboard = VirtualKeyboard::new(&crate::devices::HUNTSMAN_ELITE);
+        let mut send = |cmd: &dyn commands::Command| {
+            keyboard.control(&cmd.serialize()).expect("success");
+            cmd.status(&keyboard.get_report().expect("report"))
+                .expect("valid report")
+        };
+        assert_eq!(send(&commands::SetLedEffect::spectrum()), CommandStatus::Ok);
+        let game_mode = commands::SetGameMode { value: true };
+        assert_eq!(send(&game_mode), CommandStatus::Ok);
+        let leds = commands::SetLedState {
+            id: 9, // the Elite only has 9 rows.
+            ..Default::default()
+        };
+        assert_eq!(send(&leds), CommandStatus::Failure);
+        let unknown = commands::ArbitraryCommand {
+            register: Cmd {
+                major: 0x42,
+                minor: 0x42,
+            },
+            payload: vec![],
+        };
+        assert_eq!(send(&unknown), CommandStatus::Unsupported);
+        assert!(keyboard.game_mode);
+        assert_eq!(keyboard.effects[&0].effect, 0x03);
+    }
+}
@@ -6,6 +6,30 @@ pub use base::{Canvas, State, RGBA};
 
 use std::collections::HashMap;
 
+/// Sends the canvas to the keyboard's custom frame, the bottom of the canvas is the first led row.
+/// Pixels that don't fit on the connected model are ignored.
+pub fn set_canvas(h: &mut huntsman::Huntsman, c: &Canvas) -> Result<(), huntsman::Error> {
+    let columns = usize::min(c.width(), h.model().led_columns as usize);
+    let rows = usize::min(c.height(), h.model().led_rows as usize);
+    let mut rgb_buff: Vec<huntsman::RGB> = vec![Default::default(); columns];
+    for y in 0..rows {
+        for (x, led) in rgb_buff.iter_mut().enumerate() {
+            let pixel = c.pixel(x, c.height() - y - 1);
+            led.r = pixel.r_u8();
+            led.g = pixel.g_u8();
+            led.b = pixel.b_u8();
+        }
+        let status = h.set_color(y as u8, &rgb_buff[..])?;
+        if !status.is_ok() {
+            return Err(huntsman::Error::Rejected {
+                cmd: huntsman::commands::SetLedState::CMD,
+                status,
+            });
+        }
+    }
+    Ok(())
+}
+
 #[derive(Clone, Debug)]
 pub struct BasicState {
     pub stored: HashMap<String, Canvas>,
@@ -58,3 +82,78 @@ impl State for BasicState {
         self.rng.as_mut().unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use huntsman::{DeviceInfo, DeviceSelector, Error, HidHal};
+    use std::sync::{Arc, Mutex};
+
+    /// Hal that acknowledges every command and keeps the requests it received.
+    struct FakeHal {
+        requests: Arc<Mutex<Vec<Vec<u8>>>>,
+    }
+
+    impl HidHal for FakeHal {
+        fn enumerate(
+            &mut self,
+            _vendor_id: u16,
+            _product_id: u16,
+            _endpoint_id: u32,
+        ) -> Result<Vec<DeviceInfo>, Error> {
+            Ok(vec![])
+        }
+        fn connect(
+            &mut self,
+            _vendor_id: u16,
+            _product_id: u16,
+            _endpoint_id: u32,
+            _selector: &DeviceSelector,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+        fn control(&mut self, payload: &[u8]) -> Result<(), Error> {
+            self.requests.lock().unwrap().push(payload.to_vec());
+            Ok(())
+        }
+        fn get_report(&mut self) -> Result<Vec<u8>, Error> {
+            // The status isn't part of the checksum, so the request with status ok is valid.
+            let mut report = self.requests.lock().unwrap().last().unwrap().clone();
+            report[0] = 0x02;
+            Ok(report)
+        }
+    }
+
+    #[test]
+    fn test_set_canvas() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let mut h = huntsman::Huntsman::with_hal(Box::new(FakeHal {
+            requests: Arc::clone(&requests),
+        }));
+        let model = h.model();
+        let mut canvas = Canvas::new(model.led_columns as usize, model.led_rows as usize);
+        // Top left pixel is red, this is the first led in the last row.
+        *canvas.pixel_as_mut(0, 0) = RGBA {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        };
+        set_canvas(&mut h, &canvas).expect("success");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), model.led_rows as usize);
+        for (row, request) in requests.iter().enumerate() {
+            // Header is 8 bytes, then first, padding, row id, padding, count and the leds.
+            assert_eq!(request[10] as usize, row);
+            assert_eq!(request[12], model.led_columns);
+            let first_red = request[13];
+            let expected = if row == model.led_rows as usize - 1 {
+                0xff
+            } else {
+                0
+            };
+            assert_eq!(first_red, expected);
+        }
+    }
+}
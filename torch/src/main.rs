@@ -1,4 +1,3 @@
-use huntsman::RGB;
 use torch::{BasicState, Canvas, State};
 
 fn get_time() -> f64 {
@@ -9,22 +8,6 @@ fn get_time() -> f64 {
         + (since_the_epoch.subsec_nanos() as f64 / 1_000_000_000.0);
 }
 
-#[allow(dead_code)]
-fn set_canvas(h: &mut huntsman::Huntsman, c: &Canvas) -> Result<(), Box<dyn std::error::Error>> {
-    let s = get_time();
-    let mut rgb_buff: Vec<RGB> = vec![Default::default(); c.width()];
-    for y in 0..c.height() {
-        for x in 0..usize::min(c.width(), 23) {
-            rgb_buff[x].r = c.pixel(x, c.height() - y - 1).r_u8();
-            rgb_buff[x].g = c.pixel(x, c.height() - y - 1).g_u8();
-            rgb_buff[x].b = c.pixel(x, c.height() - y - 1).b_u8();
-        }
-        h.set_color(y as u8, &rgb_buff[..])?
-    }
-    println!("set canvas took: {:.5}", get_time() - s);
-    Ok(())
-}
-
 #[allow(unreachable_code, unused_variables)]
 pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = std::env::args().collect();
@@ -35,9 +18,12 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // return Ok(());
 
+    let mut h = huntsman::Huntsman::new()?;
+    let model = h.model();
+
     let mut mystate: BasicState = BasicState {
         stored: Default::default(),
-        base_canvas: Canvas::transparent(23, 9),
+        base_canvas: Canvas::transparent(model.led_columns as usize, model.led_rows as usize),
         last_update_cycle: 0.0,
         rng: None,
         update_count: 0,
@@ -46,8 +32,10 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
     mystate.finish_update();
     let ten_millis = std::time::Duration::from_millis(50);
 
-    let mut h = huntsman::Huntsman::new()?;
-    h.effect_custom()?;
+    let status = h.effect_custom()?;
+    if !status.is_ok() {
+        return Err(format!("Enabling the custom effect failed: {}", status).into());
+    }
     loop {
         // for _i in 0..100 {
         mystate.start_update();
@@ -56,7 +44,9 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
         // println!("update took: {:.5}", get_time() - s);
         mystate.finish_update();
         println!("{}\n", res.to_string());
-        set_canvas(&mut h, &res)?;
+        let s = get_time();
+        torch::set_canvas(&mut h, &res)?;
+        println!("set canvas took: {:.5}", get_time() - s);
         std::thread::sleep(ten_millis);
     }
     Ok(())
@@ -2,6 +2,18 @@
 enum Usage {
     Selector,
     DynamicFlag,
+    LinearControl,
+    OnOffControl,
+    MomentaryControl,
+    OneShotControl,
+    ReTriggerControl,
+    StaticValue,
+    DynamicValue,
+    Switch,
+    NamedArray,
+    CollectionApplication,
+    CollectionLogical,
+    CollectionPhysical,
 }
 
 #[derive(Debug, Clone)]
@@ -88,6 +100,143 @@ fn parse_file(fname: &str) -> Vec<Key> {
     return res;
 }
 
+#[derive(Debug, Clone)]
+struct PageUsage {
+    pub id: usize,
+    pub desc: String,
+    pub usage: Usage,
+}
+
+fn parse_usage_type(token: &str) -> Option<Usage> {
+    match token {
+        "Sel" => Some(Usage::Selector),
+        "DF" => Some(Usage::DynamicFlag),
+        "LC" => Some(Usage::LinearControl),
+        "OOC" => Some(Usage::OnOffControl),
+        "MC" => Some(Usage::MomentaryControl),
+        "OSC" => Some(Usage::OneShotControl),
+        "RTC" => Some(Usage::ReTriggerControl),
+        "SV" => Some(Usage::StaticValue),
+        "DV" => Some(Usage::DynamicValue),
+        "US" => Some(Usage::Switch),
+        "NAry" => Some(Usage::NamedArray),
+        "CA" => Some(Usage::CollectionApplication),
+        "CL" => Some(Usage::CollectionLogical),
+        "CP" => Some(Usage::CollectionPhysical),
+        _ => None,
+    }
+}
+
+/// Parse the pages other than the keyboard page, their lines are;
+/// xx Usage name Usage type
+/// Lines with a range as id, or without a usage type are skipped.
+fn parse_page_file(fname: &str) -> Vec<PageUsage> {
+    let mut res: Vec<PageUsage> = Vec::new();
+
+    let contents = std::fs::read_to_string(fname).expect("Failed to read the file.");
+    for l in contents.lines() {
+        if l.starts_with('#') {
+            continue; // comment
+        }
+        let tokens = l.split_whitespace().collect::<Vec<&str>>();
+        if tokens.len() < 3 {
+            continue; // empty line, or no usage type.
+        }
+        let id = match usize::from_str_radix(tokens[0], 16) {
+            Ok(id) => id,
+            Err(_) => continue, // ranges.
+        };
+        let usage = match parse_usage_type(tokens[tokens.len() - 1]) {
+            Some(usage) => usage,
+            None => continue,
+        };
+        res.push(PageUsage {
+            id,
+            desc: tokens[1..tokens.len() - 1].join(" "),
+            usage,
+        });
+    }
+    res
+}
+
+/// Turn a usage name like 'AL Contacts/Address Book' into 'al_contacts_address_book'.
+fn usage_desc_to_name(desc: &str) -> String {
+    let name = desc
+        .to_ascii_lowercase()
+        .replace("+", "plus")
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    name.split('_')
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_")
+}
+
+/// The module name for a page file, hut1_22_pdf_consumer_page_0x0c.txt becomes
+/// hid_consumer_page.
+fn page_module(fname: &str) -> String {
+    let stem = std::path::Path::new(fname)
+        .file_stem()
+        .expect("Should have a file name")
+        .to_string_lossy()
+        .to_string();
+    let start = stem.find("_pdf_").map(|p| p + "_pdf_".len()).unwrap_or(0);
+    let end = stem.rfind("_0x").unwrap_or(stem.len());
+    format!("hid_{}", &stem[start..end])
+}
+
+fn generate_page(input: &str, output: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let usages = parse_page_file(input);
+    let mut usagedefs: Vec<String> = vec![];
+    let mut names: Vec<String> = vec![];
+    for u in usages.iter() {
+        let n = usage_desc_to_name(&u.desc).to_ascii_uppercase();
+        usagedefs.push(format!(
+            "
+    pub const {}: PageUsage = PageUsage {{
+        name: \"{}\",
+        id: 0x{:0>2x},
+        usage: Usage::{:?},
+        desc: \"{}\",
+    }};",
+            n, n, u.id, u.usage, u.desc
+        ));
+        names.push(n);
+    }
+
+    if let Some(output) = output {
+        use std::io::Write;
+        let mut file = std::fs::File::create(&output)?;
+        let fcontent = format!(
+            "// This file is generated with dev/generate.rs
+
+#[allow(dead_code)]
+pub mod {} {{
+    #[allow(dead_code)]
+    use crate::defs::{{PageUsage, Usage}};
+{}
+
+    pub const fn usages() -> &'static [PageUsage] {{
+        &[
+{},
+        ]
+    }}
+}}
+",
+            page_module(input),
+            usagedefs.join("\n"),
+            names
+                .iter()
+                .map(|x| format!("            {}", x))
+                .collect::<Vec<String>>()
+                .join(",\n")
+        );
+        file.write_all(fcontent.as_bytes())?;
+    } else {
+        println!("{}", usagedefs.join("\n"));
+    }
+    Ok(())
+}
+
 fn desc_to_name(desc: &str) -> String {
     let tokens = desc.split(" ").collect::<Vec<&str>>();
     // println!("{:?}", tokens);
@@ -179,8 +328,13 @@ fn desc_to_name(desc: &str) -> String {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     if std::env::args().len() < 2 {
         println!("use like: cargo run --bin generate -- hut1_22_pdf_keyboard_page_0x07.txt src/keyboard_page.rs");
+        println!("      or: cargo run --bin generate -- hut1_22_pdf_consumer_page_0x0c.txt src/consumer_page.rs");
         std::process::exit(1);
     }
+    let input = std::env::args().nth(1).unwrap();
+    if !input.contains("keyboard_page") {
+        return generate_page(&input, std::env::args().nth(2));
+    }
     let keys = parse_file(&std::env::args().nth(1).unwrap());
     // println!("{:#?}", keys);
     let mut keydefs: Vec<String> = vec![];
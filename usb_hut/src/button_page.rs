@@ -0,0 +1,276 @@
+// This file is generated with dev/generate.rs
+
+#[allow(dead_code)]
+pub mod hid_button_page {
+    #[allow(dead_code)]
+    use crate::defs::{PageUsage, Usage};
+
+    pub const NO_BUTTON_PRESSED: PageUsage = PageUsage {
+        name: "NO_BUTTON_PRESSED",
+        id: 0x00,
+        usage: Usage::Selector,
+        desc: "No button pressed",
+    };
+
+    pub const BUTTON_1: PageUsage = PageUsage {
+        name: "BUTTON_1",
+        id: 0x01,
+        usage: Usage::MomentaryControl,
+        desc: "Button 1",
+    };
+
+    pub const BUTTON_2: PageUsage = PageUsage {
+        name: "BUTTON_2",
+        id: 0x02,
+        usage: Usage::MomentaryControl,
+        desc: "Button 2",
+    };
+
+    pub const BUTTON_3: PageUsage = PageUsage {
+        name: "BUTTON_3",
+        id: 0x03,
+        usage: Usage::MomentaryControl,
+        desc: "Button 3",
+    };
+
+    pub const BUTTON_4: PageUsage = PageUsage {
+        name: "BUTTON_4",
+        id: 0x04,
+        usage: Usage::MomentaryControl,
+        desc: "Button 4",
+    };
+
+    pub const BUTTON_5: PageUsage = PageUsage {
+        name: "BUTTON_5",
+        id: 0x05,
+        usage: Usage::MomentaryControl,
+        desc: "Button 5",
+    };
+
+    pub const BUTTON_6: PageUsage = PageUsage {
+        name: "BUTTON_6",
+        id: 0x06,
+        usage: Usage::MomentaryControl,
+        desc: "Button 6",
+    };
+
+    pub const BUTTON_7: PageUsage = PageUsage {
+        name: "BUTTON_7",
+        id: 0x07,
+        usage: Usage::MomentaryControl,
+        desc: "Button 7",
+    };
+
+    pub const BUTTON_8: PageUsage = PageUsage {
+        name: "BUTTON_8",
+        id: 0x08,
+        usage: Usage::MomentaryControl,
+        desc: "Button 8",
+    };
+
+    pub const BUTTON_9: PageUsage = PageUsage {
+        name: "BUTTON_9",
+        id: 0x09,
+        usage: Usage::MomentaryControl,
+        desc: "Button 9",
+    };
+
+    pub const BUTTON_10: PageUsage = PageUsage {
+        name: "BUTTON_10",
+        id: 0x0a,
+        usage: Usage::MomentaryControl,
+        desc: "Button 10",
+    };
+
+    pub const BUTTON_11: PageUsage = PageUsage {
+        name: "BUTTON_11",
+        id: 0x0b,
+        usage: Usage::MomentaryControl,
+        desc: "Button 11",
+    };
+
+    pub const BUTTON_12: PageUsage = PageUsage {
+        name: "BUTTON_12",
+        id: 0x0c,
+        usage: Usage::MomentaryControl,
+        desc: "Button 12",
+    };
+
+    pub const BUTTON_13: PageUsage = PageUsage {
+        name: "BUTTON_13",
+        id: 0x0d,
+        usage: Usage::MomentaryControl,
+        desc: "Button 13",
+    };
+
+    pub const BUTTON_14: PageUsage = PageUsage {
+        name: "BUTTON_14",
+        id: 0x0e,
+        usage: Usage::MomentaryControl,
+        desc: "Button 14",
+    };
+
+    pub const BUTTON_15: PageUsage = PageUsage {
+        name: "BUTTON_15",
+        id: 0x0f,
+        usage: Usage::MomentaryControl,
+        desc: "Button 15",
+    };
+
+    pub const BUTTON_16: PageUsage = PageUsage {
+        name: "BUTTON_16",
+        id: 0x10,
+        usage: Usage::MomentaryControl,
+        desc: "Button 16",
+    };
+
+    pub const BUTTON_17: PageUsage = PageUsage {
+        name: "BUTTON_17",
+        id: 0x11,
+        usage: Usage::MomentaryControl,
+        desc: "Button 17",
+    };
+
+    pub const BUTTON_18: PageUsage = PageUsage {
+        name: "BUTTON_18",
+        id: 0x12,
+        usage: Usage::MomentaryControl,
+        desc: "Button 18",
+    };
+
+    pub const BUTTON_19: PageUsage = PageUsage {
+        name: "BUTTON_19",
+        id: 0x13,
+        usage: Usage::MomentaryControl,
+        desc: "Button 19",
+    };
+
+    pub const BUTTON_20: PageUsage = PageUsage {
+        name: "BUTTON_20",
+        id: 0x14,
+        usage: Usage::MomentaryControl,
+        desc: "Button 20",
+    };
+
+    pub const BUTTON_21: PageUsage = PageUsage {
+        name: "BUTTON_21",
+        id: 0x15,
+        usage: Usage::MomentaryControl,
+        desc: "Button 21",
+    };
+
+    pub const BUTTON_22: PageUsage = PageUsage {
+        name: "BUTTON_22",
+        id: 0x16,
+        usage: Usage::MomentaryControl,
+        desc: "Button 22",
+    };
+
+    pub const BUTTON_23: PageUsage = PageUsage {
+        name: "BUTTON_23",
+        id: 0x17,
+        usage: Usage::MomentaryControl,
+        desc: "Button 23",
+    };
+
+    pub const BUTTON_24: PageUsage = PageUsage {
+        name: "BUTTON_24",
+        id: 0x18,
+        usage: Usage::MomentaryControl,
+        desc: "Button 24",
+    };
+
+    pub const BUTTON_25: PageUsage = PageUsage {
+        name: "BUTTON_25",
+        id: 0x19,
+        usage: Usage::MomentaryControl,
+        desc: "Button 25",
+    };
+
+    pub const BUTTON_26: PageUsage = PageUsage {
+        name: "BUTTON_26",
+        id: 0x1a,
+        usage: Usage::MomentaryControl,
+        desc: "Button 26",
+    };
+
+    pub const BUTTON_27: PageUsage = PageUsage {
+        name: "BUTTON_27",
+        id: 0x1b,
+        usage: Usage::MomentaryControl,
+        desc: "Button 27",
+    };
+
+    pub const BUTTON_28: PageUsage = PageUsage {
+        name: "BUTTON_28",
+        id: 0x1c,
+        usage: Usage::MomentaryControl,
+        desc: "Button 28",
+    };
+
+    pub const BUTTON_29: PageUsage = PageUsage {
+        name: "BUTTON_29",
+        id: 0x1d,
+        usage: Usage::MomentaryControl,
+        desc: "Button 29",
+    };
+
+    pub const BUTTON_30: PageUsage = PageUsage {
+        name: "BUTTON_30",
+        id: 0x1e,
+        usage: Usage::MomentaryControl,
+        desc: "Button 30",
+    };
+
+    pub const BUTTON_31: PageUsage = PageUsage {
+        name: "BUTTON_31",
+        id: 0x1f,
+        usage: Usage::MomentaryControl,
+        desc: "Button 31",
+    };
+
+    pub const BUTTON_32: PageUsage = PageUsage {
+        name: "BUTTON_32",
+        id: 0x20,
+        usage: Usage::MomentaryControl,
+        desc: "Button 32",
+    };
+
+    pub const fn usages() -> &'static [PageUsage] {
+        &[
+            NO_BUTTON_PRESSED,
+            BUTTON_1,
+            BUTTON_2,
+            BUTTON_3,
+            BUTTON_4,
+            BUTTON_5,
+            BUTTON_6,
+            BUTTON_7,
+            BUTTON_8,
+            BUTTON_9,
+            BUTTON_10,
+            BUTTON_11,
+            BUTTON_12,
+            BUTTON_13,
+            BUTTON_14,
+            BUTTON_15,
+            BUTTON_16,
+            BUTTON_17,
+            BUTTON_18,
+            BUTTON_19,
+            BUTTON_20,
+            BUTTON_21,
+            BUTTON_22,
+            BUTTON_23,
+            BUTTON_24,
+            BUTTON_25,
+            BUTTON_26,
+            BUTTON_27,
+            BUTTON_28,
+            BUTTON_29,
+            BUTTON_30,
+            BUTTON_31,
+            BUTTON_32,
+        ]
+    }
+}
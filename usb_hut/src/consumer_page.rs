@@ -0,0 +1,2924 @@
+// This file is generated with dev/generate.rs
+
+#[allow(dead_code)]
+pub mod hid_consumer_page {
+    #[allow(dead_code)]
+    use crate::defs::{PageUsage, Usage};
+
+    pub const CONSUMER_CONTROL: PageUsage = PageUsage {
+        name: "CONSUMER_CONTROL",
+        id: 0x01,
+        usage: Usage::CollectionApplication,
+        desc: "Consumer Control",
+    };
+
+    pub const NUMERIC_KEY_PAD: PageUsage = PageUsage {
+        name: "NUMERIC_KEY_PAD",
+        id: 0x02,
+        usage: Usage::NamedArray,
+        desc: "Numeric Key Pad",
+    };
+
+    pub const PROGRAMMABLE_BUTTONS: PageUsage = PageUsage {
+        name: "PROGRAMMABLE_BUTTONS",
+        id: 0x03,
+        usage: Usage::NamedArray,
+        desc: "Programmable Buttons",
+    };
+
+    pub const MICROPHONE: PageUsage = PageUsage {
+        name: "MICROPHONE",
+        id: 0x04,
+        usage: Usage::CollectionApplication,
+        desc: "Microphone",
+    };
+
+    pub const HEADPHONE: PageUsage = PageUsage {
+        name: "HEADPHONE",
+        id: 0x05,
+        usage: Usage::CollectionApplication,
+        desc: "Headphone",
+    };
+
+    pub const GRAPHIC_EQUALIZER: PageUsage = PageUsage {
+        name: "GRAPHIC_EQUALIZER",
+        id: 0x06,
+        usage: Usage::CollectionApplication,
+        desc: "Graphic Equalizer",
+    };
+
+    pub const PLUS10: PageUsage = PageUsage {
+        name: "PLUS10",
+        id: 0x20,
+        usage: Usage::OneShotControl,
+        desc: "+10",
+    };
+
+    pub const PLUS100: PageUsage = PageUsage {
+        name: "PLUS100",
+        id: 0x21,
+        usage: Usage::OneShotControl,
+        desc: "+100",
+    };
+
+    pub const AM_PM: PageUsage = PageUsage {
+        name: "AM_PM",
+        id: 0x22,
+        usage: Usage::OneShotControl,
+        desc: "AM/PM",
+    };
+
+    pub const POWER: PageUsage = PageUsage {
+        name: "POWER",
+        id: 0x30,
+        usage: Usage::OnOffControl,
+        desc: "Power",
+    };
+
+    pub const RESET: PageUsage = PageUsage {
+        name: "RESET",
+        id: 0x31,
+        usage: Usage::OneShotControl,
+        desc: "Reset",
+    };
+
+    pub const SLEEP: PageUsage = PageUsage {
+        name: "SLEEP",
+        id: 0x32,
+        usage: Usage::OneShotControl,
+        desc: "Sleep",
+    };
+
+    pub const SLEEP_AFTER: PageUsage = PageUsage {
+        name: "SLEEP_AFTER",
+        id: 0x33,
+        usage: Usage::OneShotControl,
+        desc: "Sleep After",
+    };
+
+    pub const SLEEP_MODE: PageUsage = PageUsage {
+        name: "SLEEP_MODE",
+        id: 0x34,
+        usage: Usage::ReTriggerControl,
+        desc: "Sleep Mode",
+    };
+
+    pub const ILLUMINATION: PageUsage = PageUsage {
+        name: "ILLUMINATION",
+        id: 0x35,
+        usage: Usage::OnOffControl,
+        desc: "Illumination",
+    };
+
+    pub const FUNCTION_BUTTONS: PageUsage = PageUsage {
+        name: "FUNCTION_BUTTONS",
+        id: 0x36,
+        usage: Usage::NamedArray,
+        desc: "Function Buttons",
+    };
+
+    pub const MENU: PageUsage = PageUsage {
+        name: "MENU",
+        id: 0x40,
+        usage: Usage::OnOffControl,
+        desc: "Menu",
+    };
+
+    pub const MENU_PICK: PageUsage = PageUsage {
+        name: "MENU_PICK",
+        id: 0x41,
+        usage: Usage::OneShotControl,
+        desc: "Menu Pick",
+    };
+
+    pub const MENU_UP: PageUsage = PageUsage {
+        name: "MENU_UP",
+        id: 0x42,
+        usage: Usage::OneShotControl,
+        desc: "Menu Up",
+    };
+
+    pub const MENU_DOWN: PageUsage = PageUsage {
+        name: "MENU_DOWN",
+        id: 0x43,
+        usage: Usage::OneShotControl,
+        desc: "Menu Down",
+    };
+
+    pub const MENU_LEFT: PageUsage = PageUsage {
+        name: "MENU_LEFT",
+        id: 0x44,
+        usage: Usage::OneShotControl,
+        desc: "Menu Left",
+    };
+
+    pub const MENU_RIGHT: PageUsage = PageUsage {
+        name: "MENU_RIGHT",
+        id: 0x45,
+        usage: Usage::OneShotControl,
+        desc: "Menu Right",
+    };
+
+    pub const MENU_ESCAPE: PageUsage = PageUsage {
+        name: "MENU_ESCAPE",
+        id: 0x46,
+        usage: Usage::OneShotControl,
+        desc: "Menu Escape",
+    };
+
+    pub const MENU_VALUE_INCREASE: PageUsage = PageUsage {
+        name: "MENU_VALUE_INCREASE",
+        id: 0x47,
+        usage: Usage::OneShotControl,
+        desc: "Menu Value Increase",
+    };
+
+    pub const MENU_VALUE_DECREASE: PageUsage = PageUsage {
+        name: "MENU_VALUE_DECREASE",
+        id: 0x48,
+        usage: Usage::OneShotControl,
+        desc: "Menu Value Decrease",
+    };
+
+    pub const DATA_ON_SCREEN: PageUsage = PageUsage {
+        name: "DATA_ON_SCREEN",
+        id: 0x60,
+        usage: Usage::OnOffControl,
+        desc: "Data On Screen",
+    };
+
+    pub const CLOSED_CAPTION: PageUsage = PageUsage {
+        name: "CLOSED_CAPTION",
+        id: 0x61,
+        usage: Usage::OnOffControl,
+        desc: "Closed Caption",
+    };
+
+    pub const CLOSED_CAPTION_SELECT: PageUsage = PageUsage {
+        name: "CLOSED_CAPTION_SELECT",
+        id: 0x62,
+        usage: Usage::OneShotControl,
+        desc: "Closed Caption Select",
+    };
+
+    pub const VCR_TV: PageUsage = PageUsage {
+        name: "VCR_TV",
+        id: 0x63,
+        usage: Usage::OnOffControl,
+        desc: "VCR/TV",
+    };
+
+    pub const BROADCAST_MODE: PageUsage = PageUsage {
+        name: "BROADCAST_MODE",
+        id: 0x64,
+        usage: Usage::OneShotControl,
+        desc: "Broadcast Mode",
+    };
+
+    pub const SNAPSHOT: PageUsage = PageUsage {
+        name: "SNAPSHOT",
+        id: 0x65,
+        usage: Usage::OneShotControl,
+        desc: "Snapshot",
+    };
+
+    pub const STILL: PageUsage = PageUsage {
+        name: "STILL",
+        id: 0x66,
+        usage: Usage::OneShotControl,
+        desc: "Still",
+    };
+
+    pub const SELECTION: PageUsage = PageUsage {
+        name: "SELECTION",
+        id: 0x80,
+        usage: Usage::NamedArray,
+        desc: "Selection",
+    };
+
+    pub const ASSIGN_SELECTION: PageUsage = PageUsage {
+        name: "ASSIGN_SELECTION",
+        id: 0x81,
+        usage: Usage::OneShotControl,
+        desc: "Assign Selection",
+    };
+
+    pub const MODE_STEP: PageUsage = PageUsage {
+        name: "MODE_STEP",
+        id: 0x82,
+        usage: Usage::OneShotControl,
+        desc: "Mode Step",
+    };
+
+    pub const RECALL_LAST: PageUsage = PageUsage {
+        name: "RECALL_LAST",
+        id: 0x83,
+        usage: Usage::OneShotControl,
+        desc: "Recall Last",
+    };
+
+    pub const ENTER_CHANNEL: PageUsage = PageUsage {
+        name: "ENTER_CHANNEL",
+        id: 0x84,
+        usage: Usage::OneShotControl,
+        desc: "Enter Channel",
+    };
+
+    pub const ORDER_MOVIE: PageUsage = PageUsage {
+        name: "ORDER_MOVIE",
+        id: 0x85,
+        usage: Usage::OneShotControl,
+        desc: "Order Movie",
+    };
+
+    pub const CHANNEL: PageUsage = PageUsage {
+        name: "CHANNEL",
+        id: 0x86,
+        usage: Usage::LinearControl,
+        desc: "Channel",
+    };
+
+    pub const MEDIA_SELECTION: PageUsage = PageUsage {
+        name: "MEDIA_SELECTION",
+        id: 0x87,
+        usage: Usage::NamedArray,
+        desc: "Media Selection",
+    };
+
+    pub const MEDIA_SELECT_COMPUTER: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_COMPUTER",
+        id: 0x88,
+        usage: Usage::Selector,
+        desc: "Media Select Computer",
+    };
+
+    pub const MEDIA_SELECT_TV: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_TV",
+        id: 0x89,
+        usage: Usage::Selector,
+        desc: "Media Select TV",
+    };
+
+    pub const MEDIA_SELECT_WWW: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_WWW",
+        id: 0x8a,
+        usage: Usage::Selector,
+        desc: "Media Select WWW",
+    };
+
+    pub const MEDIA_SELECT_DVD: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_DVD",
+        id: 0x8b,
+        usage: Usage::Selector,
+        desc: "Media Select DVD",
+    };
+
+    pub const MEDIA_SELECT_TELEPHONE: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_TELEPHONE",
+        id: 0x8c,
+        usage: Usage::Selector,
+        desc: "Media Select Telephone",
+    };
+
+    pub const MEDIA_SELECT_PROGRAM_GUIDE: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_PROGRAM_GUIDE",
+        id: 0x8d,
+        usage: Usage::Selector,
+        desc: "Media Select Program Guide",
+    };
+
+    pub const MEDIA_SELECT_VIDEO_PHONE: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_VIDEO_PHONE",
+        id: 0x8e,
+        usage: Usage::Selector,
+        desc: "Media Select Video Phone",
+    };
+
+    pub const MEDIA_SELECT_GAMES: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_GAMES",
+        id: 0x8f,
+        usage: Usage::Selector,
+        desc: "Media Select Games",
+    };
+
+    pub const MEDIA_SELECT_MESSAGES: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_MESSAGES",
+        id: 0x90,
+        usage: Usage::Selector,
+        desc: "Media Select Messages",
+    };
+
+    pub const MEDIA_SELECT_CD: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_CD",
+        id: 0x91,
+        usage: Usage::Selector,
+        desc: "Media Select CD",
+    };
+
+    pub const MEDIA_SELECT_VCR: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_VCR",
+        id: 0x92,
+        usage: Usage::Selector,
+        desc: "Media Select VCR",
+    };
+
+    pub const MEDIA_SELECT_TUNER: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_TUNER",
+        id: 0x93,
+        usage: Usage::Selector,
+        desc: "Media Select Tuner",
+    };
+
+    pub const QUIT: PageUsage = PageUsage {
+        name: "QUIT",
+        id: 0x94,
+        usage: Usage::OneShotControl,
+        desc: "Quit",
+    };
+
+    pub const HELP: PageUsage = PageUsage {
+        name: "HELP",
+        id: 0x95,
+        usage: Usage::OnOffControl,
+        desc: "Help",
+    };
+
+    pub const MEDIA_SELECT_TAPE: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_TAPE",
+        id: 0x96,
+        usage: Usage::Selector,
+        desc: "Media Select Tape",
+    };
+
+    pub const MEDIA_SELECT_CABLE: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_CABLE",
+        id: 0x97,
+        usage: Usage::Selector,
+        desc: "Media Select Cable",
+    };
+
+    pub const MEDIA_SELECT_SATELLITE: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_SATELLITE",
+        id: 0x98,
+        usage: Usage::Selector,
+        desc: "Media Select Satellite",
+    };
+
+    pub const MEDIA_SELECT_SECURITY: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_SECURITY",
+        id: 0x99,
+        usage: Usage::Selector,
+        desc: "Media Select Security",
+    };
+
+    pub const MEDIA_SELECT_HOME: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_HOME",
+        id: 0x9a,
+        usage: Usage::Selector,
+        desc: "Media Select Home",
+    };
+
+    pub const MEDIA_SELECT_CALL: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_CALL",
+        id: 0x9b,
+        usage: Usage::Selector,
+        desc: "Media Select Call",
+    };
+
+    pub const CHANNEL_INCREMENT: PageUsage = PageUsage {
+        name: "CHANNEL_INCREMENT",
+        id: 0x9c,
+        usage: Usage::OneShotControl,
+        desc: "Channel Increment",
+    };
+
+    pub const CHANNEL_DECREMENT: PageUsage = PageUsage {
+        name: "CHANNEL_DECREMENT",
+        id: 0x9d,
+        usage: Usage::OneShotControl,
+        desc: "Channel Decrement",
+    };
+
+    pub const MEDIA_SELECT_SAP: PageUsage = PageUsage {
+        name: "MEDIA_SELECT_SAP",
+        id: 0x9e,
+        usage: Usage::Selector,
+        desc: "Media Select SAP",
+    };
+
+    pub const VCR_PLUS: PageUsage = PageUsage {
+        name: "VCR_PLUS",
+        id: 0xa0,
+        usage: Usage::OneShotControl,
+        desc: "VCR Plus",
+    };
+
+    pub const ONCE: PageUsage = PageUsage {
+        name: "ONCE",
+        id: 0xa1,
+        usage: Usage::OneShotControl,
+        desc: "Once",
+    };
+
+    pub const DAILY: PageUsage = PageUsage {
+        name: "DAILY",
+        id: 0xa2,
+        usage: Usage::OneShotControl,
+        desc: "Daily",
+    };
+
+    pub const WEEKLY: PageUsage = PageUsage {
+        name: "WEEKLY",
+        id: 0xa3,
+        usage: Usage::OneShotControl,
+        desc: "Weekly",
+    };
+
+    pub const MONTHLY: PageUsage = PageUsage {
+        name: "MONTHLY",
+        id: 0xa4,
+        usage: Usage::OneShotControl,
+        desc: "Monthly",
+    };
+
+    pub const PLAY: PageUsage = PageUsage {
+        name: "PLAY",
+        id: 0xb0,
+        usage: Usage::OnOffControl,
+        desc: "Play",
+    };
+
+    pub const PAUSE: PageUsage = PageUsage {
+        name: "PAUSE",
+        id: 0xb1,
+        usage: Usage::OnOffControl,
+        desc: "Pause",
+    };
+
+    pub const RECORD: PageUsage = PageUsage {
+        name: "RECORD",
+        id: 0xb2,
+        usage: Usage::OnOffControl,
+        desc: "Record",
+    };
+
+    pub const FAST_FORWARD: PageUsage = PageUsage {
+        name: "FAST_FORWARD",
+        id: 0xb3,
+        usage: Usage::OnOffControl,
+        desc: "Fast Forward",
+    };
+
+    pub const REWIND: PageUsage = PageUsage {
+        name: "REWIND",
+        id: 0xb4,
+        usage: Usage::OnOffControl,
+        desc: "Rewind",
+    };
+
+    pub const SCAN_NEXT_TRACK: PageUsage = PageUsage {
+        name: "SCAN_NEXT_TRACK",
+        id: 0xb5,
+        usage: Usage::OneShotControl,
+        desc: "Scan Next Track",
+    };
+
+    pub const SCAN_PREVIOUS_TRACK: PageUsage = PageUsage {
+        name: "SCAN_PREVIOUS_TRACK",
+        id: 0xb6,
+        usage: Usage::OneShotControl,
+        desc: "Scan Previous Track",
+    };
+
+    pub const STOP: PageUsage = PageUsage {
+        name: "STOP",
+        id: 0xb7,
+        usage: Usage::OneShotControl,
+        desc: "Stop",
+    };
+
+    pub const EJECT: PageUsage = PageUsage {
+        name: "EJECT",
+        id: 0xb8,
+        usage: Usage::OneShotControl,
+        desc: "Eject",
+    };
+
+    pub const RANDOM_PLAY: PageUsage = PageUsage {
+        name: "RANDOM_PLAY",
+        id: 0xb9,
+        usage: Usage::OnOffControl,
+        desc: "Random Play",
+    };
+
+    pub const SELECT_DISC: PageUsage = PageUsage {
+        name: "SELECT_DISC",
+        id: 0xba,
+        usage: Usage::NamedArray,
+        desc: "Select Disc",
+    };
+
+    pub const ENTER_DISC: PageUsage = PageUsage {
+        name: "ENTER_DISC",
+        id: 0xbb,
+        usage: Usage::MomentaryControl,
+        desc: "Enter Disc",
+    };
+
+    pub const REPEAT: PageUsage = PageUsage {
+        name: "REPEAT",
+        id: 0xbc,
+        usage: Usage::OneShotControl,
+        desc: "Repeat",
+    };
+
+    pub const TRACKING: PageUsage = PageUsage {
+        name: "TRACKING",
+        id: 0xbd,
+        usage: Usage::LinearControl,
+        desc: "Tracking",
+    };
+
+    pub const TRACK_NORMAL: PageUsage = PageUsage {
+        name: "TRACK_NORMAL",
+        id: 0xbe,
+        usage: Usage::OneShotControl,
+        desc: "Track Normal",
+    };
+
+    pub const SLOW_TRACKING: PageUsage = PageUsage {
+        name: "SLOW_TRACKING",
+        id: 0xbf,
+        usage: Usage::LinearControl,
+        desc: "Slow Tracking",
+    };
+
+    pub const FRAME_FORWARD: PageUsage = PageUsage {
+        name: "FRAME_FORWARD",
+        id: 0xc0,
+        usage: Usage::ReTriggerControl,
+        desc: "Frame Forward",
+    };
+
+    pub const FRAME_BACK: PageUsage = PageUsage {
+        name: "FRAME_BACK",
+        id: 0xc1,
+        usage: Usage::ReTriggerControl,
+        desc: "Frame Back",
+    };
+
+    pub const MARK: PageUsage = PageUsage {
+        name: "MARK",
+        id: 0xc2,
+        usage: Usage::OneShotControl,
+        desc: "Mark",
+    };
+
+    pub const CLEAR_MARK: PageUsage = PageUsage {
+        name: "CLEAR_MARK",
+        id: 0xc3,
+        usage: Usage::OneShotControl,
+        desc: "Clear Mark",
+    };
+
+    pub const REPEAT_FROM_MARK: PageUsage = PageUsage {
+        name: "REPEAT_FROM_MARK",
+        id: 0xc4,
+        usage: Usage::OnOffControl,
+        desc: "Repeat From Mark",
+    };
+
+    pub const RETURN_TO_MARK: PageUsage = PageUsage {
+        name: "RETURN_TO_MARK",
+        id: 0xc5,
+        usage: Usage::OneShotControl,
+        desc: "Return To Mark",
+    };
+
+    pub const SEARCH_MARK_FORWARD: PageUsage = PageUsage {
+        name: "SEARCH_MARK_FORWARD",
+        id: 0xc6,
+        usage: Usage::OneShotControl,
+        desc: "Search Mark Forward",
+    };
+
+    pub const SEARCH_MARK_BACKWARDS: PageUsage = PageUsage {
+        name: "SEARCH_MARK_BACKWARDS",
+        id: 0xc7,
+        usage: Usage::OneShotControl,
+        desc: "Search Mark Backwards",
+    };
+
+    pub const COUNTER_RESET: PageUsage = PageUsage {
+        name: "COUNTER_RESET",
+        id: 0xc8,
+        usage: Usage::OneShotControl,
+        desc: "Counter Reset",
+    };
+
+    pub const SHOW_COUNTER: PageUsage = PageUsage {
+        name: "SHOW_COUNTER",
+        id: 0xc9,
+        usage: Usage::OneShotControl,
+        desc: "Show Counter",
+    };
+
+    pub const TRACKING_INCREMENT: PageUsage = PageUsage {
+        name: "TRACKING_INCREMENT",
+        id: 0xca,
+        usage: Usage::ReTriggerControl,
+        desc: "Tracking Increment",
+    };
+
+    pub const TRACKING_DECREMENT: PageUsage = PageUsage {
+        name: "TRACKING_DECREMENT",
+        id: 0xcb,
+        usage: Usage::ReTriggerControl,
+        desc: "Tracking Decrement",
+    };
+
+    pub const STOP_EJECT: PageUsage = PageUsage {
+        name: "STOP_EJECT",
+        id: 0xcc,
+        usage: Usage::OneShotControl,
+        desc: "Stop/Eject",
+    };
+
+    pub const PLAY_PAUSE: PageUsage = PageUsage {
+        name: "PLAY_PAUSE",
+        id: 0xcd,
+        usage: Usage::OneShotControl,
+        desc: "Play/Pause",
+    };
+
+    pub const PLAY_SKIP: PageUsage = PageUsage {
+        name: "PLAY_SKIP",
+        id: 0xce,
+        usage: Usage::OneShotControl,
+        desc: "Play/Skip",
+    };
+
+    pub const VOLUME: PageUsage = PageUsage {
+        name: "VOLUME",
+        id: 0xe0,
+        usage: Usage::LinearControl,
+        desc: "Volume",
+    };
+
+    pub const BALANCE: PageUsage = PageUsage {
+        name: "BALANCE",
+        id: 0xe1,
+        usage: Usage::LinearControl,
+        desc: "Balance",
+    };
+
+    pub const MUTE: PageUsage = PageUsage {
+        name: "MUTE",
+        id: 0xe2,
+        usage: Usage::OnOffControl,
+        desc: "Mute",
+    };
+
+    pub const BASS: PageUsage = PageUsage {
+        name: "BASS",
+        id: 0xe3,
+        usage: Usage::LinearControl,
+        desc: "Bass",
+    };
+
+    pub const TREBLE: PageUsage = PageUsage {
+        name: "TREBLE",
+        id: 0xe4,
+        usage: Usage::LinearControl,
+        desc: "Treble",
+    };
+
+    pub const BASS_BOOST: PageUsage = PageUsage {
+        name: "BASS_BOOST",
+        id: 0xe5,
+        usage: Usage::OnOffControl,
+        desc: "Bass Boost",
+    };
+
+    pub const SURROUND_MODE: PageUsage = PageUsage {
+        name: "SURROUND_MODE",
+        id: 0xe6,
+        usage: Usage::OneShotControl,
+        desc: "Surround Mode",
+    };
+
+    pub const LOUDNESS: PageUsage = PageUsage {
+        name: "LOUDNESS",
+        id: 0xe7,
+        usage: Usage::OnOffControl,
+        desc: "Loudness",
+    };
+
+    pub const MPX: PageUsage = PageUsage {
+        name: "MPX",
+        id: 0xe8,
+        usage: Usage::OnOffControl,
+        desc: "MPX",
+    };
+
+    pub const VOLUME_INCREMENT: PageUsage = PageUsage {
+        name: "VOLUME_INCREMENT",
+        id: 0xe9,
+        usage: Usage::ReTriggerControl,
+        desc: "Volume Increment",
+    };
+
+    pub const VOLUME_DECREMENT: PageUsage = PageUsage {
+        name: "VOLUME_DECREMENT",
+        id: 0xea,
+        usage: Usage::ReTriggerControl,
+        desc: "Volume Decrement",
+    };
+
+    pub const SPEED_SELECT: PageUsage = PageUsage {
+        name: "SPEED_SELECT",
+        id: 0xf0,
+        usage: Usage::OneShotControl,
+        desc: "Speed Select",
+    };
+
+    pub const PLAYBACK_SPEED: PageUsage = PageUsage {
+        name: "PLAYBACK_SPEED",
+        id: 0xf1,
+        usage: Usage::NamedArray,
+        desc: "Playback Speed",
+    };
+
+    pub const STANDARD_PLAY: PageUsage = PageUsage {
+        name: "STANDARD_PLAY",
+        id: 0xf2,
+        usage: Usage::Selector,
+        desc: "Standard Play",
+    };
+
+    pub const LONG_PLAY: PageUsage = PageUsage {
+        name: "LONG_PLAY",
+        id: 0xf3,
+        usage: Usage::Selector,
+        desc: "Long Play",
+    };
+
+    pub const EXTENDED_PLAY: PageUsage = PageUsage {
+        name: "EXTENDED_PLAY",
+        id: 0xf4,
+        usage: Usage::Selector,
+        desc: "Extended Play",
+    };
+
+    pub const SLOW: PageUsage = PageUsage {
+        name: "SLOW",
+        id: 0xf5,
+        usage: Usage::OneShotControl,
+        desc: "Slow",
+    };
+
+    pub const FAN_ENABLE: PageUsage = PageUsage {
+        name: "FAN_ENABLE",
+        id: 0x100,
+        usage: Usage::OnOffControl,
+        desc: "Fan Enable",
+    };
+
+    pub const FAN_SPEED: PageUsage = PageUsage {
+        name: "FAN_SPEED",
+        id: 0x101,
+        usage: Usage::LinearControl,
+        desc: "Fan Speed",
+    };
+
+    pub const LIGHT_ENABLE: PageUsage = PageUsage {
+        name: "LIGHT_ENABLE",
+        id: 0x102,
+        usage: Usage::OnOffControl,
+        desc: "Light Enable",
+    };
+
+    pub const LIGHT_ILLUMINATION_LEVEL: PageUsage = PageUsage {
+        name: "LIGHT_ILLUMINATION_LEVEL",
+        id: 0x103,
+        usage: Usage::LinearControl,
+        desc: "Light Illumination Level",
+    };
+
+    pub const CLIMATE_CONTROL_ENABLE: PageUsage = PageUsage {
+        name: "CLIMATE_CONTROL_ENABLE",
+        id: 0x104,
+        usage: Usage::OnOffControl,
+        desc: "Climate Control Enable",
+    };
+
+    pub const ROOM_TEMPERATURE: PageUsage = PageUsage {
+        name: "ROOM_TEMPERATURE",
+        id: 0x105,
+        usage: Usage::LinearControl,
+        desc: "Room Temperature",
+    };
+
+    pub const SECURITY_ENABLE: PageUsage = PageUsage {
+        name: "SECURITY_ENABLE",
+        id: 0x106,
+        usage: Usage::OnOffControl,
+        desc: "Security Enable",
+    };
+
+    pub const FIRE_ALARM: PageUsage = PageUsage {
+        name: "FIRE_ALARM",
+        id: 0x107,
+        usage: Usage::OneShotControl,
+        desc: "Fire Alarm",
+    };
+
+    pub const POLICE_ALARM: PageUsage = PageUsage {
+        name: "POLICE_ALARM",
+        id: 0x108,
+        usage: Usage::OneShotControl,
+        desc: "Police Alarm",
+    };
+
+    pub const PROXIMITY: PageUsage = PageUsage {
+        name: "PROXIMITY",
+        id: 0x109,
+        usage: Usage::LinearControl,
+        desc: "Proximity",
+    };
+
+    pub const MOTION: PageUsage = PageUsage {
+        name: "MOTION",
+        id: 0x10a,
+        usage: Usage::OneShotControl,
+        desc: "Motion",
+    };
+
+    pub const DURESS_ALARM: PageUsage = PageUsage {
+        name: "DURESS_ALARM",
+        id: 0x10b,
+        usage: Usage::OneShotControl,
+        desc: "Duress Alarm",
+    };
+
+    pub const HOLDUP_ALARM: PageUsage = PageUsage {
+        name: "HOLDUP_ALARM",
+        id: 0x10c,
+        usage: Usage::OneShotControl,
+        desc: "Holdup Alarm",
+    };
+
+    pub const MEDICAL_ALARM: PageUsage = PageUsage {
+        name: "MEDICAL_ALARM",
+        id: 0x10d,
+        usage: Usage::OneShotControl,
+        desc: "Medical Alarm",
+    };
+
+    pub const BALANCE_RIGHT: PageUsage = PageUsage {
+        name: "BALANCE_RIGHT",
+        id: 0x150,
+        usage: Usage::ReTriggerControl,
+        desc: "Balance Right",
+    };
+
+    pub const BALANCE_LEFT: PageUsage = PageUsage {
+        name: "BALANCE_LEFT",
+        id: 0x151,
+        usage: Usage::ReTriggerControl,
+        desc: "Balance Left",
+    };
+
+    pub const BASS_INCREMENT: PageUsage = PageUsage {
+        name: "BASS_INCREMENT",
+        id: 0x152,
+        usage: Usage::ReTriggerControl,
+        desc: "Bass Increment",
+    };
+
+    pub const BASS_DECREMENT: PageUsage = PageUsage {
+        name: "BASS_DECREMENT",
+        id: 0x153,
+        usage: Usage::ReTriggerControl,
+        desc: "Bass Decrement",
+    };
+
+    pub const TREBLE_INCREMENT: PageUsage = PageUsage {
+        name: "TREBLE_INCREMENT",
+        id: 0x154,
+        usage: Usage::ReTriggerControl,
+        desc: "Treble Increment",
+    };
+
+    pub const TREBLE_DECREMENT: PageUsage = PageUsage {
+        name: "TREBLE_DECREMENT",
+        id: 0x155,
+        usage: Usage::ReTriggerControl,
+        desc: "Treble Decrement",
+    };
+
+    pub const SPEAKER_SYSTEM: PageUsage = PageUsage {
+        name: "SPEAKER_SYSTEM",
+        id: 0x160,
+        usage: Usage::CollectionLogical,
+        desc: "Speaker System",
+    };
+
+    pub const CHANNEL_LEFT: PageUsage = PageUsage {
+        name: "CHANNEL_LEFT",
+        id: 0x161,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Left",
+    };
+
+    pub const CHANNEL_RIGHT: PageUsage = PageUsage {
+        name: "CHANNEL_RIGHT",
+        id: 0x162,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Right",
+    };
+
+    pub const CHANNEL_CENTER: PageUsage = PageUsage {
+        name: "CHANNEL_CENTER",
+        id: 0x163,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Center",
+    };
+
+    pub const CHANNEL_FRONT: PageUsage = PageUsage {
+        name: "CHANNEL_FRONT",
+        id: 0x164,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Front",
+    };
+
+    pub const CHANNEL_CENTER_FRONT: PageUsage = PageUsage {
+        name: "CHANNEL_CENTER_FRONT",
+        id: 0x165,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Center Front",
+    };
+
+    pub const CHANNEL_SIDE: PageUsage = PageUsage {
+        name: "CHANNEL_SIDE",
+        id: 0x166,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Side",
+    };
+
+    pub const CHANNEL_SURROUND: PageUsage = PageUsage {
+        name: "CHANNEL_SURROUND",
+        id: 0x167,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Surround",
+    };
+
+    pub const CHANNEL_LOW_FREQUENCY_ENHANCEMENT: PageUsage = PageUsage {
+        name: "CHANNEL_LOW_FREQUENCY_ENHANCEMENT",
+        id: 0x168,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Low Frequency Enhancement",
+    };
+
+    pub const CHANNEL_TOP: PageUsage = PageUsage {
+        name: "CHANNEL_TOP",
+        id: 0x169,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Top",
+    };
+
+    pub const CHANNEL_UNKNOWN: PageUsage = PageUsage {
+        name: "CHANNEL_UNKNOWN",
+        id: 0x16a,
+        usage: Usage::CollectionLogical,
+        desc: "Channel Unknown",
+    };
+
+    pub const SUB_CHANNEL: PageUsage = PageUsage {
+        name: "SUB_CHANNEL",
+        id: 0x170,
+        usage: Usage::LinearControl,
+        desc: "Sub-channel",
+    };
+
+    pub const SUB_CHANNEL_INCREMENT: PageUsage = PageUsage {
+        name: "SUB_CHANNEL_INCREMENT",
+        id: 0x171,
+        usage: Usage::OneShotControl,
+        desc: "Sub-channel Increment",
+    };
+
+    pub const SUB_CHANNEL_DECREMENT: PageUsage = PageUsage {
+        name: "SUB_CHANNEL_DECREMENT",
+        id: 0x172,
+        usage: Usage::OneShotControl,
+        desc: "Sub-channel Decrement",
+    };
+
+    pub const ALTERNATE_AUDIO_INCREMENT: PageUsage = PageUsage {
+        name: "ALTERNATE_AUDIO_INCREMENT",
+        id: 0x173,
+        usage: Usage::OneShotControl,
+        desc: "Alternate Audio Increment",
+    };
+
+    pub const ALTERNATE_AUDIO_DECREMENT: PageUsage = PageUsage {
+        name: "ALTERNATE_AUDIO_DECREMENT",
+        id: 0x174,
+        usage: Usage::OneShotControl,
+        desc: "Alternate Audio Decrement",
+    };
+
+    pub const APPLICATION_LAUNCH_BUTTONS: PageUsage = PageUsage {
+        name: "APPLICATION_LAUNCH_BUTTONS",
+        id: 0x180,
+        usage: Usage::NamedArray,
+        desc: "Application Launch Buttons",
+    };
+
+    pub const AL_LAUNCH_BUTTON_CONFIGURATION_TOOL: PageUsage = PageUsage {
+        name: "AL_LAUNCH_BUTTON_CONFIGURATION_TOOL",
+        id: 0x181,
+        usage: Usage::Selector,
+        desc: "AL Launch Button Configuration Tool",
+    };
+
+    pub const AL_PROGRAMMABLE_BUTTON_CONFIGURATION: PageUsage = PageUsage {
+        name: "AL_PROGRAMMABLE_BUTTON_CONFIGURATION",
+        id: 0x182,
+        usage: Usage::Selector,
+        desc: "AL Programmable Button Configuration",
+    };
+
+    pub const AL_CONSUMER_CONTROL_CONFIGURATION: PageUsage = PageUsage {
+        name: "AL_CONSUMER_CONTROL_CONFIGURATION",
+        id: 0x183,
+        usage: Usage::Selector,
+        desc: "AL Consumer Control Configuration",
+    };
+
+    pub const AL_WORD_PROCESSOR: PageUsage = PageUsage {
+        name: "AL_WORD_PROCESSOR",
+        id: 0x184,
+        usage: Usage::Selector,
+        desc: "AL Word Processor",
+    };
+
+    pub const AL_TEXT_EDITOR: PageUsage = PageUsage {
+        name: "AL_TEXT_EDITOR",
+        id: 0x185,
+        usage: Usage::Selector,
+        desc: "AL Text Editor",
+    };
+
+    pub const AL_SPREADSHEET: PageUsage = PageUsage {
+        name: "AL_SPREADSHEET",
+        id: 0x186,
+        usage: Usage::Selector,
+        desc: "AL Spreadsheet",
+    };
+
+    pub const AL_GRAPHICS_EDITOR: PageUsage = PageUsage {
+        name: "AL_GRAPHICS_EDITOR",
+        id: 0x187,
+        usage: Usage::Selector,
+        desc: "AL Graphics Editor",
+    };
+
+    pub const AL_PRESENTATION_APP: PageUsage = PageUsage {
+        name: "AL_PRESENTATION_APP",
+        id: 0x188,
+        usage: Usage::Selector,
+        desc: "AL Presentation App",
+    };
+
+    pub const AL_DATABASE_APP: PageUsage = PageUsage {
+        name: "AL_DATABASE_APP",
+        id: 0x189,
+        usage: Usage::Selector,
+        desc: "AL Database App",
+    };
+
+    pub const AL_EMAIL_READER: PageUsage = PageUsage {
+        name: "AL_EMAIL_READER",
+        id: 0x18a,
+        usage: Usage::Selector,
+        desc: "AL Email Reader",
+    };
+
+    pub const AL_NEWSREADER: PageUsage = PageUsage {
+        name: "AL_NEWSREADER",
+        id: 0x18b,
+        usage: Usage::Selector,
+        desc: "AL Newsreader",
+    };
+
+    pub const AL_VOICEMAIL: PageUsage = PageUsage {
+        name: "AL_VOICEMAIL",
+        id: 0x18c,
+        usage: Usage::Selector,
+        desc: "AL Voicemail",
+    };
+
+    pub const AL_CONTACTS_ADDRESS_BOOK: PageUsage = PageUsage {
+        name: "AL_CONTACTS_ADDRESS_BOOK",
+        id: 0x18d,
+        usage: Usage::Selector,
+        desc: "AL Contacts/Address Book",
+    };
+
+    pub const AL_CALENDAR_SCHEDULE: PageUsage = PageUsage {
+        name: "AL_CALENDAR_SCHEDULE",
+        id: 0x18e,
+        usage: Usage::Selector,
+        desc: "AL Calendar/Schedule",
+    };
+
+    pub const AL_TASK_PROJECT_MANAGER: PageUsage = PageUsage {
+        name: "AL_TASK_PROJECT_MANAGER",
+        id: 0x18f,
+        usage: Usage::Selector,
+        desc: "AL Task/Project Manager",
+    };
+
+    pub const AL_LOG_JOURNAL_TIMECARD: PageUsage = PageUsage {
+        name: "AL_LOG_JOURNAL_TIMECARD",
+        id: 0x190,
+        usage: Usage::Selector,
+        desc: "AL Log/Journal/Timecard",
+    };
+
+    pub const AL_CHECKBOOK_FINANCE: PageUsage = PageUsage {
+        name: "AL_CHECKBOOK_FINANCE",
+        id: 0x191,
+        usage: Usage::Selector,
+        desc: "AL Checkbook/Finance",
+    };
+
+    pub const AL_CALCULATOR: PageUsage = PageUsage {
+        name: "AL_CALCULATOR",
+        id: 0x192,
+        usage: Usage::Selector,
+        desc: "AL Calculator",
+    };
+
+    pub const AL_A_V_CAPTURE_PLAYBACK: PageUsage = PageUsage {
+        name: "AL_A_V_CAPTURE_PLAYBACK",
+        id: 0x193,
+        usage: Usage::Selector,
+        desc: "AL A/V Capture/Playback",
+    };
+
+    pub const AL_LOCAL_MACHINE_BROWSER: PageUsage = PageUsage {
+        name: "AL_LOCAL_MACHINE_BROWSER",
+        id: 0x194,
+        usage: Usage::Selector,
+        desc: "AL Local Machine Browser",
+    };
+
+    pub const AL_LAN_WAN_BROWSER: PageUsage = PageUsage {
+        name: "AL_LAN_WAN_BROWSER",
+        id: 0x195,
+        usage: Usage::Selector,
+        desc: "AL LAN/WAN Browser",
+    };
+
+    pub const AL_INTERNET_BROWSER: PageUsage = PageUsage {
+        name: "AL_INTERNET_BROWSER",
+        id: 0x196,
+        usage: Usage::Selector,
+        desc: "AL Internet Browser",
+    };
+
+    pub const AL_REMOTE_NETWORKING_ISP_CONNECT: PageUsage = PageUsage {
+        name: "AL_REMOTE_NETWORKING_ISP_CONNECT",
+        id: 0x197,
+        usage: Usage::Selector,
+        desc: "AL Remote Networking/ISP Connect",
+    };
+
+    pub const AL_NETWORK_CONFERENCE: PageUsage = PageUsage {
+        name: "AL_NETWORK_CONFERENCE",
+        id: 0x198,
+        usage: Usage::Selector,
+        desc: "AL Network Conference",
+    };
+
+    pub const AL_NETWORK_CHAT: PageUsage = PageUsage {
+        name: "AL_NETWORK_CHAT",
+        id: 0x199,
+        usage: Usage::Selector,
+        desc: "AL Network Chat",
+    };
+
+    pub const AL_TELEPHONY_DIALER: PageUsage = PageUsage {
+        name: "AL_TELEPHONY_DIALER",
+        id: 0x19a,
+        usage: Usage::Selector,
+        desc: "AL Telephony/Dialer",
+    };
+
+    pub const AL_LOGON: PageUsage = PageUsage {
+        name: "AL_LOGON",
+        id: 0x19b,
+        usage: Usage::Selector,
+        desc: "AL Logon",
+    };
+
+    pub const AL_LOGOFF: PageUsage = PageUsage {
+        name: "AL_LOGOFF",
+        id: 0x19c,
+        usage: Usage::Selector,
+        desc: "AL Logoff",
+    };
+
+    pub const AL_LOGON_LOGOFF: PageUsage = PageUsage {
+        name: "AL_LOGON_LOGOFF",
+        id: 0x19d,
+        usage: Usage::Selector,
+        desc: "AL Logon/Logoff",
+    };
+
+    pub const AL_TERMINAL_LOCK_SCREENSAVER: PageUsage = PageUsage {
+        name: "AL_TERMINAL_LOCK_SCREENSAVER",
+        id: 0x19e,
+        usage: Usage::Selector,
+        desc: "AL Terminal Lock/Screensaver",
+    };
+
+    pub const AL_CONTROL_PANEL: PageUsage = PageUsage {
+        name: "AL_CONTROL_PANEL",
+        id: 0x19f,
+        usage: Usage::Selector,
+        desc: "AL Control Panel",
+    };
+
+    pub const AL_COMMAND_LINE_PROCESSOR_RUN: PageUsage = PageUsage {
+        name: "AL_COMMAND_LINE_PROCESSOR_RUN",
+        id: 0x1a0,
+        usage: Usage::Selector,
+        desc: "AL Command Line Processor/Run",
+    };
+
+    pub const AL_PROCESS_TASK_MANAGER: PageUsage = PageUsage {
+        name: "AL_PROCESS_TASK_MANAGER",
+        id: 0x1a1,
+        usage: Usage::Selector,
+        desc: "AL Process/Task Manager",
+    };
+
+    pub const AL_SELECT_TASK_APPLICATION: PageUsage = PageUsage {
+        name: "AL_SELECT_TASK_APPLICATION",
+        id: 0x1a2,
+        usage: Usage::Selector,
+        desc: "AL Select Task/Application",
+    };
+
+    pub const AL_NEXT_TASK_APPLICATION: PageUsage = PageUsage {
+        name: "AL_NEXT_TASK_APPLICATION",
+        id: 0x1a3,
+        usage: Usage::Selector,
+        desc: "AL Next Task/Application",
+    };
+
+    pub const AL_PREVIOUS_TASK_APPLICATION: PageUsage = PageUsage {
+        name: "AL_PREVIOUS_TASK_APPLICATION",
+        id: 0x1a4,
+        usage: Usage::Selector,
+        desc: "AL Previous Task/Application",
+    };
+
+    pub const AL_PREEMPTIVE_HALT_TASK_APPLICATION: PageUsage = PageUsage {
+        name: "AL_PREEMPTIVE_HALT_TASK_APPLICATION",
+        id: 0x1a5,
+        usage: Usage::Selector,
+        desc: "AL Preemptive Halt Task/Application",
+    };
+
+    pub const AL_INTEGRATED_HELP_CENTER: PageUsage = PageUsage {
+        name: "AL_INTEGRATED_HELP_CENTER",
+        id: 0x1a6,
+        usage: Usage::Selector,
+        desc: "AL Integrated Help Center",
+    };
+
+    pub const AL_DOCUMENTS: PageUsage = PageUsage {
+        name: "AL_DOCUMENTS",
+        id: 0x1a7,
+        usage: Usage::Selector,
+        desc: "AL Documents",
+    };
+
+    pub const AL_THESAURUS: PageUsage = PageUsage {
+        name: "AL_THESAURUS",
+        id: 0x1a8,
+        usage: Usage::Selector,
+        desc: "AL Thesaurus",
+    };
+
+    pub const AL_DICTIONARY: PageUsage = PageUsage {
+        name: "AL_DICTIONARY",
+        id: 0x1a9,
+        usage: Usage::Selector,
+        desc: "AL Dictionary",
+    };
+
+    pub const AL_DESKTOP: PageUsage = PageUsage {
+        name: "AL_DESKTOP",
+        id: 0x1aa,
+        usage: Usage::Selector,
+        desc: "AL Desktop",
+    };
+
+    pub const AL_SPELL_CHECK: PageUsage = PageUsage {
+        name: "AL_SPELL_CHECK",
+        id: 0x1ab,
+        usage: Usage::Selector,
+        desc: "AL Spell Check",
+    };
+
+    pub const AL_GRAMMAR_CHECK: PageUsage = PageUsage {
+        name: "AL_GRAMMAR_CHECK",
+        id: 0x1ac,
+        usage: Usage::Selector,
+        desc: "AL Grammar Check",
+    };
+
+    pub const AL_WIRELESS_STATUS: PageUsage = PageUsage {
+        name: "AL_WIRELESS_STATUS",
+        id: 0x1ad,
+        usage: Usage::Selector,
+        desc: "AL Wireless Status",
+    };
+
+    pub const AL_KEYBOARD_LAYOUT: PageUsage = PageUsage {
+        name: "AL_KEYBOARD_LAYOUT",
+        id: 0x1ae,
+        usage: Usage::Selector,
+        desc: "AL Keyboard Layout",
+    };
+
+    pub const AL_VIRUS_PROTECTION: PageUsage = PageUsage {
+        name: "AL_VIRUS_PROTECTION",
+        id: 0x1af,
+        usage: Usage::Selector,
+        desc: "AL Virus Protection",
+    };
+
+    pub const AL_ENCRYPTION: PageUsage = PageUsage {
+        name: "AL_ENCRYPTION",
+        id: 0x1b0,
+        usage: Usage::Selector,
+        desc: "AL Encryption",
+    };
+
+    pub const AL_SCREEN_SAVER: PageUsage = PageUsage {
+        name: "AL_SCREEN_SAVER",
+        id: 0x1b1,
+        usage: Usage::Selector,
+        desc: "AL Screen Saver",
+    };
+
+    pub const AL_ALARMS: PageUsage = PageUsage {
+        name: "AL_ALARMS",
+        id: 0x1b2,
+        usage: Usage::Selector,
+        desc: "AL Alarms",
+    };
+
+    pub const AL_CLOCK: PageUsage = PageUsage {
+        name: "AL_CLOCK",
+        id: 0x1b3,
+        usage: Usage::Selector,
+        desc: "AL Clock",
+    };
+
+    pub const AL_FILE_BROWSER: PageUsage = PageUsage {
+        name: "AL_FILE_BROWSER",
+        id: 0x1b4,
+        usage: Usage::Selector,
+        desc: "AL File Browser",
+    };
+
+    pub const AL_POWER_STATUS: PageUsage = PageUsage {
+        name: "AL_POWER_STATUS",
+        id: 0x1b5,
+        usage: Usage::Selector,
+        desc: "AL Power Status",
+    };
+
+    pub const AL_IMAGE_BROWSER: PageUsage = PageUsage {
+        name: "AL_IMAGE_BROWSER",
+        id: 0x1b6,
+        usage: Usage::Selector,
+        desc: "AL Image Browser",
+    };
+
+    pub const AL_AUDIO_BROWSER: PageUsage = PageUsage {
+        name: "AL_AUDIO_BROWSER",
+        id: 0x1b7,
+        usage: Usage::Selector,
+        desc: "AL Audio Browser",
+    };
+
+    pub const AL_MOVIE_BROWSER: PageUsage = PageUsage {
+        name: "AL_MOVIE_BROWSER",
+        id: 0x1b8,
+        usage: Usage::Selector,
+        desc: "AL Movie Browser",
+    };
+
+    pub const AL_DIGITAL_RIGHTS_MANAGER: PageUsage = PageUsage {
+        name: "AL_DIGITAL_RIGHTS_MANAGER",
+        id: 0x1b9,
+        usage: Usage::Selector,
+        desc: "AL Digital Rights Manager",
+    };
+
+    pub const AL_DIGITAL_WALLET: PageUsage = PageUsage {
+        name: "AL_DIGITAL_WALLET",
+        id: 0x1ba,
+        usage: Usage::Selector,
+        desc: "AL Digital Wallet",
+    };
+
+    pub const AL_INSTANT_MESSAGING: PageUsage = PageUsage {
+        name: "AL_INSTANT_MESSAGING",
+        id: 0x1bc,
+        usage: Usage::Selector,
+        desc: "AL Instant Messaging",
+    };
+
+    pub const AL_OEM_FEATURES_TIPS_TUTORIAL_BROWSER: PageUsage = PageUsage {
+        name: "AL_OEM_FEATURES_TIPS_TUTORIAL_BROWSER",
+        id: 0x1bd,
+        usage: Usage::Selector,
+        desc: "AL OEM Features/ Tips/Tutorial Browser",
+    };
+
+    pub const AL_OEM_HELP: PageUsage = PageUsage {
+        name: "AL_OEM_HELP",
+        id: 0x1be,
+        usage: Usage::Selector,
+        desc: "AL OEM Help",
+    };
+
+    pub const AL_ONLINE_COMMUNITY: PageUsage = PageUsage {
+        name: "AL_ONLINE_COMMUNITY",
+        id: 0x1bf,
+        usage: Usage::Selector,
+        desc: "AL Online Community",
+    };
+
+    pub const AL_ENTERTAINMENT_CONTENT_BROWSER: PageUsage = PageUsage {
+        name: "AL_ENTERTAINMENT_CONTENT_BROWSER",
+        id: 0x1c0,
+        usage: Usage::Selector,
+        desc: "AL Entertainment Content Browser",
+    };
+
+    pub const AL_ONLINE_SHOPPING_BROWSER: PageUsage = PageUsage {
+        name: "AL_ONLINE_SHOPPING_BROWSER",
+        id: 0x1c1,
+        usage: Usage::Selector,
+        desc: "AL Online Shopping Browser",
+    };
+
+    pub const AL_SMARTCARD_INFORMATION_HELP: PageUsage = PageUsage {
+        name: "AL_SMARTCARD_INFORMATION_HELP",
+        id: 0x1c2,
+        usage: Usage::Selector,
+        desc: "AL SmartCard Information/Help",
+    };
+
+    pub const AL_MARKET_MONITOR_FINANCE_BROWSER: PageUsage = PageUsage {
+        name: "AL_MARKET_MONITOR_FINANCE_BROWSER",
+        id: 0x1c3,
+        usage: Usage::Selector,
+        desc: "AL Market Monitor/Finance Browser",
+    };
+
+    pub const AL_CUSTOMIZED_CORPORATE_NEWS_BROWSER: PageUsage = PageUsage {
+        name: "AL_CUSTOMIZED_CORPORATE_NEWS_BROWSER",
+        id: 0x1c4,
+        usage: Usage::Selector,
+        desc: "AL Customized Corporate News Browser",
+    };
+
+    pub const AL_ONLINE_ACTIVITY_BROWSER: PageUsage = PageUsage {
+        name: "AL_ONLINE_ACTIVITY_BROWSER",
+        id: 0x1c5,
+        usage: Usage::Selector,
+        desc: "AL Online Activity Browser",
+    };
+
+    pub const AL_RESEARCH_SEARCH_BROWSER: PageUsage = PageUsage {
+        name: "AL_RESEARCH_SEARCH_BROWSER",
+        id: 0x1c6,
+        usage: Usage::Selector,
+        desc: "AL Research/Search Browser",
+    };
+
+    pub const AL_AUDIO_PLAYER: PageUsage = PageUsage {
+        name: "AL_AUDIO_PLAYER",
+        id: 0x1c7,
+        usage: Usage::Selector,
+        desc: "AL Audio Player",
+    };
+
+    pub const GENERIC_GUI_APPLICATION_CONTROLS: PageUsage = PageUsage {
+        name: "GENERIC_GUI_APPLICATION_CONTROLS",
+        id: 0x200,
+        usage: Usage::NamedArray,
+        desc: "Generic GUI Application Controls",
+    };
+
+    pub const AC_NEW: PageUsage = PageUsage {
+        name: "AC_NEW",
+        id: 0x201,
+        usage: Usage::Selector,
+        desc: "AC New",
+    };
+
+    pub const AC_OPEN: PageUsage = PageUsage {
+        name: "AC_OPEN",
+        id: 0x202,
+        usage: Usage::Selector,
+        desc: "AC Open",
+    };
+
+    pub const AC_CLOSE: PageUsage = PageUsage {
+        name: "AC_CLOSE",
+        id: 0x203,
+        usage: Usage::Selector,
+        desc: "AC Close",
+    };
+
+    pub const AC_EXIT: PageUsage = PageUsage {
+        name: "AC_EXIT",
+        id: 0x204,
+        usage: Usage::Selector,
+        desc: "AC Exit",
+    };
+
+    pub const AC_MAXIMIZE: PageUsage = PageUsage {
+        name: "AC_MAXIMIZE",
+        id: 0x205,
+        usage: Usage::Selector,
+        desc: "AC Maximize",
+    };
+
+    pub const AC_MINIMIZE: PageUsage = PageUsage {
+        name: "AC_MINIMIZE",
+        id: 0x206,
+        usage: Usage::Selector,
+        desc: "AC Minimize",
+    };
+
+    pub const AC_SAVE: PageUsage = PageUsage {
+        name: "AC_SAVE",
+        id: 0x207,
+        usage: Usage::Selector,
+        desc: "AC Save",
+    };
+
+    pub const AC_PRINT: PageUsage = PageUsage {
+        name: "AC_PRINT",
+        id: 0x208,
+        usage: Usage::Selector,
+        desc: "AC Print",
+    };
+
+    pub const AC_PROPERTIES: PageUsage = PageUsage {
+        name: "AC_PROPERTIES",
+        id: 0x209,
+        usage: Usage::Selector,
+        desc: "AC Properties",
+    };
+
+    pub const AC_UNDO: PageUsage = PageUsage {
+        name: "AC_UNDO",
+        id: 0x21a,
+        usage: Usage::Selector,
+        desc: "AC Undo",
+    };
+
+    pub const AC_COPY: PageUsage = PageUsage {
+        name: "AC_COPY",
+        id: 0x21b,
+        usage: Usage::Selector,
+        desc: "AC Copy",
+    };
+
+    pub const AC_CUT: PageUsage = PageUsage {
+        name: "AC_CUT",
+        id: 0x21c,
+        usage: Usage::Selector,
+        desc: "AC Cut",
+    };
+
+    pub const AC_PASTE: PageUsage = PageUsage {
+        name: "AC_PASTE",
+        id: 0x21d,
+        usage: Usage::Selector,
+        desc: "AC Paste",
+    };
+
+    pub const AC_SELECT_ALL: PageUsage = PageUsage {
+        name: "AC_SELECT_ALL",
+        id: 0x21e,
+        usage: Usage::Selector,
+        desc: "AC Select All",
+    };
+
+    pub const AC_FIND: PageUsage = PageUsage {
+        name: "AC_FIND",
+        id: 0x21f,
+        usage: Usage::Selector,
+        desc: "AC Find",
+    };
+
+    pub const AC_FIND_AND_REPLACE: PageUsage = PageUsage {
+        name: "AC_FIND_AND_REPLACE",
+        id: 0x220,
+        usage: Usage::Selector,
+        desc: "AC Find and Replace",
+    };
+
+    pub const AC_SEARCH: PageUsage = PageUsage {
+        name: "AC_SEARCH",
+        id: 0x221,
+        usage: Usage::Selector,
+        desc: "AC Search",
+    };
+
+    pub const AC_GO_TO: PageUsage = PageUsage {
+        name: "AC_GO_TO",
+        id: 0x222,
+        usage: Usage::Selector,
+        desc: "AC Go To",
+    };
+
+    pub const AC_HOME: PageUsage = PageUsage {
+        name: "AC_HOME",
+        id: 0x223,
+        usage: Usage::Selector,
+        desc: "AC Home",
+    };
+
+    pub const AC_BACK: PageUsage = PageUsage {
+        name: "AC_BACK",
+        id: 0x224,
+        usage: Usage::Selector,
+        desc: "AC Back",
+    };
+
+    pub const AC_FORWARD: PageUsage = PageUsage {
+        name: "AC_FORWARD",
+        id: 0x225,
+        usage: Usage::Selector,
+        desc: "AC Forward",
+    };
+
+    pub const AC_STOP: PageUsage = PageUsage {
+        name: "AC_STOP",
+        id: 0x226,
+        usage: Usage::Selector,
+        desc: "AC Stop",
+    };
+
+    pub const AC_REFRESH: PageUsage = PageUsage {
+        name: "AC_REFRESH",
+        id: 0x227,
+        usage: Usage::Selector,
+        desc: "AC Refresh",
+    };
+
+    pub const AC_PREVIOUS_LINK: PageUsage = PageUsage {
+        name: "AC_PREVIOUS_LINK",
+        id: 0x228,
+        usage: Usage::Selector,
+        desc: "AC Previous Link",
+    };
+
+    pub const AC_NEXT_LINK: PageUsage = PageUsage {
+        name: "AC_NEXT_LINK",
+        id: 0x229,
+        usage: Usage::Selector,
+        desc: "AC Next Link",
+    };
+
+    pub const AC_BOOKMARKS: PageUsage = PageUsage {
+        name: "AC_BOOKMARKS",
+        id: 0x22a,
+        usage: Usage::Selector,
+        desc: "AC Bookmarks",
+    };
+
+    pub const AC_HISTORY: PageUsage = PageUsage {
+        name: "AC_HISTORY",
+        id: 0x22b,
+        usage: Usage::Selector,
+        desc: "AC History",
+    };
+
+    pub const AC_SUBSCRIPTIONS: PageUsage = PageUsage {
+        name: "AC_SUBSCRIPTIONS",
+        id: 0x22c,
+        usage: Usage::Selector,
+        desc: "AC Subscriptions",
+    };
+
+    pub const AC_ZOOM_IN: PageUsage = PageUsage {
+        name: "AC_ZOOM_IN",
+        id: 0x22d,
+        usage: Usage::Selector,
+        desc: "AC Zoom In",
+    };
+
+    pub const AC_ZOOM_OUT: PageUsage = PageUsage {
+        name: "AC_ZOOM_OUT",
+        id: 0x22e,
+        usage: Usage::Selector,
+        desc: "AC Zoom Out",
+    };
+
+    pub const AC_ZOOM: PageUsage = PageUsage {
+        name: "AC_ZOOM",
+        id: 0x22f,
+        usage: Usage::LinearControl,
+        desc: "AC Zoom",
+    };
+
+    pub const AC_FULL_SCREEN_VIEW: PageUsage = PageUsage {
+        name: "AC_FULL_SCREEN_VIEW",
+        id: 0x230,
+        usage: Usage::Selector,
+        desc: "AC Full Screen View",
+    };
+
+    pub const AC_NORMAL_VIEW: PageUsage = PageUsage {
+        name: "AC_NORMAL_VIEW",
+        id: 0x231,
+        usage: Usage::Selector,
+        desc: "AC Normal View",
+    };
+
+    pub const AC_VIEW_TOGGLE: PageUsage = PageUsage {
+        name: "AC_VIEW_TOGGLE",
+        id: 0x232,
+        usage: Usage::Selector,
+        desc: "AC View Toggle",
+    };
+
+    pub const AC_SCROLL_UP: PageUsage = PageUsage {
+        name: "AC_SCROLL_UP",
+        id: 0x233,
+        usage: Usage::Selector,
+        desc: "AC Scroll Up",
+    };
+
+    pub const AC_SCROLL_DOWN: PageUsage = PageUsage {
+        name: "AC_SCROLL_DOWN",
+        id: 0x234,
+        usage: Usage::Selector,
+        desc: "AC Scroll Down",
+    };
+
+    pub const AC_SCROLL: PageUsage = PageUsage {
+        name: "AC_SCROLL",
+        id: 0x235,
+        usage: Usage::LinearControl,
+        desc: "AC Scroll",
+    };
+
+    pub const AC_PAN_LEFT: PageUsage = PageUsage {
+        name: "AC_PAN_LEFT",
+        id: 0x236,
+        usage: Usage::Selector,
+        desc: "AC Pan Left",
+    };
+
+    pub const AC_PAN_RIGHT: PageUsage = PageUsage {
+        name: "AC_PAN_RIGHT",
+        id: 0x237,
+        usage: Usage::Selector,
+        desc: "AC Pan Right",
+    };
+
+    pub const AC_PAN: PageUsage = PageUsage {
+        name: "AC_PAN",
+        id: 0x238,
+        usage: Usage::LinearControl,
+        desc: "AC Pan",
+    };
+
+    pub const AC_NEW_WINDOW: PageUsage = PageUsage {
+        name: "AC_NEW_WINDOW",
+        id: 0x239,
+        usage: Usage::Selector,
+        desc: "AC New Window",
+    };
+
+    pub const AC_TILE_HORIZONTALLY: PageUsage = PageUsage {
+        name: "AC_TILE_HORIZONTALLY",
+        id: 0x23a,
+        usage: Usage::Selector,
+        desc: "AC Tile Horizontally",
+    };
+
+    pub const AC_TILE_VERTICALLY: PageUsage = PageUsage {
+        name: "AC_TILE_VERTICALLY",
+        id: 0x23b,
+        usage: Usage::Selector,
+        desc: "AC Tile Vertically",
+    };
+
+    pub const AC_FORMAT: PageUsage = PageUsage {
+        name: "AC_FORMAT",
+        id: 0x23c,
+        usage: Usage::Selector,
+        desc: "AC Format",
+    };
+
+    pub const AC_EDIT: PageUsage = PageUsage {
+        name: "AC_EDIT",
+        id: 0x23d,
+        usage: Usage::Selector,
+        desc: "AC Edit",
+    };
+
+    pub const AC_BOLD: PageUsage = PageUsage {
+        name: "AC_BOLD",
+        id: 0x23e,
+        usage: Usage::Selector,
+        desc: "AC Bold",
+    };
+
+    pub const AC_ITALICS: PageUsage = PageUsage {
+        name: "AC_ITALICS",
+        id: 0x23f,
+        usage: Usage::Selector,
+        desc: "AC Italics",
+    };
+
+    pub const AC_UNDERLINE: PageUsage = PageUsage {
+        name: "AC_UNDERLINE",
+        id: 0x240,
+        usage: Usage::Selector,
+        desc: "AC Underline",
+    };
+
+    pub const AC_STRIKETHROUGH: PageUsage = PageUsage {
+        name: "AC_STRIKETHROUGH",
+        id: 0x241,
+        usage: Usage::Selector,
+        desc: "AC Strikethrough",
+    };
+
+    pub const AC_SUBSCRIPT: PageUsage = PageUsage {
+        name: "AC_SUBSCRIPT",
+        id: 0x242,
+        usage: Usage::Selector,
+        desc: "AC Subscript",
+    };
+
+    pub const AC_SUPERSCRIPT: PageUsage = PageUsage {
+        name: "AC_SUPERSCRIPT",
+        id: 0x243,
+        usage: Usage::Selector,
+        desc: "AC Superscript",
+    };
+
+    pub const AC_ALL_CAPS: PageUsage = PageUsage {
+        name: "AC_ALL_CAPS",
+        id: 0x244,
+        usage: Usage::Selector,
+        desc: "AC All Caps",
+    };
+
+    pub const AC_ROTATE: PageUsage = PageUsage {
+        name: "AC_ROTATE",
+        id: 0x245,
+        usage: Usage::Selector,
+        desc: "AC Rotate",
+    };
+
+    pub const AC_RESIZE: PageUsage = PageUsage {
+        name: "AC_RESIZE",
+        id: 0x246,
+        usage: Usage::Selector,
+        desc: "AC Resize",
+    };
+
+    pub const AC_FLIP_HORIZONTAL: PageUsage = PageUsage {
+        name: "AC_FLIP_HORIZONTAL",
+        id: 0x247,
+        usage: Usage::Selector,
+        desc: "AC Flip horizontal",
+    };
+
+    pub const AC_FLIP_VERTICAL: PageUsage = PageUsage {
+        name: "AC_FLIP_VERTICAL",
+        id: 0x248,
+        usage: Usage::Selector,
+        desc: "AC Flip Vertical",
+    };
+
+    pub const AC_MIRROR_HORIZONTAL: PageUsage = PageUsage {
+        name: "AC_MIRROR_HORIZONTAL",
+        id: 0x249,
+        usage: Usage::Selector,
+        desc: "AC Mirror Horizontal",
+    };
+
+    pub const AC_MIRROR_VERTICAL: PageUsage = PageUsage {
+        name: "AC_MIRROR_VERTICAL",
+        id: 0x24a,
+        usage: Usage::Selector,
+        desc: "AC Mirror Vertical",
+    };
+
+    pub const AC_FONT_SELECT: PageUsage = PageUsage {
+        name: "AC_FONT_SELECT",
+        id: 0x24b,
+        usage: Usage::Selector,
+        desc: "AC Font Select",
+    };
+
+    pub const AC_FONT_COLOR: PageUsage = PageUsage {
+        name: "AC_FONT_COLOR",
+        id: 0x24c,
+        usage: Usage::Selector,
+        desc: "AC Font Color",
+    };
+
+    pub const AC_FONT_SIZE: PageUsage = PageUsage {
+        name: "AC_FONT_SIZE",
+        id: 0x24d,
+        usage: Usage::Selector,
+        desc: "AC Font Size",
+    };
+
+    pub const AC_JUSTIFY_LEFT: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_LEFT",
+        id: 0x24e,
+        usage: Usage::Selector,
+        desc: "AC Justify Left",
+    };
+
+    pub const AC_JUSTIFY_CENTER_H: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_CENTER_H",
+        id: 0x24f,
+        usage: Usage::Selector,
+        desc: "AC Justify Center H",
+    };
+
+    pub const AC_JUSTIFY_RIGHT: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_RIGHT",
+        id: 0x250,
+        usage: Usage::Selector,
+        desc: "AC Justify Right",
+    };
+
+    pub const AC_JUSTIFY_BLOCK_H: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_BLOCK_H",
+        id: 0x251,
+        usage: Usage::Selector,
+        desc: "AC Justify Block H",
+    };
+
+    pub const AC_JUSTIFY_TOP: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_TOP",
+        id: 0x252,
+        usage: Usage::Selector,
+        desc: "AC Justify Top",
+    };
+
+    pub const AC_JUSTIFY_CENTER_V: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_CENTER_V",
+        id: 0x253,
+        usage: Usage::Selector,
+        desc: "AC Justify Center V",
+    };
+
+    pub const AC_JUSTIFY_BOTTOM: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_BOTTOM",
+        id: 0x254,
+        usage: Usage::Selector,
+        desc: "AC Justify Bottom",
+    };
+
+    pub const AC_JUSTIFY_BLOCK_V: PageUsage = PageUsage {
+        name: "AC_JUSTIFY_BLOCK_V",
+        id: 0x255,
+        usage: Usage::Selector,
+        desc: "AC Justify Block V",
+    };
+
+    pub const AC_INDENT_DECREASE: PageUsage = PageUsage {
+        name: "AC_INDENT_DECREASE",
+        id: 0x256,
+        usage: Usage::Selector,
+        desc: "AC Indent Decrease",
+    };
+
+    pub const AC_INDENT_INCREASE: PageUsage = PageUsage {
+        name: "AC_INDENT_INCREASE",
+        id: 0x257,
+        usage: Usage::Selector,
+        desc: "AC Indent Increase",
+    };
+
+    pub const AC_NUMBERED_LIST: PageUsage = PageUsage {
+        name: "AC_NUMBERED_LIST",
+        id: 0x258,
+        usage: Usage::Selector,
+        desc: "AC Numbered List",
+    };
+
+    pub const AC_RESTART_NUMBERING: PageUsage = PageUsage {
+        name: "AC_RESTART_NUMBERING",
+        id: 0x259,
+        usage: Usage::Selector,
+        desc: "AC Restart Numbering",
+    };
+
+    pub const AC_BULLETED_LIST: PageUsage = PageUsage {
+        name: "AC_BULLETED_LIST",
+        id: 0x25a,
+        usage: Usage::Selector,
+        desc: "AC Bulleted List",
+    };
+
+    pub const AC_PROMOTE: PageUsage = PageUsage {
+        name: "AC_PROMOTE",
+        id: 0x25b,
+        usage: Usage::Selector,
+        desc: "AC Promote",
+    };
+
+    pub const AC_DEMOTE: PageUsage = PageUsage {
+        name: "AC_DEMOTE",
+        id: 0x25c,
+        usage: Usage::Selector,
+        desc: "AC Demote",
+    };
+
+    pub const AC_YES: PageUsage = PageUsage {
+        name: "AC_YES",
+        id: 0x25d,
+        usage: Usage::Selector,
+        desc: "AC Yes",
+    };
+
+    pub const AC_NO: PageUsage = PageUsage {
+        name: "AC_NO",
+        id: 0x25e,
+        usage: Usage::Selector,
+        desc: "AC No",
+    };
+
+    pub const AC_CANCEL: PageUsage = PageUsage {
+        name: "AC_CANCEL",
+        id: 0x25f,
+        usage: Usage::Selector,
+        desc: "AC Cancel",
+    };
+
+    pub const AC_CATALOG: PageUsage = PageUsage {
+        name: "AC_CATALOG",
+        id: 0x260,
+        usage: Usage::Selector,
+        desc: "AC Catalog",
+    };
+
+    pub const AC_BUY_CHECKOUT: PageUsage = PageUsage {
+        name: "AC_BUY_CHECKOUT",
+        id: 0x261,
+        usage: Usage::Selector,
+        desc: "AC Buy/Checkout",
+    };
+
+    pub const AC_ADD_TO_CART: PageUsage = PageUsage {
+        name: "AC_ADD_TO_CART",
+        id: 0x262,
+        usage: Usage::Selector,
+        desc: "AC Add to Cart",
+    };
+
+    pub const AC_EXPAND: PageUsage = PageUsage {
+        name: "AC_EXPAND",
+        id: 0x263,
+        usage: Usage::Selector,
+        desc: "AC Expand",
+    };
+
+    pub const AC_EXPAND_ALL: PageUsage = PageUsage {
+        name: "AC_EXPAND_ALL",
+        id: 0x264,
+        usage: Usage::Selector,
+        desc: "AC Expand All",
+    };
+
+    pub const AC_COLLAPSE: PageUsage = PageUsage {
+        name: "AC_COLLAPSE",
+        id: 0x265,
+        usage: Usage::Selector,
+        desc: "AC Collapse",
+    };
+
+    pub const AC_COLLAPSE_ALL: PageUsage = PageUsage {
+        name: "AC_COLLAPSE_ALL",
+        id: 0x266,
+        usage: Usage::Selector,
+        desc: "AC Collapse All",
+    };
+
+    pub const AC_PRINT_PREVIEW: PageUsage = PageUsage {
+        name: "AC_PRINT_PREVIEW",
+        id: 0x267,
+        usage: Usage::Selector,
+        desc: "AC Print Preview",
+    };
+
+    pub const AC_PASTE_SPECIAL: PageUsage = PageUsage {
+        name: "AC_PASTE_SPECIAL",
+        id: 0x268,
+        usage: Usage::Selector,
+        desc: "AC Paste Special",
+    };
+
+    pub const AC_INSERT_MODE: PageUsage = PageUsage {
+        name: "AC_INSERT_MODE",
+        id: 0x269,
+        usage: Usage::Selector,
+        desc: "AC Insert Mode",
+    };
+
+    pub const AC_DELETE: PageUsage = PageUsage {
+        name: "AC_DELETE",
+        id: 0x26a,
+        usage: Usage::Selector,
+        desc: "AC Delete",
+    };
+
+    pub const AC_LOCK: PageUsage = PageUsage {
+        name: "AC_LOCK",
+        id: 0x26b,
+        usage: Usage::Selector,
+        desc: "AC Lock",
+    };
+
+    pub const AC_UNLOCK: PageUsage = PageUsage {
+        name: "AC_UNLOCK",
+        id: 0x26c,
+        usage: Usage::Selector,
+        desc: "AC Unlock",
+    };
+
+    pub const AC_PROTECT: PageUsage = PageUsage {
+        name: "AC_PROTECT",
+        id: 0x26d,
+        usage: Usage::Selector,
+        desc: "AC Protect",
+    };
+
+    pub const AC_UNPROTECT: PageUsage = PageUsage {
+        name: "AC_UNPROTECT",
+        id: 0x26e,
+        usage: Usage::Selector,
+        desc: "AC Unprotect",
+    };
+
+    pub const AC_ATTACH_COMMENT: PageUsage = PageUsage {
+        name: "AC_ATTACH_COMMENT",
+        id: 0x26f,
+        usage: Usage::Selector,
+        desc: "AC Attach Comment",
+    };
+
+    pub const AC_DELETE_COMMENT: PageUsage = PageUsage {
+        name: "AC_DELETE_COMMENT",
+        id: 0x270,
+        usage: Usage::Selector,
+        desc: "AC Delete Comment",
+    };
+
+    pub const AC_VIEW_COMMENT: PageUsage = PageUsage {
+        name: "AC_VIEW_COMMENT",
+        id: 0x271,
+        usage: Usage::Selector,
+        desc: "AC View Comment",
+    };
+
+    pub const AC_SELECT_WORD: PageUsage = PageUsage {
+        name: "AC_SELECT_WORD",
+        id: 0x272,
+        usage: Usage::Selector,
+        desc: "AC Select Word",
+    };
+
+    pub const AC_SELECT_SENTENCE: PageUsage = PageUsage {
+        name: "AC_SELECT_SENTENCE",
+        id: 0x273,
+        usage: Usage::Selector,
+        desc: "AC Select Sentence",
+    };
+
+    pub const AC_SELECT_PARAGRAPH: PageUsage = PageUsage {
+        name: "AC_SELECT_PARAGRAPH",
+        id: 0x274,
+        usage: Usage::Selector,
+        desc: "AC Select Paragraph",
+    };
+
+    pub const AC_SELECT_COLUMN: PageUsage = PageUsage {
+        name: "AC_SELECT_COLUMN",
+        id: 0x275,
+        usage: Usage::Selector,
+        desc: "AC Select Column",
+    };
+
+    pub const AC_SELECT_ROW: PageUsage = PageUsage {
+        name: "AC_SELECT_ROW",
+        id: 0x276,
+        usage: Usage::Selector,
+        desc: "AC Select Row",
+    };
+
+    pub const AC_SELECT_TABLE: PageUsage = PageUsage {
+        name: "AC_SELECT_TABLE",
+        id: 0x277,
+        usage: Usage::Selector,
+        desc: "AC Select Table",
+    };
+
+    pub const AC_SELECT_OBJECT: PageUsage = PageUsage {
+        name: "AC_SELECT_OBJECT",
+        id: 0x278,
+        usage: Usage::Selector,
+        desc: "AC Select Object",
+    };
+
+    pub const AC_REDO_REPEAT: PageUsage = PageUsage {
+        name: "AC_REDO_REPEAT",
+        id: 0x279,
+        usage: Usage::Selector,
+        desc: "AC Redo/Repeat",
+    };
+
+    pub const AC_SORT: PageUsage = PageUsage {
+        name: "AC_SORT",
+        id: 0x27a,
+        usage: Usage::Selector,
+        desc: "AC Sort",
+    };
+
+    pub const AC_SORT_ASCENDING: PageUsage = PageUsage {
+        name: "AC_SORT_ASCENDING",
+        id: 0x27b,
+        usage: Usage::Selector,
+        desc: "AC Sort Ascending",
+    };
+
+    pub const AC_SORT_DESCENDING: PageUsage = PageUsage {
+        name: "AC_SORT_DESCENDING",
+        id: 0x27c,
+        usage: Usage::Selector,
+        desc: "AC Sort Descending",
+    };
+
+    pub const AC_FILTER: PageUsage = PageUsage {
+        name: "AC_FILTER",
+        id: 0x27d,
+        usage: Usage::Selector,
+        desc: "AC Filter",
+    };
+
+    pub const AC_SET_CLOCK: PageUsage = PageUsage {
+        name: "AC_SET_CLOCK",
+        id: 0x27e,
+        usage: Usage::Selector,
+        desc: "AC Set Clock",
+    };
+
+    pub const AC_VIEW_CLOCK: PageUsage = PageUsage {
+        name: "AC_VIEW_CLOCK",
+        id: 0x27f,
+        usage: Usage::Selector,
+        desc: "AC View Clock",
+    };
+
+    pub const AC_SELECT_TIME_ZONE: PageUsage = PageUsage {
+        name: "AC_SELECT_TIME_ZONE",
+        id: 0x280,
+        usage: Usage::Selector,
+        desc: "AC Select Time Zone",
+    };
+
+    pub const AC_EDIT_TIME_ZONES: PageUsage = PageUsage {
+        name: "AC_EDIT_TIME_ZONES",
+        id: 0x281,
+        usage: Usage::Selector,
+        desc: "AC Edit Time Zones",
+    };
+
+    pub const AC_SET_ALARM: PageUsage = PageUsage {
+        name: "AC_SET_ALARM",
+        id: 0x282,
+        usage: Usage::Selector,
+        desc: "AC Set Alarm",
+    };
+
+    pub const AC_CLEAR_ALARM: PageUsage = PageUsage {
+        name: "AC_CLEAR_ALARM",
+        id: 0x283,
+        usage: Usage::Selector,
+        desc: "AC Clear Alarm",
+    };
+
+    pub const AC_SNOOZE_ALARM: PageUsage = PageUsage {
+        name: "AC_SNOOZE_ALARM",
+        id: 0x284,
+        usage: Usage::Selector,
+        desc: "AC Snooze Alarm",
+    };
+
+    pub const AC_RESET_ALARM: PageUsage = PageUsage {
+        name: "AC_RESET_ALARM",
+        id: 0x285,
+        usage: Usage::Selector,
+        desc: "AC Reset Alarm",
+    };
+
+    pub const AC_SYNCHRONIZE: PageUsage = PageUsage {
+        name: "AC_SYNCHRONIZE",
+        id: 0x286,
+        usage: Usage::Selector,
+        desc: "AC Synchronize",
+    };
+
+    pub const AC_SEND_RECEIVE: PageUsage = PageUsage {
+        name: "AC_SEND_RECEIVE",
+        id: 0x287,
+        usage: Usage::Selector,
+        desc: "AC Send/Receive",
+    };
+
+    pub const AC_SEND_TO: PageUsage = PageUsage {
+        name: "AC_SEND_TO",
+        id: 0x288,
+        usage: Usage::Selector,
+        desc: "AC Send To",
+    };
+
+    pub const AC_REPLY: PageUsage = PageUsage {
+        name: "AC_REPLY",
+        id: 0x289,
+        usage: Usage::Selector,
+        desc: "AC Reply",
+    };
+
+    pub const AC_REPLY_ALL: PageUsage = PageUsage {
+        name: "AC_REPLY_ALL",
+        id: 0x28a,
+        usage: Usage::Selector,
+        desc: "AC Reply All",
+    };
+
+    pub const AC_FORWARD_MSG: PageUsage = PageUsage {
+        name: "AC_FORWARD_MSG",
+        id: 0x28b,
+        usage: Usage::Selector,
+        desc: "AC Forward Msg",
+    };
+
+    pub const AC_SEND: PageUsage = PageUsage {
+        name: "AC_SEND",
+        id: 0x28c,
+        usage: Usage::Selector,
+        desc: "AC Send",
+    };
+
+    pub const AC_ATTACH_FILE: PageUsage = PageUsage {
+        name: "AC_ATTACH_FILE",
+        id: 0x28d,
+        usage: Usage::Selector,
+        desc: "AC Attach File",
+    };
+
+    pub const AC_UPLOAD: PageUsage = PageUsage {
+        name: "AC_UPLOAD",
+        id: 0x28e,
+        usage: Usage::Selector,
+        desc: "AC Upload",
+    };
+
+    pub const AC_DOWNLOAD_SAVE_TARGET_AS: PageUsage = PageUsage {
+        name: "AC_DOWNLOAD_SAVE_TARGET_AS",
+        id: 0x28f,
+        usage: Usage::Selector,
+        desc: "AC Download (Save Target As)",
+    };
+
+    pub const AC_SET_BORDERS: PageUsage = PageUsage {
+        name: "AC_SET_BORDERS",
+        id: 0x290,
+        usage: Usage::Selector,
+        desc: "AC Set Borders",
+    };
+
+    pub const AC_INSERT_ROW: PageUsage = PageUsage {
+        name: "AC_INSERT_ROW",
+        id: 0x291,
+        usage: Usage::Selector,
+        desc: "AC Insert Row",
+    };
+
+    pub const AC_INSERT_COLUMN: PageUsage = PageUsage {
+        name: "AC_INSERT_COLUMN",
+        id: 0x292,
+        usage: Usage::Selector,
+        desc: "AC Insert Column",
+    };
+
+    pub const AC_INSERT_FILE: PageUsage = PageUsage {
+        name: "AC_INSERT_FILE",
+        id: 0x293,
+        usage: Usage::Selector,
+        desc: "AC Insert File",
+    };
+
+    pub const AC_INSERT_PICTURE: PageUsage = PageUsage {
+        name: "AC_INSERT_PICTURE",
+        id: 0x294,
+        usage: Usage::Selector,
+        desc: "AC Insert Picture",
+    };
+
+    pub const AC_INSERT_OBJECT: PageUsage = PageUsage {
+        name: "AC_INSERT_OBJECT",
+        id: 0x295,
+        usage: Usage::Selector,
+        desc: "AC Insert Object",
+    };
+
+    pub const AC_INSERT_SYMBOL: PageUsage = PageUsage {
+        name: "AC_INSERT_SYMBOL",
+        id: 0x296,
+        usage: Usage::Selector,
+        desc: "AC Insert Symbol",
+    };
+
+    pub const AC_SAVE_AND_CLOSE: PageUsage = PageUsage {
+        name: "AC_SAVE_AND_CLOSE",
+        id: 0x297,
+        usage: Usage::Selector,
+        desc: "AC Save and Close",
+    };
+
+    pub const AC_RENAME: PageUsage = PageUsage {
+        name: "AC_RENAME",
+        id: 0x298,
+        usage: Usage::Selector,
+        desc: "AC Rename",
+    };
+
+    pub const AC_MERGE: PageUsage = PageUsage {
+        name: "AC_MERGE",
+        id: 0x299,
+        usage: Usage::Selector,
+        desc: "AC Merge",
+    };
+
+    pub const AC_SPLIT: PageUsage = PageUsage {
+        name: "AC_SPLIT",
+        id: 0x29a,
+        usage: Usage::Selector,
+        desc: "AC Split",
+    };
+
+    pub const AC_DISTRIBUTE_HORIZONTALLY: PageUsage = PageUsage {
+        name: "AC_DISTRIBUTE_HORIZONTALLY",
+        id: 0x29b,
+        usage: Usage::Selector,
+        desc: "AC Distribute Horizontally",
+    };
+
+    pub const AC_DISTRIBUTE_VERTICALLY: PageUsage = PageUsage {
+        name: "AC_DISTRIBUTE_VERTICALLY",
+        id: 0x29c,
+        usage: Usage::Selector,
+        desc: "AC Distribute Vertically",
+    };
+
+    pub const fn usages() -> &'static [PageUsage] {
+        &[
+            CONSUMER_CONTROL,
+            NUMERIC_KEY_PAD,
+            PROGRAMMABLE_BUTTONS,
+            MICROPHONE,
+            HEADPHONE,
+            GRAPHIC_EQUALIZER,
+            PLUS10,
+            PLUS100,
+            AM_PM,
+            POWER,
+            RESET,
+            SLEEP,
+            SLEEP_AFTER,
+            SLEEP_MODE,
+            ILLUMINATION,
+            FUNCTION_BUTTONS,
+            MENU,
+            MENU_PICK,
+            MENU_UP,
+            MENU_DOWN,
+            MENU_LEFT,
+            MENU_RIGHT,
+            MENU_ESCAPE,
+            MENU_VALUE_INCREASE,
+            MENU_VALUE_DECREASE,
+            DATA_ON_SCREEN,
+            CLOSED_CAPTION,
+            CLOSED_CAPTION_SELECT,
+            VCR_TV,
+            BROADCAST_MODE,
+            SNAPSHOT,
+            STILL,
+            SELECTION,
+            ASSIGN_SELECTION,
+            MODE_STEP,
+            RECALL_LAST,
+            ENTER_CHANNEL,
+            ORDER_MOVIE,
+            CHANNEL,
+            MEDIA_SELECTION,
+            MEDIA_SELECT_COMPUTER,
+            MEDIA_SELECT_TV,
+            MEDIA_SELECT_WWW,
+            MEDIA_SELECT_DVD,
+            MEDIA_SELECT_TELEPHONE,
+            MEDIA_SELECT_PROGRAM_GUIDE,
+            MEDIA_SELECT_VIDEO_PHONE,
+            MEDIA_SELECT_GAMES,
+            MEDIA_SELECT_MESSAGES,
+            MEDIA_SELECT_CD,
+            MEDIA_SELECT_VCR,
+            MEDIA_SELECT_TUNER,
+            QUIT,
+            HELP,
+            MEDIA_SELECT_TAPE,
+            MEDIA_SELECT_CABLE,
+            MEDIA_SELECT_SATELLITE,
+            MEDIA_SELECT_SECURITY,
+            MEDIA_SELECT_HOME,
+            MEDIA_SELECT_CALL,
+            CHANNEL_INCREMENT,
+            CHANNEL_DECREMENT,
+            MEDIA_SELECT_SAP,
+            VCR_PLUS,
+            ONCE,
+            DAILY,
+            WEEKLY,
+            MONTHLY,
+            PLAY,
+            PAUSE,
+            RECORD,
+            FAST_FORWARD,
+            REWIND,
+            SCAN_NEXT_TRACK,
+            SCAN_PREVIOUS_TRACK,
+            STOP,
+            EJECT,
+            RANDOM_PLAY,
+            SELECT_DISC,
+            ENTER_DISC,
+            REPEAT,
+            TRACKING,
+            TRACK_NORMAL,
+            SLOW_TRACKING,
+            FRAME_FORWARD,
+            FRAME_BACK,
+            MARK,
+            CLEAR_MARK,
+            REPEAT_FROM_MARK,
+            RETURN_TO_MARK,
+            SEARCH_MARK_FORWARD,
+            SEARCH_MARK_BACKWARDS,
+            COUNTER_RESET,
+            SHOW_COUNTER,
+            TRACKING_INCREMENT,
+            TRACKING_DECREMENT,
+            STOP_EJECT,
+            PLAY_PAUSE,
+            PLAY_SKIP,
+            VOLUME,
+            BALANCE,
+            MUTE,
+            BASS,
+            TREBLE,
+            BASS_BOOST,
+            SURROUND_MODE,
+            LOUDNESS,
+            MPX,
+            VOLUME_INCREMENT,
+            VOLUME_DECREMENT,
+            SPEED_SELECT,
+            PLAYBACK_SPEED,
+            STANDARD_PLAY,
+            LONG_PLAY,
+            EXTENDED_PLAY,
+            SLOW,
+            FAN_ENABLE,
+            FAN_SPEED,
+            LIGHT_ENABLE,
+            LIGHT_ILLUMINATION_LEVEL,
+            CLIMATE_CONTROL_ENABLE,
+            ROOM_TEMPERATURE,
+            SECURITY_ENABLE,
+            FIRE_ALARM,
+            POLICE_ALARM,
+            PROXIMITY,
+            MOTION,
+            DURESS_ALARM,
+            HOLDUP_ALARM,
+            MEDICAL_ALARM,
+            BALANCE_RIGHT,
+            BALANCE_LEFT,
+            BASS_INCREMENT,
+            BASS_DECREMENT,
+            TREBLE_INCREMENT,
+            TREBLE_DECREMENT,
+            SPEAKER_SYSTEM,
+            CHANNEL_LEFT,
+            CHANNEL_RIGHT,
+            CHANNEL_CENTER,
+            CHANNEL_FRONT,
+            CHANNEL_CENTER_FRONT,
+            CHANNEL_SIDE,
+            CHANNEL_SURROUND,
+            CHANNEL_LOW_FREQUENCY_ENHANCEMENT,
+            CHANNEL_TOP,
+            CHANNEL_UNKNOWN,
+            SUB_CHANNEL,
+            SUB_CHANNEL_INCREMENT,
+            SUB_CHANNEL_DECREMENT,
+            ALTERNATE_AUDIO_INCREMENT,
+            ALTERNATE_AUDIO_DECREMENT,
+            APPLICATION_LAUNCH_BUTTONS,
+            AL_LAUNCH_BUTTON_CONFIGURATION_TOOL,
+            AL_PROGRAMMABLE_BUTTON_CONFIGURATION,
+            AL_CONSUMER_CONTROL_CONFIGURATION,
+            AL_WORD_PROCESSOR,
+            AL_TEXT_EDITOR,
+            AL_SPREADSHEET,
+            AL_GRAPHICS_EDITOR,
+            AL_PRESENTATION_APP,
+            AL_DATABASE_APP,
+            AL_EMAIL_READER,
+            AL_NEWSREADER,
+            AL_VOICEMAIL,
+            AL_CONTACTS_ADDRESS_BOOK,
+            AL_CALENDAR_SCHEDULE,
+            AL_TASK_PROJECT_MANAGER,
+            AL_LOG_JOURNAL_TIMECARD,
+            AL_CHECKBOOK_FINANCE,
+            AL_CALCULATOR,
+            AL_A_V_CAPTURE_PLAYBACK,
+            AL_LOCAL_MACHINE_BROWSER,
+            AL_LAN_WAN_BROWSER,
+            AL_INTERNET_BROWSER,
+            AL_REMOTE_NETWORKING_ISP_CONNECT,
+            AL_NETWORK_CONFERENCE,
+            AL_NETWORK_CHAT,
+            AL_TELEPHONY_DIALER,
+            AL_LOGON,
+            AL_LOGOFF,
+            AL_LOGON_LOGOFF,
+            AL_TERMINAL_LOCK_SCREENSAVER,
+            AL_CONTROL_PANEL,
+            AL_COMMAND_LINE_PROCESSOR_RUN,
+            AL_PROCESS_TASK_MANAGER,
+            AL_SELECT_TASK_APPLICATION,
+            AL_NEXT_TASK_APPLICATION,
+            AL_PREVIOUS_TASK_APPLICATION,
+            AL_PREEMPTIVE_HALT_TASK_APPLICATION,
+            AL_INTEGRATED_HELP_CENTER,
+            AL_DOCUMENTS,
+            AL_THESAURUS,
+            AL_DICTIONARY,
+            AL_DESKTOP,
+            AL_SPELL_CHECK,
+            AL_GRAMMAR_CHECK,
+            AL_WIRELESS_STATUS,
+            AL_KEYBOARD_LAYOUT,
+            AL_VIRUS_PROTECTION,
+            AL_ENCRYPTION,
+            AL_SCREEN_SAVER,
+            AL_ALARMS,
+            AL_CLOCK,
+            AL_FILE_BROWSER,
+            AL_POWER_STATUS,
+            AL_IMAGE_BROWSER,
+            AL_AUDIO_BROWSER,
+            AL_MOVIE_BROWSER,
+            AL_DIGITAL_RIGHTS_MANAGER,
+            AL_DIGITAL_WALLET,
+            AL_INSTANT_MESSAGING,
+            AL_OEM_FEATURES_TIPS_TUTORIAL_BROWSER,
+            AL_OEM_HELP,
+            AL_ONLINE_COMMUNITY,
+            AL_ENTERTAINMENT_CONTENT_BROWSER,
+            AL_ONLINE_SHOPPING_BROWSER,
+            AL_SMARTCARD_INFORMATION_HELP,
+            AL_MARKET_MONITOR_FINANCE_BROWSER,
+            AL_CUSTOMIZED_CORPORATE_NEWS_BROWSER,
+            AL_ONLINE_ACTIVITY_BROWSER,
+            AL_RESEARCH_SEARCH_BROWSER,
+            AL_AUDIO_PLAYER,
+            GENERIC_GUI_APPLICATION_CONTROLS,
+            AC_NEW,
+            AC_OPEN,
+            AC_CLOSE,
+            AC_EXIT,
+            AC_MAXIMIZE,
+            AC_MINIMIZE,
+            AC_SAVE,
+            AC_PRINT,
+            AC_PROPERTIES,
+            AC_UNDO,
+            AC_COPY,
+            AC_CUT,
+            AC_PASTE,
+            AC_SELECT_ALL,
+            AC_FIND,
+            AC_FIND_AND_REPLACE,
+            AC_SEARCH,
+            AC_GO_TO,
+            AC_HOME,
+            AC_BACK,
+            AC_FORWARD,
+            AC_STOP,
+            AC_REFRESH,
+            AC_PREVIOUS_LINK,
+            AC_NEXT_LINK,
+            AC_BOOKMARKS,
+            AC_HISTORY,
+            AC_SUBSCRIPTIONS,
+            AC_ZOOM_IN,
+            AC_ZOOM_OUT,
+            AC_ZOOM,
+            AC_FULL_SCREEN_VIEW,
+            AC_NORMAL_VIEW,
+            AC_VIEW_TOGGLE,
+            AC_SCROLL_UP,
+            AC_SCROLL_DOWN,
+            AC_SCROLL,
+            AC_PAN_LEFT,
+            AC_PAN_RIGHT,
+            AC_PAN,
+            AC_NEW_WINDOW,
+            AC_TILE_HORIZONTALLY,
+            AC_TILE_VERTICALLY,
+            AC_FORMAT,
+            AC_EDIT,
+            AC_BOLD,
+            AC_ITALICS,
+            AC_UNDERLINE,
+            AC_STRIKETHROUGH,
+            AC_SUBSCRIPT,
+            AC_SUPERSCRIPT,
+            AC_ALL_CAPS,
+            AC_ROTATE,
+            AC_RESIZE,
+            AC_FLIP_HORIZONTAL,
+            AC_FLIP_VERTICAL,
+            AC_MIRROR_HORIZONTAL,
+            AC_MIRROR_VERTICAL,
+            AC_FONT_SELECT,
+            AC_FONT_COLOR,
+            AC_FONT_SIZE,
+            AC_JUSTIFY_LEFT,
+            AC_JUSTIFY_CENTER_H,
+            AC_JUSTIFY_RIGHT,
+            AC_JUSTIFY_BLOCK_H,
+            AC_JUSTIFY_TOP,
+            AC_JUSTIFY_CENTER_V,
+            AC_JUSTIFY_BOTTOM,
+            AC_JUSTIFY_BLOCK_V,
+            AC_INDENT_DECREASE,
+            AC_INDENT_INCREASE,
+            AC_NUMBERED_LIST,
+            AC_RESTART_NUMBERING,
+            AC_BULLETED_LIST,
+            AC_PROMOTE,
+            AC_DEMOTE,
+            AC_YES,
+            AC_NO,
+            AC_CANCEL,
+            AC_CATALOG,
+            AC_BUY_CHECKOUT,
+            AC_ADD_TO_CART,
+            AC_EXPAND,
+            AC_EXPAND_ALL,
+            AC_COLLAPSE,
+            AC_COLLAPSE_ALL,
+            AC_PRINT_PREVIEW,
+            AC_PASTE_SPECIAL,
+            AC_INSERT_MODE,
+            AC_DELETE,
+            AC_LOCK,
+            AC_UNLOCK,
+            AC_PROTECT,
+            AC_UNPROTECT,
+            AC_ATTACH_COMMENT,
+            AC_DELETE_COMMENT,
+            AC_VIEW_COMMENT,
+            AC_SELECT_WORD,
+            AC_SELECT_SENTENCE,
+            AC_SELECT_PARAGRAPH,
+            AC_SELECT_COLUMN,
+            AC_SELECT_ROW,
+            AC_SELECT_TABLE,
+            AC_SELECT_OBJECT,
+            AC_REDO_REPEAT,
+            AC_SORT,
+            AC_SORT_ASCENDING,
+            AC_SORT_DESCENDING,
+            AC_FILTER,
+            AC_SET_CLOCK,
+            AC_VIEW_CLOCK,
+            AC_SELECT_TIME_ZONE,
+            AC_EDIT_TIME_ZONES,
+            AC_SET_ALARM,
+            AC_CLEAR_ALARM,
+            AC_SNOOZE_ALARM,
+            AC_RESET_ALARM,
+            AC_SYNCHRONIZE,
+            AC_SEND_RECEIVE,
+            AC_SEND_TO,
+            AC_REPLY,
+            AC_REPLY_ALL,
+            AC_FORWARD_MSG,
+            AC_SEND,
+            AC_ATTACH_FILE,
+            AC_UPLOAD,
+            AC_DOWNLOAD_SAVE_TARGET_AS,
+            AC_SET_BORDERS,
+            AC_INSERT_ROW,
+            AC_INSERT_COLUMN,
+            AC_INSERT_FILE,
+            AC_INSERT_PICTURE,
+            AC_INSERT_OBJECT,
+            AC_INSERT_SYMBOL,
+            AC_SAVE_AND_CLOSE,
+            AC_RENAME,
+            AC_MERGE,
+            AC_SPLIT,
+            AC_DISTRIBUTE_HORIZONTALLY,
+            AC_DISTRIBUTE_VERTICALLY,
+        ]
+    }
+}
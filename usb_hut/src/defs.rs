@@ -2,6 +2,30 @@
 pub enum Usage {
     Selector,
     DynamicFlag,
+    /// LC, a value within a range.
+    LinearControl,
+    /// OOC, toggles between on and off.
+    OnOffControl,
+    /// MC, active while asserted.
+    MomentaryControl,
+    /// OSC, triggers a single event.
+    OneShotControl,
+    /// RTC, repeats while asserted.
+    ReTriggerControl,
+    /// SV, a read-only value.
+    StaticValue,
+    /// DV, a read / write value.
+    DynamicValue,
+    /// US, a usage switch; selects one of the usages in the collection.
+    Switch,
+    /// NAry, a collection of selectors.
+    NamedArray,
+    /// CA, an application collection.
+    CollectionApplication,
+    /// CL, a logical collection.
+    CollectionLogical,
+    /// CP, a physical collection.
+    CollectionPhysical,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -12,3 +36,12 @@ pub struct Key {
     pub desc: &'static str,
     pub usage: Usage,
 }
+
+/// A usage of one of the pages that doesn't hold keys, like the consumer page.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageUsage {
+    pub name: &'static str,
+    pub id: usize,
+    pub desc: &'static str,
+    pub usage: Usage,
+}
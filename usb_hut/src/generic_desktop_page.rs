@@ -0,0 +1,660 @@
+// This file is generated with dev/generate.rs
+
+#[allow(dead_code)]
+pub mod hid_generic_desktop_page {
+    #[allow(dead_code)]
+    use crate::defs::{PageUsage, Usage};
+
+    pub const POINTER: PageUsage = PageUsage {
+        name: "POINTER",
+        id: 0x01,
+        usage: Usage::CollectionPhysical,
+        desc: "Pointer",
+    };
+
+    pub const MOUSE: PageUsage = PageUsage {
+        name: "MOUSE",
+        id: 0x02,
+        usage: Usage::CollectionApplication,
+        desc: "Mouse",
+    };
+
+    pub const JOYSTICK: PageUsage = PageUsage {
+        name: "JOYSTICK",
+        id: 0x04,
+        usage: Usage::CollectionApplication,
+        desc: "Joystick",
+    };
+
+    pub const GAME_PAD: PageUsage = PageUsage {
+        name: "GAME_PAD",
+        id: 0x05,
+        usage: Usage::CollectionApplication,
+        desc: "Game Pad",
+    };
+
+    pub const KEYBOARD: PageUsage = PageUsage {
+        name: "KEYBOARD",
+        id: 0x06,
+        usage: Usage::CollectionApplication,
+        desc: "Keyboard",
+    };
+
+    pub const KEYPAD: PageUsage = PageUsage {
+        name: "KEYPAD",
+        id: 0x07,
+        usage: Usage::CollectionApplication,
+        desc: "Keypad",
+    };
+
+    pub const MULTI_AXIS_CONTROLLER: PageUsage = PageUsage {
+        name: "MULTI_AXIS_CONTROLLER",
+        id: 0x08,
+        usage: Usage::CollectionApplication,
+        desc: "Multi-axis Controller",
+    };
+
+    pub const TABLET_PC_SYSTEM_CONTROLS: PageUsage = PageUsage {
+        name: "TABLET_PC_SYSTEM_CONTROLS",
+        id: 0x09,
+        usage: Usage::CollectionApplication,
+        desc: "Tablet PC System Controls",
+    };
+
+    pub const X: PageUsage = PageUsage {
+        name: "X",
+        id: 0x30,
+        usage: Usage::DynamicValue,
+        desc: "X",
+    };
+
+    pub const Y: PageUsage = PageUsage {
+        name: "Y",
+        id: 0x31,
+        usage: Usage::DynamicValue,
+        desc: "Y",
+    };
+
+    pub const Z: PageUsage = PageUsage {
+        name: "Z",
+        id: 0x32,
+        usage: Usage::DynamicValue,
+        desc: "Z",
+    };
+
+    pub const RX: PageUsage = PageUsage {
+        name: "RX",
+        id: 0x33,
+        usage: Usage::DynamicValue,
+        desc: "Rx",
+    };
+
+    pub const RY: PageUsage = PageUsage {
+        name: "RY",
+        id: 0x34,
+        usage: Usage::DynamicValue,
+        desc: "Ry",
+    };
+
+    pub const RZ: PageUsage = PageUsage {
+        name: "RZ",
+        id: 0x35,
+        usage: Usage::DynamicValue,
+        desc: "Rz",
+    };
+
+    pub const SLIDER: PageUsage = PageUsage {
+        name: "SLIDER",
+        id: 0x36,
+        usage: Usage::DynamicValue,
+        desc: "Slider",
+    };
+
+    pub const DIAL: PageUsage = PageUsage {
+        name: "DIAL",
+        id: 0x37,
+        usage: Usage::DynamicValue,
+        desc: "Dial",
+    };
+
+    pub const WHEEL: PageUsage = PageUsage {
+        name: "WHEEL",
+        id: 0x38,
+        usage: Usage::DynamicValue,
+        desc: "Wheel",
+    };
+
+    pub const HAT_SWITCH: PageUsage = PageUsage {
+        name: "HAT_SWITCH",
+        id: 0x39,
+        usage: Usage::DynamicValue,
+        desc: "Hat switch",
+    };
+
+    pub const COUNTED_BUFFER: PageUsage = PageUsage {
+        name: "COUNTED_BUFFER",
+        id: 0x3a,
+        usage: Usage::CollectionLogical,
+        desc: "Counted Buffer",
+    };
+
+    pub const BYTE_COUNT: PageUsage = PageUsage {
+        name: "BYTE_COUNT",
+        id: 0x3b,
+        usage: Usage::DynamicValue,
+        desc: "Byte Count",
+    };
+
+    pub const MOTION_WAKEUP: PageUsage = PageUsage {
+        name: "MOTION_WAKEUP",
+        id: 0x3c,
+        usage: Usage::OneShotControl,
+        desc: "Motion Wakeup",
+    };
+
+    pub const START: PageUsage = PageUsage {
+        name: "START",
+        id: 0x3d,
+        usage: Usage::OnOffControl,
+        desc: "Start",
+    };
+
+    pub const SELECT: PageUsage = PageUsage {
+        name: "SELECT",
+        id: 0x3e,
+        usage: Usage::OnOffControl,
+        desc: "Select",
+    };
+
+    pub const VX: PageUsage = PageUsage {
+        name: "VX",
+        id: 0x40,
+        usage: Usage::DynamicValue,
+        desc: "Vx",
+    };
+
+    pub const VY: PageUsage = PageUsage {
+        name: "VY",
+        id: 0x41,
+        usage: Usage::DynamicValue,
+        desc: "Vy",
+    };
+
+    pub const VZ: PageUsage = PageUsage {
+        name: "VZ",
+        id: 0x42,
+        usage: Usage::DynamicValue,
+        desc: "Vz",
+    };
+
+    pub const VBRX: PageUsage = PageUsage {
+        name: "VBRX",
+        id: 0x43,
+        usage: Usage::DynamicValue,
+        desc: "Vbrx",
+    };
+
+    pub const VBRY: PageUsage = PageUsage {
+        name: "VBRY",
+        id: 0x44,
+        usage: Usage::DynamicValue,
+        desc: "Vbry",
+    };
+
+    pub const VBRZ: PageUsage = PageUsage {
+        name: "VBRZ",
+        id: 0x45,
+        usage: Usage::DynamicValue,
+        desc: "Vbrz",
+    };
+
+    pub const VNO: PageUsage = PageUsage {
+        name: "VNO",
+        id: 0x46,
+        usage: Usage::DynamicValue,
+        desc: "Vno",
+    };
+
+    pub const FEATURE_NOTIFICATION: PageUsage = PageUsage {
+        name: "FEATURE_NOTIFICATION",
+        id: 0x47,
+        usage: Usage::DynamicValue,
+        desc: "Feature Notification",
+    };
+
+    pub const RESOLUTION_MULTIPLIER: PageUsage = PageUsage {
+        name: "RESOLUTION_MULTIPLIER",
+        id: 0x48,
+        usage: Usage::DynamicValue,
+        desc: "Resolution Multiplier",
+    };
+
+    pub const SYSTEM_CONTROL: PageUsage = PageUsage {
+        name: "SYSTEM_CONTROL",
+        id: 0x80,
+        usage: Usage::CollectionApplication,
+        desc: "System Control",
+    };
+
+    pub const SYSTEM_POWER_DOWN: PageUsage = PageUsage {
+        name: "SYSTEM_POWER_DOWN",
+        id: 0x81,
+        usage: Usage::OneShotControl,
+        desc: "System Power Down",
+    };
+
+    pub const SYSTEM_SLEEP: PageUsage = PageUsage {
+        name: "SYSTEM_SLEEP",
+        id: 0x82,
+        usage: Usage::OneShotControl,
+        desc: "System Sleep",
+    };
+
+    pub const SYSTEM_WAKE_UP: PageUsage = PageUsage {
+        name: "SYSTEM_WAKE_UP",
+        id: 0x83,
+        usage: Usage::OneShotControl,
+        desc: "System Wake Up",
+    };
+
+    pub const SYSTEM_CONTEXT_MENU: PageUsage = PageUsage {
+        name: "SYSTEM_CONTEXT_MENU",
+        id: 0x84,
+        usage: Usage::OneShotControl,
+        desc: "System Context Menu",
+    };
+
+    pub const SYSTEM_MAIN_MENU: PageUsage = PageUsage {
+        name: "SYSTEM_MAIN_MENU",
+        id: 0x85,
+        usage: Usage::OneShotControl,
+        desc: "System Main Menu",
+    };
+
+    pub const SYSTEM_APP_MENU: PageUsage = PageUsage {
+        name: "SYSTEM_APP_MENU",
+        id: 0x86,
+        usage: Usage::OneShotControl,
+        desc: "System App Menu",
+    };
+
+    pub const SYSTEM_MENU_HELP: PageUsage = PageUsage {
+        name: "SYSTEM_MENU_HELP",
+        id: 0x87,
+        usage: Usage::OneShotControl,
+        desc: "System Menu Help",
+    };
+
+    pub const SYSTEM_MENU_EXIT: PageUsage = PageUsage {
+        name: "SYSTEM_MENU_EXIT",
+        id: 0x88,
+        usage: Usage::OneShotControl,
+        desc: "System Menu Exit",
+    };
+
+    pub const SYSTEM_MENU_SELECT: PageUsage = PageUsage {
+        name: "SYSTEM_MENU_SELECT",
+        id: 0x89,
+        usage: Usage::OneShotControl,
+        desc: "System Menu Select",
+    };
+
+    pub const SYSTEM_MENU_RIGHT: PageUsage = PageUsage {
+        name: "SYSTEM_MENU_RIGHT",
+        id: 0x8a,
+        usage: Usage::ReTriggerControl,
+        desc: "System Menu Right",
+    };
+
+    pub const SYSTEM_MENU_LEFT: PageUsage = PageUsage {
+        name: "SYSTEM_MENU_LEFT",
+        id: 0x8b,
+        usage: Usage::ReTriggerControl,
+        desc: "System Menu Left",
+    };
+
+    pub const SYSTEM_MENU_UP: PageUsage = PageUsage {
+        name: "SYSTEM_MENU_UP",
+        id: 0x8c,
+        usage: Usage::ReTriggerControl,
+        desc: "System Menu Up",
+    };
+
+    pub const SYSTEM_MENU_DOWN: PageUsage = PageUsage {
+        name: "SYSTEM_MENU_DOWN",
+        id: 0x8d,
+        usage: Usage::ReTriggerControl,
+        desc: "System Menu Down",
+    };
+
+    pub const SYSTEM_COLD_RESTART: PageUsage = PageUsage {
+        name: "SYSTEM_COLD_RESTART",
+        id: 0x8e,
+        usage: Usage::OneShotControl,
+        desc: "System Cold Restart",
+    };
+
+    pub const SYSTEM_WARM_RESTART: PageUsage = PageUsage {
+        name: "SYSTEM_WARM_RESTART",
+        id: 0x8f,
+        usage: Usage::OneShotControl,
+        desc: "System Warm Restart",
+    };
+
+    pub const D_PAD_UP: PageUsage = PageUsage {
+        name: "D_PAD_UP",
+        id: 0x90,
+        usage: Usage::OnOffControl,
+        desc: "D-pad Up",
+    };
+
+    pub const D_PAD_DOWN: PageUsage = PageUsage {
+        name: "D_PAD_DOWN",
+        id: 0x91,
+        usage: Usage::OnOffControl,
+        desc: "D-pad Down",
+    };
+
+    pub const D_PAD_RIGHT: PageUsage = PageUsage {
+        name: "D_PAD_RIGHT",
+        id: 0x92,
+        usage: Usage::OnOffControl,
+        desc: "D-pad Right",
+    };
+
+    pub const D_PAD_LEFT: PageUsage = PageUsage {
+        name: "D_PAD_LEFT",
+        id: 0x93,
+        usage: Usage::OnOffControl,
+        desc: "D-pad Left",
+    };
+
+    pub const SYSTEM_DOCK: PageUsage = PageUsage {
+        name: "SYSTEM_DOCK",
+        id: 0xa0,
+        usage: Usage::OneShotControl,
+        desc: "System Dock",
+    };
+
+    pub const SYSTEM_UNDOCK: PageUsage = PageUsage {
+        name: "SYSTEM_UNDOCK",
+        id: 0xa1,
+        usage: Usage::OneShotControl,
+        desc: "System Undock",
+    };
+
+    pub const SYSTEM_SETUP: PageUsage = PageUsage {
+        name: "SYSTEM_SETUP",
+        id: 0xa2,
+        usage: Usage::OneShotControl,
+        desc: "System Setup",
+    };
+
+    pub const SYSTEM_BREAK: PageUsage = PageUsage {
+        name: "SYSTEM_BREAK",
+        id: 0xa3,
+        usage: Usage::OneShotControl,
+        desc: "System Break",
+    };
+
+    pub const SYSTEM_DEBUGGER_BREAK: PageUsage = PageUsage {
+        name: "SYSTEM_DEBUGGER_BREAK",
+        id: 0xa4,
+        usage: Usage::OneShotControl,
+        desc: "System Debugger Break",
+    };
+
+    pub const APPLICATION_BREAK: PageUsage = PageUsage {
+        name: "APPLICATION_BREAK",
+        id: 0xa5,
+        usage: Usage::OneShotControl,
+        desc: "Application Break",
+    };
+
+    pub const APPLICATION_DEBUGGER_BREAK: PageUsage = PageUsage {
+        name: "APPLICATION_DEBUGGER_BREAK",
+        id: 0xa6,
+        usage: Usage::OneShotControl,
+        desc: "Application Debugger Break",
+    };
+
+    pub const SYSTEM_SPEAKER_MUTE: PageUsage = PageUsage {
+        name: "SYSTEM_SPEAKER_MUTE",
+        id: 0xa7,
+        usage: Usage::OneShotControl,
+        desc: "System Speaker Mute",
+    };
+
+    pub const SYSTEM_HIBERNATE: PageUsage = PageUsage {
+        name: "SYSTEM_HIBERNATE",
+        id: 0xa8,
+        usage: Usage::OneShotControl,
+        desc: "System Hibernate",
+    };
+
+    pub const SYSTEM_DISPLAY_INVERT: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_INVERT",
+        id: 0xb0,
+        usage: Usage::OneShotControl,
+        desc: "System Display Invert",
+    };
+
+    pub const SYSTEM_DISPLAY_INTERNAL: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_INTERNAL",
+        id: 0xb1,
+        usage: Usage::OneShotControl,
+        desc: "System Display Internal",
+    };
+
+    pub const SYSTEM_DISPLAY_EXTERNAL: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_EXTERNAL",
+        id: 0xb2,
+        usage: Usage::OneShotControl,
+        desc: "System Display External",
+    };
+
+    pub const SYSTEM_DISPLAY_BOTH: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_BOTH",
+        id: 0xb3,
+        usage: Usage::OneShotControl,
+        desc: "System Display Both",
+    };
+
+    pub const SYSTEM_DISPLAY_DUAL: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_DUAL",
+        id: 0xb4,
+        usage: Usage::OneShotControl,
+        desc: "System Display Dual",
+    };
+
+    pub const SYSTEM_DISPLAY_TOGGLE_INT_EXT: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_TOGGLE_INT_EXT",
+        id: 0xb5,
+        usage: Usage::OneShotControl,
+        desc: "System Display Toggle Int/Ext",
+    };
+
+    pub const SYSTEM_DISPLAY_SWAP_PRIMARY_SECONDARY: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_SWAP_PRIMARY_SECONDARY",
+        id: 0xb6,
+        usage: Usage::OneShotControl,
+        desc: "System Display Swap Primary/Secondary",
+    };
+
+    pub const SYSTEM_DISPLAY_LCD_AUTOSCALE: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_LCD_AUTOSCALE",
+        id: 0xb7,
+        usage: Usage::OneShotControl,
+        desc: "System Display LCD Autoscale",
+    };
+
+    pub const SENSOR_ZONE: PageUsage = PageUsage {
+        name: "SENSOR_ZONE",
+        id: 0xc0,
+        usage: Usage::CollectionLogical,
+        desc: "Sensor Zone",
+    };
+
+    pub const RPM: PageUsage = PageUsage {
+        name: "RPM",
+        id: 0xc1,
+        usage: Usage::DynamicValue,
+        desc: "RPM",
+    };
+
+    pub const COOLANT_LEVEL: PageUsage = PageUsage {
+        name: "COOLANT_LEVEL",
+        id: 0xc2,
+        usage: Usage::DynamicValue,
+        desc: "Coolant Level",
+    };
+
+    pub const COOLANT_CRITICAL_LEVEL: PageUsage = PageUsage {
+        name: "COOLANT_CRITICAL_LEVEL",
+        id: 0xc3,
+        usage: Usage::StaticValue,
+        desc: "Coolant Critical Level",
+    };
+
+    pub const COOLANT_PUMP: PageUsage = PageUsage {
+        name: "COOLANT_PUMP",
+        id: 0xc4,
+        usage: Usage::Switch,
+        desc: "Coolant Pump",
+    };
+
+    pub const CHASSIS_ENCLOSURE: PageUsage = PageUsage {
+        name: "CHASSIS_ENCLOSURE",
+        id: 0xc5,
+        usage: Usage::CollectionLogical,
+        desc: "Chassis Enclosure",
+    };
+
+    pub const WIRELESS_RADIO_BUTTON: PageUsage = PageUsage {
+        name: "WIRELESS_RADIO_BUTTON",
+        id: 0xc6,
+        usage: Usage::OnOffControl,
+        desc: "Wireless Radio Button",
+    };
+
+    pub const WIRELESS_RADIO_LED: PageUsage = PageUsage {
+        name: "WIRELESS_RADIO_LED",
+        id: 0xc7,
+        usage: Usage::OnOffControl,
+        desc: "Wireless Radio LED",
+    };
+
+    pub const WIRELESS_RADIO_SLIDER_SWITCH: PageUsage = PageUsage {
+        name: "WIRELESS_RADIO_SLIDER_SWITCH",
+        id: 0xc8,
+        usage: Usage::OnOffControl,
+        desc: "Wireless Radio Slider Switch",
+    };
+
+    pub const SYSTEM_DISPLAY_ROTATION_LOCK_BUTTON: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_ROTATION_LOCK_BUTTON",
+        id: 0xc9,
+        usage: Usage::OnOffControl,
+        desc: "System Display Rotation Lock Button",
+    };
+
+    pub const SYSTEM_DISPLAY_ROTATION_LOCK_SLIDER_SWITCH: PageUsage = PageUsage {
+        name: "SYSTEM_DISPLAY_ROTATION_LOCK_SLIDER_SWITCH",
+        id: 0xca,
+        usage: Usage::OnOffControl,
+        desc: "System Display Rotation Lock Slider Switch",
+    };
+
+    pub const CONTROL_ENABLE: PageUsage = PageUsage {
+        name: "CONTROL_ENABLE",
+        id: 0xcb,
+        usage: Usage::DynamicFlag,
+        desc: "Control Enable",
+    };
+
+    pub const fn usages() -> &'static [PageUsage] {
+        &[
+            POINTER,
+            MOUSE,
+            JOYSTICK,
+            GAME_PAD,
+            KEYBOARD,
+            KEYPAD,
+            MULTI_AXIS_CONTROLLER,
+            TABLET_PC_SYSTEM_CONTROLS,
+            X,
+            Y,
+            Z,
+            RX,
+            RY,
+            RZ,
+            SLIDER,
+            DIAL,
+            WHEEL,
+            HAT_SWITCH,
+            COUNTED_BUFFER,
+            BYTE_COUNT,
+            MOTION_WAKEUP,
+            START,
+            SELECT,
+            VX,
+            VY,
+            VZ,
+            VBRX,
+            VBRY,
+            VBRZ,
+            VNO,
+            FEATURE_NOTIFICATION,
+            RESOLUTION_MULTIPLIER,
+            SYSTEM_CONTROL,
+            SYSTEM_POWER_DOWN,
+            SYSTEM_SLEEP,
+            SYSTEM_WAKE_UP,
+            SYSTEM_CONTEXT_MENU,
+            SYSTEM_MAIN_MENU,
+            SYSTEM_APP_MENU,
+            SYSTEM_MENU_HELP,
+            SYSTEM_MENU_EXIT,
+            SYSTEM_MENU_SELECT,
+            SYSTEM_MENU_RIGHT,
+            SYSTEM_MENU_LEFT,
+            SYSTEM_MENU_UP,
+            SYSTEM_MENU_DOWN,
+            SYSTEM_COLD_RESTART,
+            SYSTEM_WARM_RESTART,
+            D_PAD_UP,
+            D_PAD_DOWN,
+            D_PAD_RIGHT,
+            D_PAD_LEFT,
+            SYSTEM_DOCK,
+            SYSTEM_UNDOCK,
+            SYSTEM_SETUP,
+            SYSTEM_BREAK,
+            SYSTEM_DEBUGGER_BREAK,
+            APPLICATION_BREAK,
+            APPLICATION_DEBUGGER_BREAK,
+            SYSTEM_SPEAKER_MUTE,
+            SYSTEM_HIBERNATE,
+            SYSTEM_DISPLAY_INVERT,
+            SYSTEM_DISPLAY_INTERNAL,
+            SYSTEM_DISPLAY_EXTERNAL,
+            SYSTEM_DISPLAY_BOTH,
+            SYSTEM_DISPLAY_DUAL,
+            SYSTEM_DISPLAY_TOGGLE_INT_EXT,
+            SYSTEM_DISPLAY_SWAP_PRIMARY_SECONDARY,
+            SYSTEM_DISPLAY_LCD_AUTOSCALE,
+            SENSOR_ZONE,
+            RPM,
+            COOLANT_LEVEL,
+            COOLANT_CRITICAL_LEVEL,
+            COOLANT_PUMP,
+            CHASSIS_ENCLOSURE,
+            WIRELESS_RADIO_BUTTON,
+            WIRELESS_RADIO_LED,
+            WIRELESS_RADIO_SLIDER_SWITCH,
+            SYSTEM_DISPLAY_ROTATION_LOCK_BUTTON,
+            SYSTEM_DISPLAY_ROTATION_LOCK_SLIDER_SWITCH,
+            CONTROL_ENABLE,
+        ]
+    }
+}
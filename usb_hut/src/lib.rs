@@ -5,3 +5,9 @@ mod defs;
 pub use defs::*;
 mod keyboard_page;
 pub use keyboard_page::hid_keyboard_page;
+mod button_page;
+mod consumer_page;
+mod generic_desktop_page;
+pub use button_page::hid_button_page;
+pub use consumer_page::hid_consumer_page;
+pub use generic_desktop_page::hid_generic_desktop_page;